@@ -0,0 +1,494 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+use crate::types::*;
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct AdminLog {
+    // Log metadata
+    pub bump: u8,                    // Bump seed for the admin log PDA
+    pub sequence: u64,               // Total actions ever recorded (older ones live on in events)
+    pub upgrade_authority: Option<Pubkey>, // Last attested upgrade authority (None = never attested, default key = immutable)
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next entry is written to
+    pub count: u16,                  // Number of valid entries (up to ADMIN_LOG_LEN)
+    pub entries: [AdminLogEntry; ADMIN_LOG_LEN],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct EmergencyState {
+    pub guardian: Pubkey,            // Key allowed to flip the switch (may be a multisig PDA)
+    pub bump: u8,                    // Bump seed for the emergency state PDA
+    pub halted: bool,                // Swaps and deposits are halted; withdrawals stay open
+    pub last_changed: i64,           // Timestamp the switch was last flipped
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct FeedRegistry {
+    // Registry metadata
+    pub admin: Pubkey,               // Admin allowed to approve and remove feeds (may be a multisig PDA)
+    pub pending_admin: Pubkey,       // Proposed new admin awaiting acceptance (default = none)
+    pub bump: u8,                    // Bump seed for the feed registry PDA
+    
+    // Approved feeds
+    pub feeds: Vec<FeedEntry>,       // Approved oracle per mint pair
+    
+    // Retired corridors
+    pub deprecated_vaults: Vec<Pubkey>, // Vaults in permanent wind-down
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct HedgeConfig {
+    // Hedge metadata
+    pub vault: Pubkey,               // Vault whose net exposure is hedged
+    pub bump: u8,                    // Bump seed for the hedge config PDA
+    pub authority_bump: u8,          // Bump seed for the hedge authority PDA that holds collateral
+    
+    // Admin-configured parameters
+    pub perp_program: Pubkey,        // Whitelisted perp program hedges are placed on
+    pub keeper: Pubkey,              // Keeper allowed to open and adjust hedges
+    pub exposure_threshold: u64,     // Net exposure that must be reached before the hedge grows
+    pub max_hedge_notional: u64,     // Maximum absolute hedge size
+    
+    // Hedge state
+    pub hedge_notional: i64,         // Current hedge (negative = short the vault currency)
+    pub last_hedge_time: i64,        // Timestamp of the last adjustment
+    pub hedge_adjustments: u64,      // Number of adjustments made
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct KeeperBond {
+    pub keeper: Pubkey,                  // Operator the bond belongs to
+    pub bump: u8,                        // Bump seed for the keeper bond PDA
+    pub bonded_amount: u64,              // Tokens bonded and counting toward the minimum
+    pub unbonding_amount: u64,           // Tokens in the unbond cooldown
+    pub unbond_request_time: i64,        // Timestamp of the latest unbond request
+    pub total_slashed: u64,              // Tokens slashed from this keeper
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct KeeperHeartbeat {
+    pub bump: u8,                        // Bump seed for the heartbeat PDA
+    pub last_keeper: Pubkey,             // Bonded keeper that sent the latest heartbeat
+    pub last_heartbeat_time: i64,        // Timestamp of the latest heartbeat
+    pub heartbeat_count: u64,            // Heartbeats received from all keepers
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct KeeperRegistry {
+    // Registry metadata
+    pub authority: Pubkey,               // Governance key that can slash keepers
+    pub bond_mint: Pubkey,               // Mint of the protocol token keepers bond
+    pub bond_vault: Pubkey,              // Token account holding bonded tokens
+    pub bump: u8,                        // Bump seed for the registry PDA (signs for the bond vault)
+    
+    // Bonding parameters
+    pub min_bond: u64,                   // Bond a keeper needs to call keeper-gated instructions
+    pub unbond_cooldown: i64,            // Seconds between unbond request and withdrawal
+    
+    // Registry financials
+    pub total_bonded: u64,               // Tokens bonded by active keepers
+    pub total_unbonding: u64,            // Tokens in the unbond cooldown (still slashable)
+    pub total_slashed: u64,              // Total tokens slashed from keepers
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct LPPosition {
+    // LP metadata
+    pub owner: Pubkey,               // Owner of this LP position
+    pub vault: Pubkey,               // Vault this position belongs to
+    pub bump: u8,                    // Bump seed for the LP position PDA
+    pub is_protocol: bool,           // Protocol-owned liquidity seeded from the treasury
+    pub penalty_exempt: bool,        // Withdrawal penalties waived by the admin (e.g. market makers)
+    pub manager: Pubkey,             // Delegate that may deposit and claim (default = none)
+    
+    // LP position details
+    pub shares: u64,                 // Vault shares held, redeemable at the vault's exchange rate
+    pub last_deposit_time: i64,      // Timestamp of the last deposit
+    
+    // Rewards tracking
+    pub rewards_claimed: u64,        // Total rewards claimed by this LP
+    pub last_rewards_claim_time: i64, // Timestamp of the last rewards claim
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct MetricsHistory {
+    // History metadata
+    pub vault: Pubkey,               // Vault whose metrics are being recorded
+    pub counter_vault: Pubkey,       // Paired vault health is measured against
+    pub bump: u8,                    // Bump seed for the metrics history PDA
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next sample is written to
+    pub count: u16,                  // Number of valid samples (up to METRICS_HISTORY_LEN)
+    pub samples: [MetricsSample; METRICS_HISTORY_LEN],
+    
+    // Daily checkpoints for the trailing fee APR
+    pub daily_head: u16,             // Index the next checkpoint is written to
+    pub daily_count: u16,            // Number of valid checkpoints (up to METRICS_DAILY_LEN)
+    pub daily_samples: [MetricsSample; METRICS_DAILY_LEN],
+    pub trailing_7d_fee_apr_bps: u32, // Annualized swap fees over TVL for the last seven days
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct PairConfig {
+    // Pair identification (mints are stored in ascending order)
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub bump: u8,                    // Bump seed for the pair config PDA
+    
+    // Pricing overrides, falling back to the protocol config when unset
+    pub spread_slope_ppm: u32,       // Spread slope override (0 = use the global slope)
+    pub drift_curve: Option<DriftCurve>, // Drift curve override (None = use the global curve)
+    
+    // Oracle outage behavior
+    pub stableswap_amp: u16,         // Fallback curve amplification when oracles are stale (0 = halt)
+    
+    // Best-execution guardrail
+    pub max_execution_deviation_bps: u16, // Max shortfall of the effective rate below the oracle mid (0 = no cap)
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct PairState {
+    // Pair identification (mints are stored in ascending order)
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub bump: u8,                    // Bump seed for the pair state PDA
+    
+    // Latest oracle price, whichever direction the last swap went
+    pub last_price: u64,             // Price of mint_a in units of mint_b, scaled by 10^9
+    pub publish_slot_a: u64,         // Publish slot of mint_a's oracle price
+    pub publish_slot_b: u64,         // Publish slot of mint_b's oracle price
+    pub last_update_slot: u64,       // Slot of the last price update
+    pub last_update_timestamp: i64,  // Timestamp of the last price update
+    
+    // TWAP accumulator
+    pub price_cumulative: u128,      // Sum of last_price × seconds it was current
+    pub first_update_timestamp: i64, // Timestamp the accumulator started
+    
+    // Averaged vault health
+    pub health_average_bps: u64,     // Trailing average of the pair's vault health, in basis points
+    pub health_average_time: i64,    // Timestamp the average was last moved
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct PriceHistory {
+    // History metadata
+    pub vault: Pubkey,               // Vault whose oracle is being recorded
+    pub bump: u8,                    // Bump seed for the price history PDA
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next snapshot is written to
+    pub count: u16,                  // Number of valid snapshots (up to PRICE_HISTORY_LEN)
+    pub snapshots: [PriceSnapshot; PRICE_HISTORY_LEN],
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct ProtocolConfig {
+    // Config metadata
+    pub admin: Pubkey,               // Admin allowed to update protocol parameters (may be a multisig PDA)
+    pub pending_admin: Pubkey,       // Proposed new admin awaiting acceptance (default = none)
+    pub bump: u8,                    // Bump seed for the protocol config PDA
+    
+    // Pricing parameters
+    pub spread_slope_ppm: u32,       // Spread increase per unit of health below 0.9 (parts per million)
+    pub drift_curve: DriftCurve,     // Drift applied to swaps as vault health deteriorates
+    pub protocol_fee_curve: ParameterCurve,   // Protocol share of swap fees by vault health
+    pub withdrawal_fee_curve: ParameterCurve, // Withdrawal penalty by time since deposit
+    
+    // Withdrawal penalty routing
+    pub penalty_split: PenaltySplit, // How withdrawal penalties are shared out
+    pub insurance_fund: Pubkey,      // Owner of the insurance fund token accounts
+    
+    // Rebalancing
+    pub max_rebalance_premium_bps: u16, // Best rate a rebalancer may take over the oracle rate
+    
+    // Fee consolidation
+    pub treasury_mint: Pubkey,       // Currency protocol fees are consolidated into (default = none)
+    
+    // Utilization fee
+    pub utilization_fee_curve: ParameterCurve, // Extra swap fee by target vault drawdown below its trailing average
+    
+    // Insurance carve-out
+    pub insurance_fee_bps: u16,      // Share of protocol fees sent to the insurance fund on distribution
+    
+    // Keeper liveness
+    pub keeper_liveness_window: i64, // Seconds without a keeper heartbeat before swaps degrade (0 = off)
+    pub degraded_spread_bps: u16,    // Extra swap spread while keepers are silent
+    pub degraded_max_trade_bps: u16, // Largest swap output, in bps of target TVL, while keepers are silent (0 = no limit)
+    
+    // Staking
+    pub staking_pool: Pubkey,        // Only staking pool protocol fees may be routed to (default = none)
+    pub staker_fee_share_bps: u16,   // Share of protocol fees after the insurance carve-out routed to stakers
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct RewardTracker {
+    // Vault this reward tracker belongs to
+    pub vault: Pubkey,
+    pub bump: u8,
+    
+    // Rewards tracking
+    pub total_rewards: u64,          // Total rewards accumulated
+    pub total_deposits: u64,         // Total deposits at last update
+    pub reward_index: u64,           // Current reward index (scaled by PRECISION)
+    pub last_update_time: i64,       // Last time rewards were updated
+    
+    // Push crank pagination
+    pub push_cursor: Pubkey,         // Last LP position credited in the current pass (default = pass not started)
+    pub push_pass: u64,              // Number of completed passes over all positions
+    pub pass_started_at: i64,        // Timestamp of the first page of the current pass
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct StakePosition {
+    // Stake metadata
+    pub owner: Pubkey,               // Owner of this stake position
+    pub staking_pool: Pubkey,        // Staking pool this position belongs to
+    pub bump: u8,                    // Bump seed for the stake position PDA
+    
+    // Stake details
+    pub amount: u64,                 // Tokens staked and earning rewards
+    pub unstaking_amount: u64,       // Tokens in the unstake cooldown
+    pub unstake_request_time: i64,   // Timestamp of the last unstake request
+    
+    // Rewards tracking
+    pub reward_index_checkpoint: u128, // Pool reward index at the last settlement
+    pub pending_rewards: u64,        // Settled rewards not yet claimed
+    pub rewards_claimed: u64,        // Total rewards claimed by this staker
+    
+    // Slashing
+    pub slash_factor_checkpoint: u128, // Pool slash factor at the last settlement
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct StakingPool {
+    // Pool metadata
+    pub authority: Pubkey,               // Protocol authority that manages the pool (may be a multisig PDA)
+    pub pending_authority: Pubkey,       // Proposed new authority awaiting acceptance (default = none)
+    pub stake_mint: Pubkey,              // Mint of the protocol token being staked
+    pub stake_vault: Pubkey,             // Token account holding staked tokens
+    pub reward_mint: Pubkey,             // Mint protocol fees are paid to stakers in
+    pub reward_vault: Pubkey,            // Token account holding undistributed staker rewards
+    pub bump: u8,                        // Bump seed for the staking pool PDA (signs for both vaults)
+    
+    // Staking parameters
+    pub unstake_cooldown: i64,           // Seconds between unstake request and withdrawal
+    
+    // Staking financials
+    pub total_staked: u64,               // Tokens currently staked and earning
+    pub reward_index: u128,              // Cumulative rewards per staked token (scaled by PRECISION)
+    pub total_rewards_distributed: u64,  // Total protocol fees routed to stakers
+    
+    // Slashing backstop
+    pub total_unstaking: u64,            // Tokens in the unstake cooldown (still slashable)
+    pub slash_factor: u128,              // Cumulative post-slash share of stake (scaled by PRECISION)
+    pub max_slash_bps: u16,              // Maximum share of stake a single slash may take
+    pub slash_timelock: i64,             // Seconds between proposing and executing a slash
+    pub pending_slash_amount: u64,       // Amount of the proposed slash (0 = none pending)
+    pub pending_slash_vault: Pubkey,     // Vault whose shortfall the proposed slash covers
+    pub pending_slash_recipient: Pubkey, // Token account receiving the slashed tokens
+    pub pending_slash_eta: i64,          // Earliest time the proposed slash can execute
+    pub total_slashed: u64,              // Total tokens slashed from stakers
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct UserStats {
+    pub owner: Pubkey,               // Trader the stats belong to
+    pub bump: u8,                    // Bump seed for the user stats PDA
+    
+    // Rolling totals, valued in the oracles' common quote currency
+    pub swap_count: u64,             // Swaps executed by the trader
+    pub cumulative_volume: u128,     // Value of all amounts swapped in
+    pub cumulative_fees: u128,       // Value of all swap fees paid
+    
+    // Trade timing
+    pub first_trade_timestamp: i64,  // Timestamp of the first recorded swap
+    pub last_trade_slot: u64,        // Slot of the last swap
+    pub last_trade_timestamp: i64,   // Timestamp of the last swap
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct VaultAccount {
+    // Vault metadata
+    pub vault_name: [u8; VAULT_NAME_LEN], // User-friendly name of the vault, UTF-8 padded with zeros
+    pub authority: Pubkey,               // Authority PDA that signs vault operations
+    pub token_mint: Pubkey,              // Mint address of the stablecoin this vault accepts
+    pub token_account: Pubkey,           // Token account PDA that holds the vault's tokens
+    pub nonce: u8,                       // Canonical bump seed for the vault authority PDA
+    
+    // Vault financials
+    pub tvl: u64,                        // Total value locked in the vault
+    pub total_shares: u64,               // LP shares outstanding against TVL plus retained LP fees
+    pub protocol_owned_liquidity: u64,   // Portion of TVL seeded by the protocol treasury
+    pub accrued_lp_fees: u64,            // Accumulated fees for LPs since last distribution (70%)
+    pub accrued_pda_fees: u64,           // Accumulated fees for PDA (variable based on vault health)
+    pub accrued_protocol_fees: u64,      // Accumulated fees for protocol (variable based on vault health)
+    pub fee_basis_points: u16,           // Basis points for swap fees (1 bp = 0.01%)
+    pub last_fee_update: i64,            // Last timestamp fees were updated
+    
+    // Oracle related data
+    pub oracle: Pubkey,                  // FX oracle for this currency
+    pub last_oracle_price: u64,          // Last known oracle price scaled by 10^9
+    pub last_update_timestamp: i64,      // Last time the oracle data was updated
+    
+    // Treasury accounts
+    pub treasury: Pubkey,                // Treasury account to receive protocol fees
+    pub pda_treasury: Pubkey,            // PDA treasury account to receive PDA fees
+    pub pending_treasury: Pubkey,        // Proposed new treasury awaiting acceptance (default = none)
+    
+    // Vault admin
+    pub admin: Pubkey,                   // Key that distributes the vault's accrued fees
+    pub pending_admin: Pubkey,           // Proposed new admin awaiting acceptance (default = none)
+    pub roles: Roles,                    // Keys delegated individual capabilities by the admin
+    
+    // Balance incentive (rebalancing bounty paid out of accrued PDA fees)
+    pub balance_target_health_bps: u16,  // Health (scaled by 10000) a swap must reach to earn the bonus
+    pub balance_bonus_bps: u16,          // Bonus paid on the amount out, in basis points
+    pub balance_bonus_cap: u64,          // Maximum bonus paid per incentive
+    pub balance_bonus_cooldown: i64,     // Minimum seconds between two bonuses
+    pub last_balance_bonus_time: i64,    // Timestamp of the last bonus paid
+    pub total_balance_bonus_paid: u64,   // Total bonuses paid out of this vault
+    
+    // Launch mode (decaying fee parameters for newly launched vaults)
+    pub launch_start_time: i64,          // Timestamp the launch phase started
+    pub launch_duration: i64,            // Length of the launch phase in seconds (0 = disabled)
+    pub launch_spread_bps: u16,          // Spread at the start of the launch phase
+    pub launch_withdrawal_fee_bps: u16,  // Withdrawal penalty at the start of the launch phase
+    
+    // Reward vesting
+    pub reward_vesting_duration: i64,    // Seconds LP rewards vest over (0 = instant transfer)
+    pub early_claim_penalty_bps: u16,    // Penalty on unvested rewards claimed early
+    pub vesting_rewards_outstanding: u64, // LP rewards held in vesting schedules
+    
+    // Price deviation circuit breaker
+    pub max_price_deviation_bps: u16,    // Max move between consecutive prices before halting (0 = disabled)
+    pub breaker_cooldown_slots: u64,     // Slots swaps stay halted after the breaker trips
+    pub breaker_tripped: bool,           // Whether swaps are currently halted
+    pub halt_until_slot: u64,            // Slot swaps may resume at if the price is back within band
+    pub breaker_reference_price: u64,    // Last price the breaker accepted; the pre-trip price while tripped
+    
+    // Daily volume cap
+    pub daily_volume_cap: u64,           // Max swap volume through the vault per day (0 = unlimited)
+    pub volume_day: i64,                 // Day index (unix time / 86400) of the current counter
+    pub daily_volume: u64,               // Swap volume through the vault during volume_day
+    
+    // Net exposure since inception
+    pub cumulative_swap_inflow: u64,     // Tokens swapped into the vault
+    pub cumulative_swap_outflow: u64,    // Tokens swapped out of the vault
+    pub net_swap_flow: i128,             // Inflow minus outflow (positive = protocol long this currency)
+    
+    // Retained-earnings mode
+    pub retain_earnings: bool,           // Fold LP fees into TVL instead of paying claims
+    pub fee_fold_interval: i64,          // Minimum seconds between folds
+    pub last_fee_fold_time: i64,         // Timestamp of the last fold
+    
+    // Asset-management fees (paid to the protocol treasury out of LP assets)
+    pub management_fee_bps: u16,         // Annual fee on LP assets (0 = disabled)
+    pub performance_fee_bps: u16,        // Share of LP yield above the high-water mark (0 = disabled)
+    pub high_water_mark: u64,            // Highest virtual price performance fees were charged up to
+    pub last_management_fee_time: i64,   // Timestamp management fees were accrued up to
+    
+    // Dust limits (0 = no minimum)
+    pub min_deposit_amount: u64,         // Smallest deposit (or split-off tranche) accepted
+    pub min_withdraw_amount: u64,        // Smallest partial withdrawal accepted
+    pub min_swap_amount: u64,            // Smallest swap input accepted
+    
+    // Operation pauses (set by the pauser role)
+    pub deposits_paused: bool,           // Deposits into the vault are rejected
+    pub withdrawals_paused: bool,        // Withdrawals from the vault are rejected
+    pub swaps_paused: bool,              // Swaps into or out of the vault are rejected
+    
+    // Wind-down (permanent withdraw-only mode for retired corridors)
+    pub wound_down: bool,                // Deposits and swaps into the vault are disabled and penalties waived
+    pub wind_down_time: i64,             // Timestamp the wind-down started
+    
+    // Token account rotation (timelocked move of the vault's tokens to a new account)
+    pub pending_token_account: Pubkey,   // Proposed replacement token account (default = none)
+    pub token_account_rotation_eta: i64, // Earliest time the rotation can be executed
+    
+    // Oracle sequencing
+    pub last_swap_slot: u64,             // Slot of the most recent swap through the vault
+    pub last_swap_publish_slot: u64,     // Oracle publish slot used by that swap
+    
+    // Fee history
+    pub cumulative_swap_fees: u64,       // All swap fees charged on amounts paid out of the vault
+    
+    // Utilization (drawdown against a trailing average)
+    pub tvl_average: u64,                // Exponential moving average of TVL sampled by swaps
+    pub tvl_average_time: i64,           // Timestamp the average was last updated
+    
+    // Transaction guard
+    pub tx_guard_enabled: bool,          // Deposits, swaps and withdrawals must be alone in their transaction
+}
+
+impl VaultAccount {
+    /// Size of a vault in the current layout, discriminator included
+    pub const LEN: usize = 8 + 802;
+    
+    /// Returns the vault name without its zero padding, or None if it is not valid UTF-8
+    pub fn name(&self) -> Option<&str> {
+        let len = self.vault_name.iter().position(|byte| *byte == 0).unwrap_or(VAULT_NAME_LEN);
+        std::str::from_utf8(&self.vault_name[..len]).ok()
+    }
+}
+
+/// Vault as laid out before the fixed-offset layout, with the name as a length-prefixed String
+/// Such a vault is shorter than VaultAccount::LEN until migrate_vault_layout rewrites it
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct LegacyVaultAccount {
+    pub vault_name: String,
+    pub authority: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub nonce: u8,
+    pub tvl: u64,
+    pub accrued_lp_fees: u64,
+    pub accrued_pda_fees: u64,
+    pub accrued_protocol_fees: u64,
+    pub fee_basis_points: u16,
+    pub last_fee_update: i64,
+    pub oracle: Pubkey,
+    pub last_oracle_price: u64,
+    pub last_update_timestamp: i64,
+    pub treasury: Pubkey,
+    pub pda_treasury: Pubkey,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct VaultPair {
+    // Pair identification (vaults are stored in ascending order of their mints)
+    pub vault_a: Pubkey,             // Vault of the lower mint
+    pub vault_b: Pubkey,             // Vault of the higher mint
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub base_is_a: bool,             // Quote orientation (true = mint_a is the base currency)
+    pub bump: u8,                    // Bump seed for the vault pair PDA
+    pub created_at: i64,             // Timestamp the pair was registered
+    
+    // Pair statistics
+    pub swap_count: u64,             // Swaps executed between the two vaults
+    pub volume_in_a: u128,           // mint_a tokens swapped into vault A
+    pub volume_in_b: u128,           // mint_b tokens swapped into vault B
+    pub fees_a: u128,                // Fees charged on amounts paid out of vault A
+    pub fees_b: u128,                // Fees charged on amounts paid out of vault B
+    pub last_swap_timestamp: i64,    // Timestamp of the last swap
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct VestingSchedule {
+    // Vesting metadata
+    pub owner: Pubkey,               // LP the rewards vest to
+    pub vault: Pubkey,               // Vault the rewards were earned in
+    pub bump: u8,                    // Bump seed for the vesting schedule PDA
+    
+    // Current linear schedule
+    pub locked_amount: u64,          // Rewards on the current linear schedule
+    pub released_amount: u64,        // Portion of locked_amount already vested
+    pub start_time: i64,             // Start of the current schedule
+    pub end_time: i64,               // End of the current schedule
+    
+    // Vested balances
+    pub unlocked_amount: u64,        // Vested rewards not yet claimed
+    pub total_claimed: u64,          // Total vested rewards claimed
+} 
\ No newline at end of file
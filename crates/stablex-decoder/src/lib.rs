@@ -0,0 +1,269 @@
+//! Borsh schemas and decoders for every account of the fx_vault_dex program
+//!
+//! Geyser plugins and indexers can decode raw account data with this crate without linking
+//! the program or Anchor. Each account is an 8-byte Anchor discriminator followed by the
+//! Borsh-encoded struct; `try_decode` dispatches on the discriminator and detects vaults
+//! still in the legacy layout.
+
+pub mod accounts;
+pub mod types;
+
+pub use accounts::*;
+pub use types::*;
+
+use borsh::BorshDeserialize;
+
+/// Errors returned while decoding account data
+#[derive(Debug, thiserror::Error)]
+pub enum DecodeError {
+    #[error("Account data is shorter than the 8-byte discriminator")]
+    TooShort,
+    
+    #[error("Discriminator {0:?} does not belong to any fx_vault_dex account")]
+    UnknownDiscriminator([u8; 8]),
+    
+    #[error("Expected discriminator {expected:?}, found {found:?}")]
+    WrongDiscriminator { expected: [u8; 8], found: [u8; 8] },
+    
+    #[error("Account data does not match the layout: {0}")]
+    InvalidData(#[from] std::io::Error),
+}
+
+/// Layout revision of an account's data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LayoutVersion {
+    Legacy,  // Vault with a length-prefixed name, awaiting migrate_vault_layout
+    Current, // Layout the program reads and writes today
+}
+
+/// An fx_vault_dex account type with its Anchor discriminator
+pub trait AccountData: BorshDeserialize {
+    /// First 8 bytes of sha256("account:<Name>")
+    const DISCRIMINATOR: [u8; 8];
+    
+    /// Decodes the account from its raw data, discriminator included
+    /// Trailing bytes are ignored as Anchor ignores them, so accounts with spare room decode
+    fn try_decode(data: &[u8]) -> Result<Self, DecodeError> {
+        let found = discriminator(data)?;
+        if found != Self::DISCRIMINATOR {
+            return Err(DecodeError::WrongDiscriminator { expected: Self::DISCRIMINATOR, found });
+        }
+        
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Reads the 8-byte discriminator at the start of the account data
+pub fn discriminator(data: &[u8]) -> Result<[u8; 8], DecodeError> {
+    data.get(..8)
+        .map(|bytes| bytes.try_into().unwrap())
+        .ok_or(DecodeError::TooShort)
+}
+
+macro_rules! program_accounts {
+    ($($name:ident => $discriminator:expr,)*) => {
+        $(
+            impl AccountData for $name {
+                const DISCRIMINATOR: [u8; 8] = $discriminator;
+            }
+        )*
+        
+        /// Account type identified from a discriminator
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum AccountKind {
+            $($name,)*
+        }
+        
+        impl AccountKind {
+            /// Returns the account type owning the discriminator, if any
+            pub fn from_discriminator(discriminator: [u8; 8]) -> Option<Self> {
+                $(
+                    if discriminator == $name::DISCRIMINATOR {
+                        return Some(Self::$name);
+                    }
+                )*
+                None
+            }
+        }
+        
+        /// Any decoded fx_vault_dex account
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum ProgramAccount {
+            $($name(Box<$name>),)*
+            LegacyVaultAccount(Box<LegacyVaultAccount>),
+        }
+        
+        fn decode_current(kind: AccountKind, data: &[u8]) -> Result<ProgramAccount, DecodeError> {
+            match kind {
+                $(AccountKind::$name => Ok(ProgramAccount::$name(Box::new($name::try_decode(data)?))),)*
+            }
+        }
+    };
+}
+
+program_accounts! {
+    AdminLog => [40, 47, 171, 225, 68, 74, 64, 18],
+    EmergencyState => [180, 65, 56, 35, 62, 185, 148, 179],
+    FeedRegistry => [165, 19, 142, 137, 18, 194, 61, 247],
+    HedgeConfig => [129, 85, 154, 78, 96, 243, 21, 246],
+    KeeperBond => [147, 25, 133, 190, 97, 230, 22, 101],
+    KeeperHeartbeat => [105, 245, 204, 240, 246, 228, 44, 59],
+    KeeperRegistry => [131, 98, 137, 110, 2, 90, 80, 4],
+    LPPosition => [196, 56, 115, 198, 14, 117, 32, 224],
+    MetricsHistory => [251, 70, 61, 14, 120, 215, 202, 247],
+    PairConfig => [119, 167, 13, 129, 136, 228, 151, 77],
+    PairState => [229, 212, 222, 222, 191, 128, 176, 235],
+    PriceHistory => [38, 241, 40, 19, 42, 228, 93, 152],
+    ProtocolConfig => [207, 91, 250, 28, 152, 179, 215, 209],
+    RewardTracker => [235, 72, 124, 120, 163, 87, 136, 191],
+    StakePosition => [78, 165, 30, 111, 171, 125, 11, 220],
+    StakingPool => [203, 19, 214, 220, 220, 154, 24, 102],
+    UserStats => [176, 223, 136, 27, 122, 79, 32, 227],
+    VaultAccount => [230, 251, 241, 83, 139, 202, 93, 28],
+    VaultPair => [117, 200, 187, 79, 72, 161, 236, 91],
+    VestingSchedule => [130, 200, 173, 148, 39, 75, 243, 147],
+}
+
+impl ProgramAccount {
+    /// Layout revision the account was decoded from
+    pub fn layout_version(&self) -> LayoutVersion {
+        match self {
+            Self::LegacyVaultAccount(_) => LayoutVersion::Legacy,
+            _ => LayoutVersion::Current,
+        }
+    }
+}
+
+/// Identifies an account's type and layout revision without decoding it
+/// A vault shorter than the current layout predates the fixed-offset name, as in migrate_vault_layout
+pub fn detect(data: &[u8]) -> Result<(AccountKind, LayoutVersion), DecodeError> {
+    let discriminator = discriminator(data)?;
+    let kind = AccountKind::from_discriminator(discriminator).ok_or(DecodeError::UnknownDiscriminator(discriminator))?;
+    
+    let version = if kind == AccountKind::VaultAccount && data.len() < VaultAccount::LEN {
+        LayoutVersion::Legacy
+    } else {
+        LayoutVersion::Current
+    };
+    
+    Ok((kind, version))
+}
+
+/// Decodes any fx_vault_dex account from its raw data
+pub fn try_decode(data: &[u8]) -> Result<ProgramAccount, DecodeError> {
+    match detect(data)? {
+        (AccountKind::VaultAccount, LayoutVersion::Legacy) => {
+            let vault = LegacyVaultAccount::deserialize(&mut &data[8..])?;
+            Ok(ProgramAccount::LegacyVaultAccount(Box::new(vault)))
+        }
+        (kind, _) => decode_current(kind, data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorSerialize, Discriminator};
+    use borsh::BorshSerialize;
+    use fx_vault_dex::state as program;
+    use solana_program::pubkey::Pubkey;
+    
+    /// Checks the discriminator and layout of a mirrored account against the program's type
+    /// Both decode the same zeroed account of the program's size and must re-encode it identically
+    fn assert_matches_program<Mirror, Program>(program_len: usize)
+    where
+        Mirror: AccountData + BorshSerialize,
+        Program: AccountDeserialize + AnchorSerialize + Discriminator,
+    {
+        assert_eq!(Mirror::DISCRIMINATOR, Program::DISCRIMINATOR);
+        
+        let mut data = vec![0u8; program_len];
+        data[..8].copy_from_slice(&Program::DISCRIMINATOR);
+        
+        let mirror = Mirror::try_decode(&data).unwrap();
+        let program = Program::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(mirror.try_to_vec().unwrap(), program.try_to_vec().unwrap());
+    }
+    
+    #[test]
+    fn layouts_match_the_program() {
+        assert_matches_program::<AdminLog, program::AdminLog>(program::AdminLog::LEN);
+        assert_matches_program::<EmergencyState, program::EmergencyState>(program::EmergencyState::LEN);
+        assert_matches_program::<FeedRegistry, program::FeedRegistry>(program::FeedRegistry::LEN);
+        assert_matches_program::<HedgeConfig, program::HedgeConfig>(program::HedgeConfig::LEN);
+        assert_matches_program::<KeeperBond, program::KeeperBond>(program::KeeperBond::LEN);
+        assert_matches_program::<KeeperHeartbeat, program::KeeperHeartbeat>(program::KeeperHeartbeat::LEN);
+        assert_matches_program::<KeeperRegistry, program::KeeperRegistry>(program::KeeperRegistry::LEN);
+        assert_matches_program::<LPPosition, program::LPPosition>(program::LPPosition::LEN);
+        assert_matches_program::<MetricsHistory, program::MetricsHistory>(program::MetricsHistory::LEN);
+        assert_matches_program::<PairConfig, program::PairConfig>(program::PairConfig::LEN);
+        assert_matches_program::<PairState, program::PairState>(program::PairState::LEN);
+        assert_matches_program::<PriceHistory, program::PriceHistory>(program::PriceHistory::LEN);
+        assert_matches_program::<ProtocolConfig, program::ProtocolConfig>(program::ProtocolConfig::LEN);
+        assert_matches_program::<RewardTracker, program::RewardTracker>(program::RewardTracker::LEN);
+        assert_matches_program::<StakePosition, program::StakePosition>(program::StakePosition::LEN);
+        assert_matches_program::<StakingPool, program::StakingPool>(program::StakingPool::LEN);
+        assert_matches_program::<UserStats, program::UserStats>(program::UserStats::LEN);
+        assert_matches_program::<VaultAccount, program::VaultAccount>(program::VaultAccount::LEN);
+        assert_matches_program::<VaultPair, program::VaultPair>(program::VaultPair::LEN);
+        assert_matches_program::<VestingSchedule, program::VestingSchedule>(program::VestingSchedule::LEN);
+        
+        assert_eq!(VaultAccount::LEN, program::VaultAccount::LEN);
+    }
+    
+    #[test]
+    fn decodes_a_vault_written_by_the_program() {
+        let admin = Pubkey::new_unique();
+        let vault = program::VaultAccount {
+            vault_name: program::VaultAccount::encode_name("EURC vault").unwrap(),
+            tvl: 1_000_000,
+            total_shares: 990_000,
+            admin,
+            breaker_tripped: true,
+            net_swap_flow: -250_000,
+            tx_guard_enabled: true,
+            ..program::VaultAccount::default()
+        };
+        let mut data = Vec::new();
+        vault.try_serialize(&mut data).unwrap();
+        
+        assert_eq!(detect(&data).unwrap(), (AccountKind::VaultAccount, LayoutVersion::Current));
+        let ProgramAccount::VaultAccount(decoded) = try_decode(&data).unwrap() else {
+            panic!("decoded as another account type");
+        };
+        assert_eq!(decoded.name(), Some("EURC vault"));
+        assert_eq!(decoded.tvl, 1_000_000);
+        assert_eq!(decoded.total_shares, 990_000);
+        assert_eq!(decoded.admin, admin);
+        assert!(decoded.breaker_tripped);
+        assert_eq!(decoded.net_swap_flow, -250_000);
+        assert!(decoded.tx_guard_enabled);
+    }
+    
+    #[test]
+    fn detects_a_legacy_vault() {
+        let legacy = LegacyVaultAccount {
+            vault_name: "USDC vault".to_string(),
+            tvl: 42,
+            ..LegacyVaultAccount::default()
+        };
+        let mut data = VaultAccount::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        
+        assert_eq!(detect(&data).unwrap(), (AccountKind::VaultAccount, LayoutVersion::Legacy));
+        let decoded = try_decode(&data).unwrap();
+        assert_eq!(decoded.layout_version(), LayoutVersion::Legacy);
+        assert_eq!(decoded, ProgramAccount::LegacyVaultAccount(Box::new(legacy)));
+    }
+    
+    #[test]
+    fn rejects_foreign_and_mismatched_data() {
+        assert!(matches!(try_decode(&[1, 2, 3]), Err(DecodeError::TooShort)));
+        assert!(matches!(try_decode(&[0; 64]), Err(DecodeError::UnknownDiscriminator(_))));
+        
+        let mut data = vec![0u8; program::UserStats::LEN];
+        data[..8].copy_from_slice(&UserStats::DISCRIMINATOR);
+        assert!(matches!(VaultPair::try_decode(&data), Err(DecodeError::WrongDiscriminator { .. })));
+        assert!(matches!(UserStats::try_decode(&data[..20]), Err(DecodeError::InvalidData(_))));
+    }
+} 
\ No newline at end of file
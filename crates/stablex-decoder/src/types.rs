@@ -0,0 +1,148 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+// Fixed sizes of the program's ring buffers and name field
+pub const VAULT_NAME_LEN: usize = 32;        // Bytes reserved for a vault's name
+pub const ADMIN_LOG_LEN: usize = 32;         // Admin actions kept in the admin log ring buffer
+pub const PRICE_HISTORY_LEN: usize = 64;     // Snapshots kept in each price history ring buffer
+pub const METRICS_HISTORY_LEN: usize = 96;   // Hourly samples kept in each vault metrics ring buffer
+pub const METRICS_DAILY_LEN: usize = 8;      // Daily checkpoints kept for the trailing fee APR
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AdminAction {
+    #[default]
+    None,
+    // Protocol admin
+    SetSpreadSlope,
+    SetDriftCurve,
+    SetFeeAllocationCurve,
+    SetWithdrawalFeeCurve,
+    SetPairConfig,
+    SetPenaltySplit,
+    SetPenaltyExemption,
+    SetGuardian,
+    ProposeProtocolAdmin,
+    AcceptProtocolAdmin,
+    // Feed registry admin
+    RegisterFeed,
+    RemoveFeed,
+    ProposeRegistryAdmin,
+    AcceptRegistryAdmin,
+    // Guardian
+    SetEmergencyHalt,
+    // Vault admin
+    ConfigureCircuitBreaker,
+    ConfigureHedge,
+    SetBalanceIncentive,
+    ConfigureRewardVesting,
+    ConfigureLaunchMode,
+    SetDailyVolumeCap,
+    ConfigureRetainedEarnings,
+    ConfigureVaultFees,
+    // Vault treasury
+    SeedProtocolLiquidity,
+    ProposeVaultTreasury,
+    AcceptVaultTreasury,
+    // Staking pool authority
+    ConfigureSlashing,
+    ProposeSlash,
+    CancelSlash,
+    ExecuteSlash,
+    ProposeStakingAuthority,
+    AcceptStakingAuthority,
+    // Permissionless
+    AttestUpgradeAuthority,
+    // Vault admin (appended to keep earlier discriminants stable)
+    SetMinimumAmounts,
+    // Vault admin
+    ProposeVaultAdmin,
+    AcceptVaultAdmin,
+    AssignRole,
+    RevokeRole,
+    // Pauser
+    SetVaultPauses,
+    // Vault admin
+    SetWindDown,
+    ProposeTokenAccountRotation,
+    CancelTokenAccountRotation,
+    ExecuteTokenAccountRotation,
+    RecoverToken,
+    MigrateVaultLayout,
+    CreateVaultPair,
+    SlashKeeper,
+    SetRebalancePremiumCap,
+    SetTreasuryMint,
+    ConsolidateFees,
+    SetUtilizationFeeCurve,
+    RenameVault,
+    SetTransactionGuard,
+    SetInsuranceFeeShare,
+    SetKeeperLiveness,
+    // Vault treasury
+    WithdrawProtocolLiquidity,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct AdminLogEntry {
+    pub actor: Pubkey,               // Signer that performed the action
+    pub action: AdminAction,         // What was done
+    pub target: Pubkey,              // Account that was changed
+    pub old_value: u64,              // Setting before the action
+    pub new_value: u64,              // Setting after the action
+    pub timestamp: i64,              // When the action happened
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct FeedEntry {
+    pub base_mint: Pubkey,           // Mint of the currency being priced
+    pub quote_mint: Pubkey,          // Mint of the currency the price is quoted in
+    pub oracle: Pubkey,              // Approved oracle price account for the pair
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct MetricsSample {
+    pub timestamp: i64,              // Time the sample was taken
+    pub tvl: u64,                    // Vault TVL
+    pub health_bps: u16,             // Health against the counter vault (scaled by 10000)
+    pub cumulative_fees: u64,        // Vault's cumulative swap fees at the time
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct PriceSnapshot {
+    pub price: u64,                  // Oracle price scaled by PRICE_SCALE
+    pub conf: u64,                   // Oracle confidence scaled by PRICE_SCALE
+    pub timestamp: i64,              // Oracle publish time
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct DriftCurve {
+    pub start_health_bps: u16,       // Health below which drift starts (scaled by 10000)
+    pub kink_health_bps: u16,        // Health below which the steep slope applies
+    pub gentle_slope_ppm: u32,       // Drift per unit of health lost above the kink (parts per million)
+    pub steep_slope_ppm: u32,        // Drift per unit of health lost below the kink (parts per million)
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct CurvePoint {
+    pub x: u64,                      // Input value (vault health in bps, seconds, ...)
+    pub y: u16,                      // Output value in basis points
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq, Default)]
+pub struct ParameterCurve {
+    pub points: Vec<CurvePoint>,     // Breakpoints in strictly increasing x order
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct PenaltySplit {
+    pub lp_bps: u16,                 // Left in the vault for the remaining LPs
+    pub insurance_bps: u16,          // Sent to the insurance fund
+    pub treasury_bps: u16,           // Sent to the vault's PDA treasury
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub struct Roles {
+    pub fee_manager: Pubkey,         // Fee distribution (default = vault admin)
+    pub pauser: Pubkey,              // Operation pauses (default = vault admin)
+    pub rebalancer: Pubkey,          // Rebalancing (default = PDA treasury)
+} 
\ No newline at end of file
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use crate::state::AdminAction;
+
+#[event]
+pub struct CircuitBreakerTripped {
+    pub vault: Pubkey,
+    pub price: u64,                  // Price that tripped the breaker
+    pub reference_price: u64,        // Last good price before the move
+    pub deviation_bps: u64,          // Move between the two prices
+    pub halt_until_slot: u64,        // Slot swaps may resume at
+}
+
+#[event]
+pub struct CircuitBreakerResumed {
+    pub vault: Pubkey,
+    pub price: u64,                  // Fresh price back within band
+    pub reference_price: u64,        // Reference price the band is measured against
+    pub slot: u64,
+}
+
+#[event]
+pub struct SwapExecuted {
+    pub user: Pubkey,
+    pub source_vault: Pubkey,
+    pub target_vault: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub oracle_price: u64,           // Oracle mid: source price in target units, scaled by 10^9
+    pub effective_rate: u64,         // Target tokens received per source token, scaled by 10^9
+    pub execution_deviation_bps: i64, // Effective rate below the oracle mid, in basis points
+    pub source_publish_slot: u64,    // Publish slot of the source vault's oracle price
+    pub target_publish_slot: u64,    // Publish slot of the target vault's oracle price
+    pub source_net_flow: i128,       // Source vault net exposure after the swap
+    pub target_net_flow: i128,       // Target vault net exposure after the swap
+    pub stableswap_fallback: bool,   // Priced on the fallback curve because an oracle was stale
+}
+
+#[event]
+pub struct LpFeesFolded {
+    pub vault: Pubkey,
+    pub folded_amount: u64,          // LP fees moved into TVL
+    pub tvl: u64,                    // TVL after the fold
+    pub total_shares: u64,
+    pub virtual_price: u64,          // Tokens per share, scaled by PRECISION
+}
+
+#[event]
+pub struct VaultFeesAccrued {
+    pub vault: Pubkey,
+    pub management_fee: u64,         // Charged on LP assets for the elapsed period
+    pub performance_fee: u64,        // Charged on yield above the high-water mark
+    pub virtual_price: u64,          // Tokens per share after fees, scaled by PRECISION
+    pub high_water_mark: u64,
+}
+
+#[event]
+pub struct PenaltyExemptionUsed {
+    pub vault: Pubkey,
+    pub owner: Pubkey,               // Owner of the exempt position
+    pub protocol_owned: bool,        // Exempt as protocol-owned liquidity rather than by allowlist
+    pub waived_fee_bps: u16,         // Penalty that would otherwise have applied
+    pub waived_amount: u64,
+}
+
+#[event]
+pub struct EmergencyHaltChanged {
+    pub guardian: Pubkey,
+    pub halted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultPausesChanged {
+    pub vault: Pubkey,
+    pub pauser: Pubkey,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    pub swaps_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultWoundDown {
+    pub vault: Pubkey,
+    pub admin: Pubkey,
+    pub tvl: u64,                    // Liquidity left to withdraw when the wind-down started
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TokenAccountRotated {
+    pub vault: Pubkey,
+    pub old_token_account: Pubkey,
+    pub new_token_account: Pubkey,
+    pub amount: u64,                 // Balance moved to the new account
+}
+
+#[event]
+pub struct TokenRecovered {
+    pub vault: Pubkey,
+    pub mint: Pubkey,                // Foreign mint that was recovered
+    pub source: Pubkey,              // Vault authority token account it was sent to
+    pub destination: Pubkey,         // Treasury token account it was returned to
+    pub amount: u64,
+}
+
+#[event]
+pub struct AdminActionLogged {
+    pub sequence: u64,               // Position of the action in the full admin history
+    pub actor: Pubkey,
+    pub action: AdminAction,
+    pub target: Pubkey,
+    pub old_value: u64,
+    pub new_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DustSwept {
+    pub vault: Pubkey,
+    pub amount: u64,                 // Untracked tokens booked into protocol fees
+}
+
+#[event]
+pub struct HedgeAdjusted {
+    pub vault: Pubkey,
+    pub perp_program: Pubkey,
+    pub net_exposure: i128,          // Vault net swap flow when the hedge was adjusted
+    pub previous_hedge_notional: i64,
+    pub hedge_notional: i64,
+}
+
+#[event]
+pub struct IncentivesPushed {
+    pub vault: Pubkey,
+    pub pass: u64,                   // Pass the page belongs to
+    pub cursor: Pubkey,              // Last position key in the page
+    pub positions_credited: u32,
+    pub amount: u64,                 // Rewards paid out by the page
+    pub pass_completed: bool,
+}
+
+#[event]
+pub struct KeeperSlashed {
+    pub keeper: Pubkey,
+    pub amount: u64,                 // Tokens taken from the bond
+    pub remaining_bond: u64,         // Bonded tokens left after the slash
+    pub recipient: Pubkey,           // Token account the slashed tokens were sent to
+}
+
+#[event]
+pub struct RebalancedFromFees {
+    pub deficit_vault: Pubkey,
+    pub surplus_vault: Pubkey,
+    pub amount: u64,                 // Total added to the deficit vault's TVL
+    pub deficit_fees_used: u64,      // Deficit vault PDA fees moved into its TVL
+    pub surplus_fees_converted: u64, // Surplus vault PDA fees paid to the rebalancer for the converted top-up
+    pub oracle_price: u64,           // Surplus price in deficit units used for the conversion
+    pub health_before_bps: u16,
+    pub health_after_bps: u16,
+}
+
+#[event]
+pub struct FeesConsolidated {
+    pub source_vault: Pubkey,
+    pub treasury_vault: Pubkey,
+    pub amount_in: u64,              // Protocol fees taken from the source vault
+    pub amount_out: u64,             // Protocol fees credited in the treasury currency
+    pub oracle_price: u64,           // Source price in treasury currency units, scaled by 10^9
+}
+
+#[event]
+pub struct SwapOutputSplit {
+    pub user: Pubkey,
+    pub target_vault: Pubkey,
+    pub amount_out: u64,             // Swap output shared between the recipients
+    pub recipient_count: u8,
+}
+
+#[event]
+pub struct ProtocolLiquidityWithdrawn {
+    pub vault: Pubkey,
+    pub treasury: Pubkey,
+    pub shares: u64,
+    pub principal: u64,              // Seeded cost returned to the treasury
+    pub yield_amount: u64,           // Earnings above cost paid to the treasury
+} 
\ No newline at end of file
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED};
+use crate::utils::{accept_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.admin);
+    
+    let protocol_config = &mut *ctx.accounts.protocol_config;
+    
+    let previous = accept_handover(&mut protocol_config.admin, &mut protocol_config.pending_admin, ctx.accounts.new_admin.key())?;
+    
+    msg!("Protocol admin transferred from {} to {}", previous, protocol_config.admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.new_admin.key(),
+        AdminAction::AcceptProtocolAdmin,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeedRegistry, AdminLog, AdminAction, FEED_REGISTRY_SEED, ADMIN_LOG_SEED};
+use crate::utils::{accept_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AcceptRegistryAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<AcceptRegistryAdmin>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.feed_registry.admin);
+    
+    let feed_registry = &mut *ctx.accounts.feed_registry;
+    
+    let previous = accept_handover(&mut feed_registry.admin, &mut feed_registry.pending_admin, ctx.accounts.new_admin.key())?;
+    
+    msg!("Feed registry admin transferred from {} to {}", previous, feed_registry.admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.new_admin.key(),
+        AdminAction::AcceptRegistryAdmin,
+        ctx.accounts.feed_registry.key(),
+        old_value,
+        fingerprint(&ctx.accounts.feed_registry.admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
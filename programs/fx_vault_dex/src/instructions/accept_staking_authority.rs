@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakingPool, AdminLog, AdminAction, STAKING_POOL_SEED, ADMIN_LOG_SEED};
+use crate::utils::{accept_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AcceptStakingAuthority<'info> {
+    pub new_authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<AcceptStakingAuthority>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.staking_pool.authority);
+    
+    let staking_pool = &mut *ctx.accounts.staking_pool;
+    
+    let previous = accept_handover(&mut staking_pool.authority, &mut staking_pool.pending_authority, ctx.accounts.new_authority.key())?;
+    
+    msg!("Staking pool authority transferred from {} to {}", previous, staking_pool.authority);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.new_authority.key(),
+        AdminAction::AcceptStakingAuthority,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        fingerprint(&ctx.accounts.staking_pool.authority),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{accept_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AcceptVaultAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<AcceptVaultAdmin>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.vault_account.admin);
+    
+    let vault_account = &mut *ctx.accounts.vault_account;
+    
+    let previous = accept_handover(&mut vault_account.admin, &mut vault_account.pending_admin, ctx.accounts.new_admin.key())?;
+    
+    msg!("Vault admin transferred from {} to {}", previous, vault_account.admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.new_admin.key(),
+        AdminAction::AcceptVaultAdmin,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED};
+use crate::events::VaultFeesAccrued;
+use crate::utils::{calculate_management_fee, calculate_performance_fee};
+
+#[derive(Accounts)]
+pub struct AccrueVaultFees<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+}
+
+/// Charges management fees since the last accrual and performance fees above the
+/// high-water mark, moving them into the vault's protocol fee bucket
+/// Returns (management_fee, performance_fee)
+pub fn settle_vault_fees(vault_account: &mut VaultAccount, current_time: i64) -> Result<(u64, u64)> {
+    let elapsed = current_time - vault_account.last_management_fee_time;
+    let management_fee = calculate_management_fee(
+        vault_account.lp_assets(),
+        vault_account.management_fee_bps,
+        elapsed,
+    )?;
+    let management_fee = vault_account.charge_lp_fee(management_fee)?;
+    vault_account.last_management_fee_time = current_time;
+    
+    // Performance fees are charged on share value after management fees
+    let performance_fee = calculate_performance_fee(
+        vault_account.virtual_price(),
+        vault_account.high_water_mark,
+        vault_account.total_shares,
+        vault_account.performance_fee_bps,
+    )?;
+    let performance_fee = vault_account.charge_lp_fee(performance_fee)?;
+    
+    // Yield is only charged once: the mark follows the post-fee share value upward
+    vault_account.high_water_mark = vault_account.high_water_mark.max(vault_account.virtual_price());
+    
+    Ok((management_fee, performance_fee))
+}
+
+pub fn handler(ctx: Context<AccrueVaultFees>) -> Result<()> {
+    let vault_key = ctx.accounts.vault_account.key();
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let (management_fee, performance_fee) = settle_vault_fees(vault_account, current_time)?;
+    
+    emit!(VaultFeesAccrued {
+        vault: vault_key,
+        management_fee,
+        performance_fee,
+        virtual_price: vault_account.virtual_price(),
+        high_water_mark: vault_account.high_water_mark,
+    });
+    
+    msg!("Accrued vault fees: management {}, performance {}", management_fee, performance_fee);
+    
+    Ok(())
+} 
\ No newline at end of file
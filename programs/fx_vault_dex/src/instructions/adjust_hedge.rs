@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use crate::events::HedgeAdjusted;
+use crate::state::{VaultAccount, HedgeConfig, VAULT_ACCOUNT_SEED, HEDGE_CONFIG_SEED, HEDGE_AUTHORITY_SEED};
+
+#[derive(Accounts)]
+pub struct AdjustHedge<'info> {
+    #[account(
+        constraint = keeper.key() == hedge_config.keeper,
+    )]
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        mut,
+        seeds = [HEDGE_CONFIG_SEED, vault_account.key().as_ref()],
+        bump = hedge_config.bump,
+        constraint = hedge_config.vault == vault_account.key(),
+    )]
+    pub hedge_config: Account<'info, HedgeConfig>,
+    
+    /// CHECK: This is the hedge authority PDA that signs on the perp venue
+    #[account(
+        seeds = [HEDGE_AUTHORITY_SEED, hedge_config.key().as_ref()],
+        bump = hedge_config.authority_bump,
+    )]
+    pub hedge_authority: AccountInfo<'info>,
+    
+    /// CHECK: Must be the whitelisted perp program
+    #[account(
+        executable,
+        constraint = perp_program.key() == hedge_config.perp_program,
+    )]
+    pub perp_program: AccountInfo<'info>,
+    // The perp venue's accounts are passed through remaining_accounts
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AdjustHedge<'info>>,
+    target_hedge_notional: i64,
+    perp_instruction_data: Vec<u8>,
+) -> Result<()> {
+    let net_exposure = ctx.accounts.vault_account.net_swap_flow;
+    let hedge_config = &ctx.accounts.hedge_config;
+    let previous_hedge_notional = hedge_config.hedge_notional;
+    
+    // A hedge must offset the exposure: short when the protocol is long and vice versa
+    let target_abs = target_hedge_notional.unsigned_abs();
+    if target_hedge_notional != 0 {
+        require!(
+            (target_hedge_notional < 0) == (net_exposure > 0),
+            ErrorCode::WrongHedgeDirection
+        );
+    }
+    
+    // Never hedge more than the exposure or the configured maximum
+    require!(target_abs as u128 <= net_exposure.unsigned_abs(), ErrorCode::HedgeExceedsExposure);
+    require!(target_abs <= hedge_config.max_hedge_notional, ErrorCode::HedgeExceedsMax);
+    
+    // Growing the hedge requires the exposure threshold to be crossed; reducing it is always allowed
+    if target_abs > previous_hedge_notional.unsigned_abs() {
+        require!(
+            net_exposure.unsigned_abs() >= hedge_config.exposure_threshold as u128,
+            ErrorCode::BelowExposureThreshold
+        );
+    }
+    
+    // Forward the venue instruction, signed by the hedge authority
+    let hedge_authority_key = ctx.accounts.hedge_authority.key();
+    let account_metas: Vec<AccountMeta> = ctx.remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == hedge_authority_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    
+    let perp_instruction = Instruction {
+        program_id: ctx.accounts.perp_program.key(),
+        accounts: account_metas,
+        data: perp_instruction_data,
+    };
+    
+    let hedge_config_key = hedge_config.key();
+    let seeds = &[
+        HEDGE_AUTHORITY_SEED,
+        hedge_config_key.as_ref(),
+        &[hedge_config.authority_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let mut cpi_accounts = ctx.remaining_accounts.to_vec();
+    cpi_accounts.push(ctx.accounts.hedge_authority.to_account_info());
+    invoke_signed(&perp_instruction, &cpi_accounts, signer_seeds)?;
+    
+    // Record the new hedge
+    let hedge_config = &mut ctx.accounts.hedge_config;
+    hedge_config.hedge_notional = target_hedge_notional;
+    hedge_config.last_hedge_time = Clock::get()?.unix_timestamp;
+    hedge_config.hedge_adjustments = hedge_config.hedge_adjustments.saturating_add(1);
+    
+    emit!(HedgeAdjusted {
+        vault: ctx.accounts.vault_account.key(),
+        perp_program: ctx.accounts.perp_program.key(),
+        net_exposure,
+        previous_hedge_notional,
+        hedge_notional: target_hedge_notional,
+    });
+    
+    msg!("Adjusted hedge from {} to {} against net exposure {}",
+         previous_hedge_notional, target_hedge_notional, net_exposure);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Hedge must be in the opposite direction of the net exposure")]
+    WrongHedgeDirection,
+    
+    #[msg("Hedge cannot exceed the vault's net exposure")]
+    HedgeExceedsExposure,
+    
+    #[msg("Hedge exceeds the configured maximum notional")]
+    HedgeExceedsMax,
+    
+    #[msg("Net exposure is below the hedging threshold")]
+    BelowExposureThreshold,
+} 
\ No newline at end of file
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, Role, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AssignRole<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<AssignRole>, role: Role, holder: Pubkey) -> Result<()> {
+    // Revoking is done with revoke_role so the fallback to the default holder is explicit
+    require!(holder != Pubkey::default(), ErrorCode::InvalidRoleHolder);
+    
+    let old_value = fingerprint(&(role, ctx.accounts.vault_account.roles.get(role)));
+    
+    ctx.accounts.vault_account.roles.set(role, holder);
+    
+    msg!("Assigned role {:?} to {}", role, holder);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::AssignRole,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(role, holder)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Role holder cannot be the default key; use revoke_role instead")]
+    InvalidRoleHolder,
+} 
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::program::FxVaultDex;
+use crate::state::{AdminLog, AdminAction, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct AttestUpgradeAuthority<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    pub program: Program<'info, FxVaultDex>,
+    
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ErrorCode::InvalidProgramData,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+pub fn handler(ctx: Context<AttestUpgradeAuthority>) -> Result<()> {
+    // An immutable program has no upgrade authority and is recorded as the default key
+    let upgrade_authority = ctx.accounts.program_data.upgrade_authority_address.unwrap_or_default();
+    let admin_log = &mut ctx.accounts.admin_log;
+    
+    // Only changes are recorded so the log cannot be flooded with repeat attestations
+    let previous = admin_log.upgrade_authority;
+    require!(previous != Some(upgrade_authority), ErrorCode::UpgradeAuthorityUnchanged);
+    
+    admin_log.upgrade_authority = Some(upgrade_authority);
+    record_admin_action(
+        admin_log,
+        ctx.accounts.cranker.key(),
+        AdminAction::AttestUpgradeAuthority,
+        upgrade_authority,
+        previous.map(|key| fingerprint(&key)).unwrap_or(0),
+        fingerprint(&upgrade_authority),
+    )?;
+    
+    msg!("Attested upgrade authority {} (previously {:?})", upgrade_authority, previous);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Program data account does not belong to this program")]
+    InvalidProgramData,
+    
+    #[msg("Upgrade authority has not changed since the last attestation")]
+    UpgradeAuthorityUnchanged,
+} 
\ No newline at end of file
@@ -0,0 +1,324 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{VaultAccount, ProtocolConfig, PairConfig, PairState, VaultPair, UserStats, EmergencyState, KeeperHeartbeat, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED, PAIR_CONFIG_SEED, PAIR_STATE_SEED, VAULT_PAIR_SEED, USER_STATS_SEED, VAULT_AUTHORITY_SEED, EMERGENCY_STATE_SEED, KEEPER_HEARTBEAT_SEED};
+use crate::utils::{
+    trip_pair_circuit_breakers, price_pair_swap, settle_pair_swap, PairPricingAccounts, PairSettlementAccounts, calculate_vault_health,
+    check_transaction_guard
+};
+
+#[derive(Accounts)]
+pub struct BalanceIncentive<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    // Deficit vault (tokens going in)
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, source_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub source_vault: Account<'info, VaultAccount>,
+    
+    // Surplus vault (tokens going out, pays the bonus)
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, target_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub target_vault: Account<'info, VaultAccount>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    /// CHECK: Per-pair pricing overrides, may be uninitialized when the pair uses the global parameters
+    #[account(
+        seeds = [
+            PAIR_CONFIG_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_config: AccountInfo<'info>,
+    
+    // Shared price record for the pair, updated by swaps in either direction
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PairState::LEN,
+        seeds = [
+            PAIR_STATE_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_state: Box<Account<'info, PairState>>,
+    
+    // Admin-registered pair; swaps between vaults that were never paired are rejected
+    #[account(
+        mut,
+        seeds = [
+            VAULT_PAIR_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    /// CHECK: Bound to the source vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = source_oracle.key() == source_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub source_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the target vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = target_oracle.key() == target_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub target_oracle: AccountInfo<'info>,
+    
+    /// CHECK: This is the target vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, target_vault.key().as_ref()],
+        bump = target_vault.nonce,
+    )]
+    pub target_vault_authority: AccountInfo<'info>,
+    
+    // User token accounts
+    #[account(
+        mut,
+        constraint = user_source_token.mint == source_vault.token_mint,
+        constraint = user_source_token.owner == user.key(),
+    )]
+    pub user_source_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = user_target_token.mint == target_vault.token_mint,
+        constraint = user_target_token.owner == user.key(),
+    )]
+    pub user_target_token: Account<'info, TokenAccount>,
+    
+    // Vault token accounts
+    #[account(
+        mut,
+        constraint = source_vault_token.key() == source_vault.token_account,
+    )]
+    pub source_vault_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = target_vault_token.key() == target_vault.token_account,
+    )]
+    pub target_vault_token: Account<'info, TokenAccount>,
+    
+    // Global kill switch; swaps and deposits are rejected while it is on
+    #[account(
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+        constraint = !emergency_state.halted @ ErrorCode::EmergencyHalt,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    // Keeper liveness record; leaving it out counts as no heartbeat while monitoring is on
+    #[account(
+        seeds = [KEEPER_HEARTBEAT_SEED],
+        bump = keeper_heartbeat.bump,
+    )]
+    pub keeper_heartbeat: Option<Account<'info, KeeperHeartbeat>>,
+    
+    // Opt-in trader statistics, updated exactly as by swap
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStats::LEN,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_stats: Option<Box<Account<'info, UserStats>>>,
+}
+
+pub fn handler(
+    ctx: Context<BalanceIncentive>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.source_vault, &ctx.accounts.source_vault.key(), instructions_sysvar.as_ref())?;
+    check_transaction_guard(&ctx.accounts.target_vault, &ctx.accounts.target_vault.key(), instructions_sysvar.as_ref())?;
+    
+    let source_vault_key = ctx.accounts.source_vault.key();
+    let target_vault_key = ctx.accounts.target_vault.key();
+    
+    // A breaker trip or resume must persist, so a halted pair ends the swap without moving funds
+    if !trip_pair_circuit_breakers(
+        &mut ctx.accounts.source_vault,
+        source_vault_key,
+        &mut ctx.accounts.target_vault,
+        target_vault_key,
+        &ctx.accounts.source_oracle,
+        &ctx.accounts.target_oracle,
+    )? {
+        msg!("Balance incentive swap not executed: circuit breaker active");
+        return Ok(());
+    }
+    
+    let source_vault = &mut ctx.accounts.source_vault;
+    let target_vault = &mut ctx.accounts.target_vault;
+    
+    // Dust swaps bloat state and skew fee accounting
+    require!(amount_in >= source_vault.min_swap_amount, ErrorCode::BelowMinimumAmount);
+    require!(!source_vault.swaps_paused && !target_vault.swaps_paused, ErrorCode::SwapsPaused);
+    
+    // A wound-down vault only pays out; swapping into it would add new liquidity
+    require!(!source_vault.wound_down, ErrorCode::VaultWoundDown);
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Ensure the incentive is enabled on the paying vault
+    require!(
+        target_vault.balance_bonus_bps > 0 && target_vault.balance_target_health_bps > 0,
+        ErrorCode::IncentiveDisabled
+    );
+    
+    // Ensure the cooldown since the last bonus has elapsed
+    let time_since_bonus = current_time - target_vault.last_balance_bonus_time;
+    require!(
+        time_since_bonus >= target_vault.balance_bonus_cooldown,
+        ErrorCode::CooldownActive
+    );
+    
+    // Only flow into the deficit vault qualifies
+    let source_amount = source_vault.tvl;
+    let target_amount = target_vault.tvl;
+    require!(source_amount < target_amount, ErrorCode::NotDeficitCurrency);
+    
+    let target_health = target_vault.balance_target_health_bps as f64 / 10000.0;
+    let health_before = calculate_vault_health(source_amount, target_amount);
+    require!(health_before < target_health, ErrorCode::AlreadyBalanced);
+    
+    // Price the swap exactly like a regular swap
+    let pair_config = PairConfig::load(&ctx.accounts.pair_config)?;
+    let quote = price_pair_swap(
+        PairPricingAccounts {
+            source_vault,
+            target_vault,
+            source_oracle: &ctx.accounts.source_oracle,
+            target_oracle: &ctx.accounts.target_oracle,
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_config: pair_config.as_ref(),
+            pair_state: &mut ctx.accounts.pair_state,
+            vault_pair: &ctx.accounts.vault_pair,
+            keeper_heartbeat: ctx.accounts.keeper_heartbeat.as_deref(),
+        },
+        amount_in,
+    )?;
+    let amount_out = quote.amount_out;
+    
+    require!(amount_out >= minimum_amount_out, ErrorCode::SlippageExceeded);
+    
+    // The swap must push vault health above the target
+    let new_source_amount = source_amount.checked_add(amount_in).ok_or(ErrorCode::MathOverflow)?;
+    let new_target_amount = target_amount.checked_sub(amount_out).ok_or(ErrorCode::MathOverflow)?;
+    let health_after = calculate_vault_health(new_source_amount, new_target_amount);
+    require!(health_after >= target_health, ErrorCode::TargetHealthNotReached);
+    
+    // Bonus is a share of the amount out, capped and funded by accrued PDA fees
+    let bonus_amount = amount_out
+        .checked_mul(target_vault.balance_bonus_bps as u64)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::MathOverflow)?
+        .min(target_vault.balance_bonus_cap)
+        .min(target_vault.accrued_pda_fees);
+    
+    // Settle like a regular swap, paying the bonus out alongside the amount out
+    let total_out = amount_out.checked_add(bonus_amount).ok_or(ErrorCode::MathOverflow)?;
+    settle_pair_swap(
+        PairSettlementAccounts {
+            user: ctx.accounts.user.to_account_info(),
+            source_vault,
+            source_vault_key,
+            target_vault,
+            target_vault_key,
+            user_source_token: Some(ctx.accounts.user_source_token.to_account_info()),
+            source_vault_token: ctx.accounts.source_vault_token.to_account_info(),
+            target_vault_token: ctx.accounts.target_vault_token.to_account_info(),
+            user_target_token: ctx.accounts.user_target_token.to_account_info(),
+            target_vault_authority: ctx.accounts.target_vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_state: &mut ctx.accounts.pair_state,
+            pair_state_bump: *ctx.bumps.get("pair_state").unwrap(),
+            vault_pair: &mut ctx.accounts.vault_pair,
+            user_stats: ctx.accounts.user_stats.as_deref_mut().map(|user_stats| &mut **user_stats),
+            user_stats_bump: ctx.bumps.get("user_stats").copied().unwrap_or_default(),
+        },
+        &quote,
+        amount_in,
+        total_out,
+    )?;
+    
+    // The bonus is paid from the PDA fees the swap has just accrued to
+    target_vault.accrued_pda_fees = target_vault.accrued_pda_fees.checked_sub(bonus_amount).ok_or(ErrorCode::MathOverflow)?;
+    target_vault.last_balance_bonus_time = current_time;
+    target_vault.total_balance_bonus_paid = target_vault.total_balance_bonus_paid.checked_add(bonus_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Balance incentive: swapped {} for {} with {} bonus. Vault health improved from {:.4} to {:.4}",
+         amount_in, amount_out, bonus_amount, health_before, health_after);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Balance incentive is not enabled for this vault")]
+    IncentiveDisabled,
+    
+    #[msg("Balance incentive cooldown has not elapsed")]
+    CooldownActive,
+    
+    #[msg("Source currency is not the deficit side of the pair")]
+    NotDeficitCurrency,
+    
+    #[msg("Vault health is already above the target")]
+    AlreadyBalanced,
+    
+    #[msg("Swap does not push vault health above the target")]
+    TargetHealthNotReached,
+    
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+    
+    #[msg("Oracle account does not match the vault's feed")]
+    InvalidOracle,
+    
+    #[msg("Protocol is halted by the emergency switch; only withdrawals are open")]
+    EmergencyHalt,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Swaps through this vault are paused")]
+    SwapsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
+} 
\ No newline at end of file
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{KeeperRegistry, KeeperBond, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED};
+
+#[derive(Accounts)]
+pub struct BondKeeper<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = KeeperBond::LEN,
+        seeds = [KEEPER_BOND_SEED, keeper.key().as_ref()],
+        bump,
+    )]
+    pub keeper_bond: Account<'info, KeeperBond>,
+    
+    #[account(
+        mut,
+        constraint = keeper_token.mint == keeper_registry.bond_mint,
+        constraint = keeper_token.owner == keeper.key(),
+    )]
+    pub keeper_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = bond_vault.key() == keeper_registry.bond_vault,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BondKeeper>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    
+    let keeper_bond = &mut ctx.accounts.keeper_bond;
+    
+    // Initialize the bond on first use
+    if keeper_bond.keeper == Pubkey::default() {
+        keeper_bond.keeper = ctx.accounts.keeper.key();
+        keeper_bond.bump = *ctx.bumps.get("keeper_bond").unwrap();
+    }
+    
+    // Transfer bond tokens from the keeper to the bond vault
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.keeper_token.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.keeper.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    keeper_bond.bonded_amount = keeper_bond.bonded_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    let keeper_registry = &mut ctx.accounts.keeper_registry;
+    keeper_registry.total_bonded = keeper_registry.total_bonded.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Keeper {} bonded {} tokens ({} total, active: {})",
+         keeper_bond.keeper, amount, keeper_bond.bonded_amount, keeper_bond.is_active(keeper_registry.min_bond));
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Bond amount must be greater than zero")]
+    InvalidAmount,
+} 
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakingPool, AdminLog, AdminAction, STAKING_POOL_SEED, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct CancelSlash<'info> {
+    #[account(
+        constraint = authority.key() == staking_pool.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<CancelSlash>) -> Result<()> {
+    let old_value = ctx.accounts.staking_pool.pending_slash_amount;
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    require!(staking_pool.pending_slash_amount > 0, ErrorCode::NoSlashPending);
+    
+    msg!("Cancelled pending slash of {} staked tokens", staking_pool.pending_slash_amount);
+    
+    staking_pool.pending_slash_amount = 0;
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::CancelSlash,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        ctx.accounts.staking_pool.pending_slash_amount,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No slash is pending")]
+    NoSlashPending,
+} 
\ No newline at end of file
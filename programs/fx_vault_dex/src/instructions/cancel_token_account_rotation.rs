@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct CancelTokenAccountRotation<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<CancelTokenAccountRotation>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.vault_account.pending_token_account);
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    require!(vault_account.pending_token_account != Pubkey::default(), ErrorCode::NoRotationPending);
+    
+    msg!("Cancelled rotating the vault token account to {}", vault_account.pending_token_account);
+    
+    vault_account.pending_token_account = Pubkey::default();
+    vault_account.token_account_rotation_eta = 0;
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::CancelTokenAccountRotation,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.pending_token_account),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No token account rotation is pending")]
+    NoRotationPending,
+} 
\ No newline at end of file
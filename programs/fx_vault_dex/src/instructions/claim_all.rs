@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, LPPosition, VAULT_AUTHORITY_SEED};
+use crate::utils::{load_program_account, store_program_account};
+
+// Accounts per vault in remaining_accounts
+pub const CLAIM_ALL_GROUP_LEN: usize = 5;
+
+#[derive(Accounts)]
+pub struct ClaimAll<'info> {
+    pub user: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    // Each vault is passed through remaining_accounts as a group of
+    // (vault_account, vault_authority, vault_token_account, lp_position, user_token_account)
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % CLAIM_ALL_GROUP_LEN == 0,
+        ErrorCode::InvalidAccountGroups
+    );
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut claimed_vaults: Vec<Pubkey> = Vec::with_capacity(remaining_accounts.len() / CLAIM_ALL_GROUP_LEN);
+    let mut total_claimed: u64 = 0;
+    
+    for group in remaining_accounts.chunks(CLAIM_ALL_GROUP_LEN) {
+        let vault_authority = &group[1];
+        let vault_token_account = &group[2];
+        
+        let vault_key = group[0].key();
+        let mut vault_account: VaultAccount = load_program_account(&group[0], ctx.program_id)?;
+        let mut lp_position: LPPosition = load_program_account(&group[3], ctx.program_id)?;
+        let user_token_account: TokenAccount = load_program_account(&group[4], &token::ID)?;
+        
+        // A vault listed twice would be settled against stale state
+        require!(!claimed_vaults.contains(&vault_key), ErrorCode::DuplicateVault);
+        claimed_vaults.push(vault_key);
+        
+        // Validate the group the same way distribute_incentives does
+        let expected_authority = Pubkey::create_program_address(
+            &[VAULT_AUTHORITY_SEED, vault_key.as_ref(), &[vault_account.nonce]],
+            ctx.program_id,
+        ).map_err(|_| ErrorCode::InvalidVaultAuthority)?;
+        require_keys_eq!(vault_authority.key(), expected_authority, ErrorCode::InvalidVaultAuthority);
+        require_keys_eq!(vault_token_account.key(), vault_account.token_account, ErrorCode::InvalidVaultTokenAccount);
+        require_keys_eq!(lp_position.vault, vault_key, ErrorCode::PositionVaultMismatch);
+        require!(lp_position.is_owner_or_manager(&ctx.accounts.user.key()), ErrorCode::Unauthorized);
+        require!(!lp_position.is_protocol, ErrorCode::ProtocolPosition);
+        
+        // Rewards are always paid to the position owner, even when a manager claims
+        require_keys_eq!(user_token_account.mint, vault_account.token_mint, ErrorCode::InvalidUserTokenAccount);
+        require_keys_eq!(user_token_account.owner, lp_position.owner, ErrorCode::InvalidUserTokenAccount);
+        
+        // Vesting needs the position's schedule account, which this batch does not carry
+        require!(!vault_account.retain_earnings, ErrorCode::RetainedEarningsMode);
+        require!(vault_account.reward_vesting_duration == 0, ErrorCode::VestingVault);
+        
+        // Positions with nothing to claim are skipped rather than failing the batch
+        if vault_account.accrued_lp_fees == 0 || lp_position.shares == 0 {
+            continue;
+        }
+        
+        let (reward_amount, shares_burned) = vault_account.harvest_fee_share(lp_position.shares)?;
+        if reward_amount == 0 {
+            continue;
+        }
+        
+        lp_position.shares = lp_position.shares.checked_sub(shares_burned).ok_or(ErrorCode::MathOverflow)?;
+        lp_position.rewards_claimed = lp_position.rewards_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+        lp_position.last_rewards_claim_time = current_time;
+        
+        // Transfer tokens from vault to the position owner
+        let seeds = &[
+            VAULT_AUTHORITY_SEED,
+            vault_key.as_ref(),
+            &[vault_account.nonce],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_cpi_accounts = Transfer {
+            from: vault_token_account.clone(),
+            to: group[4].clone(),
+            authority: vault_authority.clone(),
+        };
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_cpi_accounts,
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, reward_amount)?;
+        
+        // Persist the updated vault and position
+        store_program_account(&vault_account, &group[0])?;
+        store_program_account(&lp_position, &group[3])?;
+        
+        total_claimed = total_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+        
+        msg!("Distributed {} tokens in rewards from vault {}", reward_amount, vault_key);
+    }
+    
+    require!(total_claimed > 0, ErrorCode::NoFeesToClaim);
+    
+    msg!("Claimed rewards from {} vaults", claimed_vaults.len());
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Remaining accounts must be non-empty groups of five accounts per vault")]
+    InvalidAccountGroups,
+    
+    #[msg("A vault appears more than once in the batch")]
+    DuplicateVault,
+    
+    #[msg("Vault authority does not match the vault")]
+    InvalidVaultAuthority,
+    
+    #[msg("Vault token account does not match the vault")]
+    InvalidVaultTokenAccount,
+    
+    #[msg("LP position does not belong to the vault")]
+    PositionVaultMismatch,
+    
+    #[msg("Only the position owner or its manager can claim rewards")]
+    Unauthorized,
+    
+    #[msg("Reward token account must be the position owner's account for the vault mint")]
+    InvalidUserTokenAccount,
+    
+    #[msg("Vault reinvests LP fees; they accrue to share value instead of being claimed")]
+    RetainedEarningsMode,
+    
+    #[msg("Vault vests rewards; claim it with distribute_incentives")]
+    VestingVault,
+    
+    #[msg("No fees available to claim")]
+    NoFeesToClaim,
+    
+    #[msg("Protocol-owned liquidity earns no incentives; its yield is paid to the treasury on withdrawal")]
+    ProtocolPosition,
+} 
\ No newline at end of file
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{StakingPool, StakePosition, STAKING_POOL_SEED, STAKE_POSITION_SEED};
+
+#[derive(Accounts)]
+pub struct ClaimStakingRewards<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == user.key(),
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        constraint = user_reward_token.mint == staking_pool.reward_mint,
+        constraint = user_reward_token.owner == user.key(),
+    )]
+    pub user_reward_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = reward_vault.key() == staking_pool.reward_vault,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimStakingRewards>) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    
+    stake_position.settle(staking_pool.reward_index, staking_pool.slash_factor)?;
+    
+    let reward_amount = stake_position.pending_rewards;
+    require!(reward_amount > 0, ErrorCode::NothingToClaim);
+    
+    // Transfer rewards from the reward vault to the staker
+    let stake_mint = staking_pool.stake_mint;
+    let seeds = &[
+        STAKING_POOL_SEED,
+        stake_mint.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.reward_vault.to_account_info(),
+        to: ctx.accounts.user_reward_token.to_account_info(),
+        authority: staking_pool.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, reward_amount)?;
+    
+    stake_position.pending_rewards = 0;
+    stake_position.rewards_claimed = stake_position.rewards_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Claimed {} tokens in staking rewards", reward_amount);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("No staking rewards available to claim")]
+    NothingToClaim,
+} 
\ No newline at end of file
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, VestingSchedule, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, VESTING_SCHEDULE_SEED};
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VESTING_SCHEDULE_SEED, vault_account.key().as_ref(), user.key().as_ref()],
+        bump = vesting_schedule.bump,
+        constraint = vesting_schedule.owner == user.key(),
+        constraint = vesting_schedule.vault == vault_account.key(),
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+    
+    #[account(
+        mut,
+        constraint = user_token_account.mint == vault_account.token_mint,
+        constraint = user_token_account.owner == user.key(),
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimVested>, claim_unvested: bool) -> Result<()> {
+    let vault_account = &mut ctx.accounts.vault_account;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    
+    // Bring the schedule up to date
+    vesting_schedule.release(Clock::get()?.unix_timestamp)?;
+    
+    let mut claim_amount = vesting_schedule.unlocked_amount;
+    vesting_schedule.unlocked_amount = 0;
+    
+    // Optionally claim the still-locked rewards early, paying a penalty
+    let mut penalty_amount = 0;
+    if claim_unvested {
+        let unvested = vesting_schedule.locked_amount
+            .checked_sub(vesting_schedule.released_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        penalty_amount = unvested
+            .checked_mul(vault_account.early_claim_penalty_bps as u64)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(10000)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        claim_amount = claim_amount
+            .checked_add(unvested - penalty_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        vesting_schedule.locked_amount = 0;
+        vesting_schedule.released_amount = 0;
+        vesting_schedule.end_time = vesting_schedule.start_time;
+    }
+    
+    require!(claim_amount > 0, ErrorCode::NothingToClaim);
+    
+    // Transfer vested rewards from vault to user
+    let bump = vault_account.nonce;
+    let vault_key = vault_account.key();
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, claim_amount)?;
+    
+    // Early-claim penalties go back to the remaining LPs
+    let released_from_vesting = claim_amount.checked_add(penalty_amount).ok_or(ErrorCode::MathOverflow)?;
+    vault_account.vesting_rewards_outstanding = vault_account.vesting_rewards_outstanding.checked_sub(released_from_vesting).ok_or(ErrorCode::MathOverflow)?;
+    vault_account.accrued_lp_fees = vault_account.accrued_lp_fees.checked_add(penalty_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    vesting_schedule.total_claimed = vesting_schedule.total_claimed.checked_add(claim_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Claimed {} vested reward tokens (early-claim penalty: {})", claim_amount, penalty_amount);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("No vested rewards available to claim")]
+    NothingToClaim,
+} 
\ No newline at end of file
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureCircuitBreaker<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureCircuitBreaker>,
+    max_price_deviation_bps: u16,
+    cooldown_slots: u64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.max_price_deviation_bps, ctx.accounts.vault_account.breaker_cooldown_slots));
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    require!(max_price_deviation_bps <= 10000, ErrorCode::InvalidDeviation);
+    
+    vault_account.max_price_deviation_bps = max_price_deviation_bps;
+    vault_account.breaker_cooldown_slots = cooldown_slots;
+    
+    // Reconfiguring clears a tripped breaker, e.g. after the price has settled at a new level
+    vault_account.breaker_tripped = false;
+    vault_account.halt_until_slot = 0;
+    vault_account.breaker_reference_price = 0;
+    
+    msg!("Circuit breaker configured: max deviation {} bps, cooldown {} slots",
+         max_price_deviation_bps, cooldown_slots);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureCircuitBreaker,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.max_price_deviation_bps, ctx.accounts.vault_account.breaker_cooldown_slots)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Max price deviation must be at most 10000 basis points")]
+    InvalidDeviation,
+} 
\ No newline at end of file
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, HedgeConfig, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, HEDGE_CONFIG_SEED, HEDGE_AUTHORITY_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureHedge<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = HedgeConfig::LEN,
+        seeds = [HEDGE_CONFIG_SEED, vault_account.key().as_ref()],
+        bump,
+    )]
+    pub hedge_config: Account<'info, HedgeConfig>,
+    
+    /// CHECK: This is the hedge authority PDA that holds treasury collateral on the perp venue
+    #[account(
+        seeds = [HEDGE_AUTHORITY_SEED, hedge_config.key().as_ref()],
+        bump,
+    )]
+    pub hedge_authority: AccountInfo<'info>,
+    
+    /// CHECK: Whitelisted perp program; must be executable
+    #[account(executable)]
+    pub perp_program: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureHedge>,
+    keeper: Pubkey,
+    exposure_threshold: u64,
+    max_hedge_notional: u64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.hedge_config.perp_program, ctx.accounts.hedge_config.keeper, ctx.accounts.hedge_config.exposure_threshold, ctx.accounts.hedge_config.max_hedge_notional));
+    
+    let hedge_config = &mut ctx.accounts.hedge_config;
+    
+    // Initialize the hedge config on first use
+    if hedge_config.vault == Pubkey::default() {
+        hedge_config.vault = ctx.accounts.vault_account.key();
+        hedge_config.bump = *ctx.bumps.get("hedge_config").unwrap();
+        hedge_config.authority_bump = *ctx.bumps.get("hedge_authority").unwrap();
+    }
+    
+    // The venue can only change while no hedge is open on the old one
+    if hedge_config.perp_program != ctx.accounts.perp_program.key() {
+        require!(hedge_config.hedge_notional == 0, ErrorCode::HedgeOpen);
+    }
+    require!(max_hedge_notional <= i64::MAX as u64, ErrorCode::InvalidMaxHedge);
+    
+    hedge_config.perp_program = ctx.accounts.perp_program.key();
+    hedge_config.keeper = keeper;
+    hedge_config.exposure_threshold = exposure_threshold;
+    hedge_config.max_hedge_notional = max_hedge_notional;
+    
+    msg!("Hedge configured on {}: keeper {}, threshold {}, max notional {}",
+         ctx.accounts.perp_program.key(), keeper, exposure_threshold, max_hedge_notional);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureHedge,
+        ctx.accounts.hedge_config.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.hedge_config.perp_program, ctx.accounts.hedge_config.keeper, ctx.accounts.hedge_config.exposure_threshold, ctx.accounts.hedge_config.max_hedge_notional)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Cannot change the perp program while a hedge is open")]
+    HedgeOpen,
+    
+    #[msg("Max hedge notional is too large")]
+    InvalidMaxHedge,
+} 
\ No newline at end of file
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, MAX_LAUNCH_SPREAD_BPS, MAX_LAUNCH_WITHDRAWAL_FEE_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureLaunchMode<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureLaunchMode>,
+    spread_bps: u16,
+    withdrawal_fee_bps: u16,
+    duration: i64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.launch_duration, ctx.accounts.vault_account.launch_spread_bps, ctx.accounts.vault_account.launch_withdrawal_fee_bps));
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    // Validate the launch parameters
+    require!(spread_bps <= MAX_LAUNCH_SPREAD_BPS, ErrorCode::SpreadTooHigh);
+    require!(withdrawal_fee_bps <= MAX_LAUNCH_WITHDRAWAL_FEE_BPS, ErrorCode::WithdrawalFeeTooHigh);
+    require!(duration >= 0, ErrorCode::InvalidDuration);
+    
+    // The launch phase starts now and decays linearly over the duration
+    vault_account.launch_start_time = Clock::get()?.unix_timestamp;
+    vault_account.launch_duration = duration;
+    vault_account.launch_spread_bps = spread_bps;
+    vault_account.launch_withdrawal_fee_bps = withdrawal_fee_bps;
+    
+    msg!("Launch mode configured: spread {} bps, withdrawal fee {} bps, decaying over {}s",
+         spread_bps, withdrawal_fee_bps, duration);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureLaunchMode,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.launch_duration, ctx.accounts.vault_account.launch_spread_bps, ctx.accounts.vault_account.launch_withdrawal_fee_bps)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Launch spread is too high, maximum is 5%")]
+    SpreadTooHigh,
+    
+    #[msg("Launch withdrawal fee is too high, maximum is 10%")]
+    WithdrawalFeeTooHigh,
+    
+    #[msg("Launch duration cannot be negative")]
+    InvalidDuration,
+} 
\ No newline at end of file
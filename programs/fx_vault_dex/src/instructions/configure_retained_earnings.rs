@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureRetainedEarnings<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureRetainedEarnings>,
+    retain_earnings: bool,
+    fee_fold_interval: i64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.retain_earnings, ctx.accounts.vault_account.fee_fold_interval));
+    
+    require!(fee_fold_interval >= 0, ErrorCode::InvalidInterval);
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    vault_account.retain_earnings = retain_earnings;
+    vault_account.fee_fold_interval = fee_fold_interval;
+    
+    msg!("Retained-earnings mode {}, fold interval {} seconds",
+         if retain_earnings { "enabled" } else { "disabled" }, fee_fold_interval);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureRetainedEarnings,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.retain_earnings, ctx.accounts.vault_account.fee_fold_interval)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fold interval cannot be negative")]
+    InvalidInterval,
+} 
\ No newline at end of file
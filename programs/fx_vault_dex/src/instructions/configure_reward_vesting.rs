@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, MAX_EARLY_CLAIM_PENALTY_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureRewardVesting<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureRewardVesting>,
+    vesting_duration: i64,
+    early_claim_penalty_bps: u16,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.reward_vesting_duration, ctx.accounts.vault_account.early_claim_penalty_bps));
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    // Validate the vesting parameters
+    require!(vesting_duration >= 0, ErrorCode::InvalidDuration);
+    require!(early_claim_penalty_bps <= MAX_EARLY_CLAIM_PENALTY_BPS, ErrorCode::PenaltyTooHigh);
+    
+    vault_account.reward_vesting_duration = vesting_duration;
+    vault_account.early_claim_penalty_bps = early_claim_penalty_bps;
+    
+    msg!("Reward vesting configured: {}s linear vesting, {} bps early-claim penalty",
+         vesting_duration, early_claim_penalty_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureRewardVesting,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.reward_vesting_duration, ctx.accounts.vault_account.early_claim_penalty_bps)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vesting duration cannot be negative")]
+    InvalidDuration,
+    
+    #[msg("Early-claim penalty is too high, maximum is 50%")]
+    PenaltyTooHigh,
+} 
\ No newline at end of file
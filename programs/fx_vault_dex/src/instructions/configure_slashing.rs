@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakingPool, AdminLog, AdminAction, STAKING_POOL_SEED, MAX_SLASH_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureSlashing<'info> {
+    #[account(
+        constraint = authority.key() == staking_pool.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureSlashing>,
+    max_slash_bps: u16,
+    slash_timelock: i64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.staking_pool.max_slash_bps, ctx.accounts.staking_pool.slash_timelock));
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    // Validate the slashing parameters
+    require!(max_slash_bps <= MAX_SLASH_BPS, ErrorCode::SlashTooHigh);
+    require!(slash_timelock >= 0, ErrorCode::InvalidTimelock);
+    require!(staking_pool.pending_slash_amount == 0, ErrorCode::SlashPending);
+    
+    staking_pool.max_slash_bps = max_slash_bps;
+    staking_pool.slash_timelock = slash_timelock;
+    
+    msg!("Slashing configured: max {} bps per slash, {}s timelock", max_slash_bps, slash_timelock);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::ConfigureSlashing,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.staking_pool.max_slash_bps, ctx.accounts.staking_pool.slash_timelock)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Max slash is too high, maximum is 50%")]
+    SlashTooHigh,
+    
+    #[msg("Slash timelock cannot be negative")]
+    InvalidTimelock,
+    
+    #[msg("Cannot change slashing parameters while a slash is pending")]
+    SlashPending,
+} 
\ No newline at end of file
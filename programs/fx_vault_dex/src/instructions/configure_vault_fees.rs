@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, MAX_MANAGEMENT_FEE_BPS, MAX_PERFORMANCE_FEE_BPS, ADMIN_LOG_SEED};
+use crate::instructions::accrue_vault_fees::settle_vault_fees;
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ConfigureVaultFees<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<ConfigureVaultFees>,
+    management_fee_bps: u16,
+    performance_fee_bps: u16,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.management_fee_bps, ctx.accounts.vault_account.performance_fee_bps));
+    
+    require!(management_fee_bps <= MAX_MANAGEMENT_FEE_BPS, ErrorCode::ManagementFeeTooHigh);
+    require!(performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS, ErrorCode::PerformanceFeeTooHigh);
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    // Settle fees at the old rates so the new rates only apply from now on
+    let current_time = Clock::get()?.unix_timestamp;
+    settle_vault_fees(vault_account, current_time)?;
+    
+    vault_account.management_fee_bps = management_fee_bps;
+    vault_account.performance_fee_bps = performance_fee_bps;
+    
+    msg!("Vault fees configured: management {} bps per year, performance {} bps",
+         management_fee_bps, performance_fee_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConfigureVaultFees,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.management_fee_bps, ctx.accounts.vault_account.performance_fee_bps)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Management fee is too high, maximum is 5% per year")]
+    ManagementFeeTooHigh,
+    
+    #[msg("Performance fee is too high, maximum is 30%")]
+    PerformanceFeeTooHigh,
+} 
\ No newline at end of file
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use crate::state::{
+    VaultAccount, ProtocolConfig, VaultPair, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED,
+    VAULT_PAIR_SEED, ADMIN_LOG_SEED, PRICE_SCALE
+};
+use crate::events::FeesConsolidated;
+use crate::utils::{get_oracle_price, calculate_cross_rate, record_admin_action, check_transaction_guard};
+
+#[derive(Accounts)]
+pub struct ConsolidateFees<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Vault whose protocol fees are converted
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, source_vault.token_mint.as_ref()],
+        bump,
+        constraint = source_vault.token_mint != protocol_config.treasury_mint @ ErrorCode::AlreadyTreasuryCurrency,
+    )]
+    pub source_vault: Account<'info, VaultAccount>,
+    
+    // Vault of the configured treasury currency
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, treasury_vault.token_mint.as_ref()],
+        bump,
+        constraint = treasury_vault.token_mint == protocol_config.treasury_mint @ ErrorCode::NotTreasuryCurrency,
+    )]
+    pub treasury_vault: Account<'info, VaultAccount>,
+    
+    // Conversions only run through registered pairs
+    #[account(
+        seeds = [
+            VAULT_PAIR_SEED,
+            source_vault.token_mint.min(treasury_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(treasury_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    /// CHECK: Bound to the source vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = source_oracle.key() == source_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub source_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the treasury vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = treasury_oracle.key() == treasury_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub treasury_oracle: AccountInfo<'info>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<ConsolidateFees>) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.source_vault, &ctx.accounts.source_vault.key(), instructions_sysvar.as_ref())?;
+    check_transaction_guard(&ctx.accounts.treasury_vault, &ctx.accounts.treasury_vault.key(), instructions_sysvar.as_ref())?;
+    
+    let oracle_time = Clock::get()?.unix_timestamp;
+    let source_price = get_oracle_price(&ctx.accounts.source_oracle, oracle_time)?;
+    let treasury_price = get_oracle_price(&ctx.accounts.treasury_oracle, oracle_time)?;
+    let oracle_price = calculate_cross_rate(source_price.price, treasury_price.price)?;
+    
+    let source_vault = &mut ctx.accounts.source_vault;
+    let treasury_vault = &mut ctx.accounts.treasury_vault;
+    let old_value = treasury_vault.accrued_protocol_fees;
+    
+    let amount_in = source_vault.accrued_protocol_fees;
+    require!(amount_in > 0, ErrorCode::NoFeesToConsolidate);
+    
+    // Convert at the oracle rate with no spread, drift or impact
+    let amount_out = (amount_in as u128)
+        .checked_mul(oracle_price as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(PRICE_SCALE as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let amount_out = u64::try_from(amount_out).map_err(|_| ErrorCode::MathOverflow)?;
+    require!(treasury_vault.tvl >= amount_out, ErrorCode::InsufficientLiquidity);
+    
+    // The protocol swaps with its own pools: the fee tokens stay in the source vault as
+    // liquidity, and the same value of treasury currency is set aside as protocol fees
+    source_vault.accrued_protocol_fees = 0;
+    source_vault.tvl = source_vault.tvl.checked_add(amount_in).ok_or(ErrorCode::MathOverflow)?;
+    source_vault.record_swap_flow(amount_in, 0);
+    
+    treasury_vault.tvl -= amount_out;
+    treasury_vault.accrued_protocol_fees = treasury_vault.accrued_protocol_fees
+        .checked_add(amount_out)
+        .ok_or(ErrorCode::MathOverflow)?;
+    treasury_vault.record_swap_flow(0, amount_out);
+    
+    emit!(FeesConsolidated {
+        source_vault: source_vault.key(),
+        treasury_vault: treasury_vault.key(),
+        amount_in,
+        amount_out,
+        oracle_price,
+    });
+    
+    msg!("Consolidated {} protocol fee tokens into {} treasury currency tokens", amount_in, amount_out);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ConsolidateFees,
+        ctx.accounts.source_vault.key(),
+        old_value,
+        ctx.accounts.treasury_vault.accrued_protocol_fees,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Source vault already holds the treasury currency")]
+    AlreadyTreasuryCurrency,
+    
+    #[msg("Treasury vault does not hold the configured treasury currency")]
+    NotTreasuryCurrency,
+    
+    #[msg("Oracle account does not match the vault's feed")]
+    InvalidOracle,
+    
+    #[msg("Source vault has no protocol fees to consolidate")]
+    NoFeesToConsolidate,
+    
+    #[msg("Insufficient liquidity in the treasury vault")]
+    InsufficientLiquidity,
+} 
\ No newline at end of file
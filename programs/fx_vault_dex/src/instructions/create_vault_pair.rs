@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ProtocolConfig, VaultPair, VaultAccount, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED,
+    VAULT_PAIR_SEED, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED
+};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct CreateVaultPair<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // The two vaults of the pair, ordered by mint
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_a.token_mint.as_ref()],
+        bump,
+        constraint = vault_a.token_mint < vault_b.token_mint @ ErrorCode::VaultsNotOrdered,
+    )]
+    pub vault_a: Account<'info, VaultAccount>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_b.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_b: Account<'info, VaultAccount>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = VaultPair::LEN,
+        seeds = [VAULT_PAIR_SEED, vault_a.token_mint.as_ref(), vault_b.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<CreateVaultPair>,
+    base_is_a: bool,
+) -> Result<()> {
+    let vault_pair = &mut ctx.accounts.vault_pair;
+    vault_pair.vault_a = ctx.accounts.vault_a.key();
+    vault_pair.vault_b = ctx.accounts.vault_b.key();
+    vault_pair.mint_a = ctx.accounts.vault_a.token_mint;
+    vault_pair.mint_b = ctx.accounts.vault_b.token_mint;
+    vault_pair.base_is_a = base_is_a;
+    vault_pair.bump = *ctx.bumps.get("vault_pair").unwrap();
+    vault_pair.created_at = Clock::get()?.unix_timestamp;
+    
+    let (base_mint, quote_mint) = vault_pair.orientation();
+    msg!("Vault pair registered: {} / {}", base_mint, quote_mint);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::CreateVaultPair,
+        ctx.accounts.vault_pair.key(),
+        0,
+        base_is_a as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vault A's mint must sort before vault B's mint")]
+    VaultsNotOrdered,
+} 
\ No newline at end of file
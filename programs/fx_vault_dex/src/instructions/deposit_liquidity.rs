@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VaultAccount, LPPosition, VAULT_ACCOUNT_SEED, LP_POSITION_SEED};
+use crate::state::{VaultAccount, LPPosition, EmergencyState, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, EMERGENCY_STATE_SEED};
+use crate::utils::check_transaction_guard;
 
 #[derive(Accounts)]
 pub struct DepositLiquidity<'info> {
+    // Account order is frozen for integrators (see interface.rs); append new accounts at the end
     #[account(mut)]
     pub user: Signer<'info>,
     
@@ -14,12 +17,16 @@ pub struct DepositLiquidity<'info> {
     )]
     pub vault_account: Account<'info, VaultAccount>,
     
+    /// CHECK: Owner of the LP position, only used to derive its address
+    pub position_owner: AccountInfo<'info>,
+    
+    // LP position, created on the owner's first deposit
     #[account(
-        mut, 
-        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), user.key().as_ref()],
+        init_if_needed,
+        payer = user,
+        space = LPPosition::LEN,
+        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), position_owner.key().as_ref()],
         bump,
-        constraint = lp_position.owner == user.key(),
-        constraint = lp_position.vault == vault_account.key(),
     )]
     pub lp_position: Account<'info, LPPosition>,
     
@@ -36,14 +43,44 @@ pub struct DepositLiquidity<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
     
+    // Global kill switch; swaps and deposits are rejected while it is on
+    #[account(
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+        constraint = !emergency_state.halted @ ErrorCode::EmergencyHalt,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.vault_account, &ctx.accounts.vault_account.key(), instructions_sysvar.as_ref())?;
+    
     let vault_account = &mut ctx.accounts.vault_account;
     let lp_position = &mut ctx.accounts.lp_position;
     
+    require!(!vault_account.deposits_paused, ErrorCode::DepositsPaused);
+    require!(!vault_account.wound_down, ErrorCode::VaultWoundDown);
+    require!(amount >= vault_account.min_deposit_amount, ErrorCode::BelowMinimumAmount);
+    
+    // Only the owner can open a position; afterwards its manager may deposit too
+    if lp_position.owner == Pubkey::default() {
+        require!(ctx.accounts.user.key() == ctx.accounts.position_owner.key(), ErrorCode::Unauthorized);
+        lp_position.owner = ctx.accounts.position_owner.key();
+        lp_position.vault = vault_account.key();
+        lp_position.bump = *ctx.bumps.get("lp_position").unwrap();
+    } else {
+        require!(lp_position.is_owner_or_manager(&ctx.accounts.user.key()), ErrorCode::Unauthorized);
+    }
+    
     // Transfer tokens from user to vault
     let transfer_cpi_accounts = Transfer {
         from: ctx.accounts.user_token_account.to_account_info(),
@@ -58,14 +95,14 @@ pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
     
     token::transfer(cpi_ctx, amount)?;
     
-    // Update the vault's total value locked
-    vault_account.tvl = vault_account.tvl.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    // Add the deposit to TVL and mint shares at the current exchange rate
+    let shares = vault_account.issue_shares(amount)?;
     
     // Update the LP's position
-    lp_position.amount = lp_position.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    lp_position.shares = lp_position.shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
     lp_position.last_deposit_time = Clock::get()?.unix_timestamp;
     
-    msg!("Deposited {} tokens into vault", amount);
+    msg!("Deposited {} tokens into vault for {} shares", amount, shares);
     
     Ok(())
 }
@@ -74,4 +111,19 @@ pub fn handler(ctx: Context<DepositLiquidity>, amount: u64) -> Result<()> {
 pub enum ErrorCode {
     #[msg("Math operation resulted in overflow")]
     MathOverflow,
+    
+    #[msg("Only the position owner or its manager can deposit")]
+    Unauthorized,
+    
+    #[msg("Protocol is halted by the emergency switch; only withdrawals are open")]
+    EmergencyHalt,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Deposits into this vault are paused")]
+    DepositsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
 } 
\ No newline at end of file
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, LPPosition, EmergencyState, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, EMERGENCY_STATE_SEED};
+use crate::utils::check_transaction_guard;
+
+#[derive(Accounts)]
+pub struct DepositPair<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    // First vault of the pair
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_a.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_a: Account<'info, VaultAccount>,
+    
+    // Second vault of the pair
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_b.token_mint.as_ref()],
+        bump,
+        constraint = vault_b.key() != vault_a.key() @ ErrorCode::SameVault,
+    )]
+    pub vault_b: Account<'info, VaultAccount>,
+    
+    // LP positions, created on the first deposit
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LPPosition::LEN,
+        seeds = [LP_POSITION_SEED, vault_a.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub lp_position_a: Account<'info, LPPosition>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = LPPosition::LEN,
+        seeds = [LP_POSITION_SEED, vault_b.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub lp_position_b: Account<'info, LPPosition>,
+    
+    // User token accounts
+    #[account(
+        mut,
+        constraint = user_token_a.mint == vault_a.token_mint,
+        constraint = user_token_a.owner == user.key(),
+    )]
+    pub user_token_a: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = user_token_b.mint == vault_b.token_mint,
+        constraint = user_token_b.owner == user.key(),
+    )]
+    pub user_token_b: Account<'info, TokenAccount>,
+    
+    // Vault token accounts
+    #[account(
+        mut,
+        constraint = vault_token_a.key() == vault_a.token_account,
+    )]
+    pub vault_token_a: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_b.key() == vault_b.token_account,
+    )]
+    pub vault_token_b: Account<'info, TokenAccount>,
+    
+    // Global kill switch; swaps and deposits are rejected while it is on
+    #[account(
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+        constraint = !emergency_state.halted @ ErrorCode::EmergencyHalt,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<DepositPair>, amount_a: u64, amount_b: u64) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.vault_a, &ctx.accounts.vault_a.key(), instructions_sysvar.as_ref())?;
+    check_transaction_guard(&ctx.accounts.vault_b, &ctx.accounts.vault_b.key(), instructions_sysvar.as_ref())?;
+    
+    require!(amount_a > 0 && amount_b > 0, ErrorCode::InvalidAmount);
+    require!(
+        !ctx.accounts.vault_a.deposits_paused && !ctx.accounts.vault_b.deposits_paused,
+        ErrorCode::DepositsPaused
+    );
+    require!(
+        !ctx.accounts.vault_a.wound_down && !ctx.accounts.vault_b.wound_down,
+        ErrorCode::VaultWoundDown
+    );
+    require!(
+        amount_a >= ctx.accounts.vault_a.min_deposit_amount && amount_b >= ctx.accounts.vault_b.min_deposit_amount,
+        ErrorCode::BelowMinimumAmount
+    );
+    
+    let user_key = ctx.accounts.user.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // 1. Transfer both legs from the user to the vaults
+    let transfer_a_accounts = Transfer {
+        from: ctx.accounts.user_token_a.to_account_info(),
+        to: ctx.accounts.vault_token_a.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    
+    let cpi_ctx_a = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_a_accounts,
+    );
+    
+    token::transfer(cpi_ctx_a, amount_a)?;
+    
+    let transfer_b_accounts = Transfer {
+        from: ctx.accounts.user_token_b.to_account_info(),
+        to: ctx.accounts.vault_token_b.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    
+    let cpi_ctx_b = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_b_accounts,
+    );
+    
+    token::transfer(cpi_ctx_b, amount_b)?;
+    
+    // 2. Mint shares in both vaults at their current exchange rates
+    let shares_a = ctx.accounts.vault_a.issue_shares(amount_a)?;
+    let shares_b = ctx.accounts.vault_b.issue_shares(amount_b)?;
+    
+    // 3. Update both LP positions
+    let lp_position_a = &mut ctx.accounts.lp_position_a;
+    if lp_position_a.owner == Pubkey::default() {
+        lp_position_a.owner = user_key;
+        lp_position_a.vault = ctx.accounts.vault_a.key();
+        lp_position_a.bump = *ctx.bumps.get("lp_position_a").unwrap();
+    }
+    lp_position_a.shares = lp_position_a.shares.checked_add(shares_a).ok_or(ErrorCode::MathOverflow)?;
+    lp_position_a.last_deposit_time = current_time;
+    
+    let lp_position_b = &mut ctx.accounts.lp_position_b;
+    if lp_position_b.owner == Pubkey::default() {
+        lp_position_b.owner = user_key;
+        lp_position_b.vault = ctx.accounts.vault_b.key();
+        lp_position_b.bump = *ctx.bumps.get("lp_position_b").unwrap();
+    }
+    lp_position_b.shares = lp_position_b.shares.checked_add(shares_b).ok_or(ErrorCode::MathOverflow)?;
+    lp_position_b.last_deposit_time = current_time;
+    
+    msg!("Deposited {} and {} tokens into the vault pair for {} and {} shares",
+         amount_a, amount_b, shares_a, shares_b);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Both deposits must be large enough to mint shares")]
+    InvalidAmount,
+    
+    #[msg("A pair deposit needs two different vaults")]
+    SameVault,
+    
+    #[msg("Protocol is halted by the emergency switch; only withdrawals are open")]
+    EmergencyHalt,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Deposits into this vault are paused")]
+    DepositsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
+} 
\ No newline at end of file
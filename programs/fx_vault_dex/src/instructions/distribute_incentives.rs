@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VaultAccount, LPPosition, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, VAULT_AUTHORITY_SEED};
-use crate::utils::calculate_lp_rewards;
+use crate::state::{VaultAccount, LPPosition, VestingSchedule, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, VAULT_AUTHORITY_SEED, VESTING_SCHEDULE_SEED};
 
 #[derive(Accounts)]
 pub struct DistributeIncentives<'info> {
@@ -24,17 +23,29 @@ pub struct DistributeIncentives<'info> {
     
     #[account(
         mut, 
-        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), user.key().as_ref()],
+        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), lp_position.owner.as_ref()],
         bump,
-        constraint = lp_position.owner == user.key(),
+        constraint = lp_position.is_owner_or_manager(&user.key()) @ ErrorCode::Unauthorized,
         constraint = lp_position.vault == vault_account.key(),
+        constraint = !lp_position.is_protocol @ ErrorCode::ProtocolPosition,
     )]
     pub lp_position: Account<'info, LPPosition>,
     
+    // Required when the vault vests rewards instead of paying them instantly
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = VestingSchedule::LEN,
+        seeds = [VESTING_SCHEDULE_SEED, vault_account.key().as_ref(), lp_position.owner.as_ref()],
+        bump,
+    )]
+    pub vesting_schedule: Option<Account<'info, VestingSchedule>>,
+    
+    // Rewards are always paid to the position owner, even when a manager claims
     #[account(
         mut,
         constraint = user_token_account.mint == vault_account.token_mint,
-        constraint = user_token_account.owner == user.key(),
+        constraint = user_token_account.owner == lp_position.owner,
     )]
     pub user_token_account: Account<'info, TokenAccount>,
     
@@ -52,22 +63,51 @@ pub fn handler(ctx: Context<DistributeIncentives>) -> Result<()> {
     let vault_account = &mut ctx.accounts.vault_account;
     let lp_position = &mut ctx.accounts.lp_position;
     
+    // In retained-earnings mode LP fees are folded into TVL rather than claimed
+    require!(!vault_account.retain_earnings, ErrorCode::RetainedEarningsMode);
+    
     // Ensure there are LP fees to distribute
     require!(vault_account.accrued_lp_fees > 0, ErrorCode::NoFeesToClaim);
     
     // Ensure user has liquidity positioned
-    require!(lp_position.amount > 0, ErrorCode::NoLiquidityProvided);
+    require!(lp_position.shares > 0, ErrorCode::NoLiquidityProvided);
     
-    // Calculate the LP's share of fees
-    let reward_amount = calculate_lp_rewards(
-        lp_position.amount,
-        vault_account.accrued_lp_fees,
-        vault_account.tvl,
-    )?;
+    // LP fees already accrue to share value; claiming pays out the position's share
+    // of retained fees and burns the shares backing it at the current exchange rate
+    let (reward_amount, shares_burned) = vault_account.harvest_fee_share(lp_position.shares)?;
     
     // Ensure there's something to claim
     require!(reward_amount > 0, ErrorCode::RewardTooSmall);
     
+    lp_position.shares = lp_position.shares.checked_sub(shares_burned).ok_or(ErrorCode::MathOverflow)?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Credit the rewards into the vesting schedule when vesting is enabled
+    if vault_account.reward_vesting_duration > 0 {
+        let vesting_schedule = ctx.accounts.vesting_schedule
+            .as_mut()
+            .ok_or(ErrorCode::VestingScheduleRequired)?;
+        
+        if vesting_schedule.owner == Pubkey::default() {
+            vesting_schedule.owner = lp_position.owner;
+            vesting_schedule.vault = vault_account.key();
+            vesting_schedule.bump = *ctx.bumps.get("vesting_schedule").unwrap();
+        }
+        
+        vesting_schedule.credit(reward_amount, current_time, vault_account.reward_vesting_duration)?;
+        
+        // Rewards stay in the vault token account until claimed from the schedule
+        vault_account.vesting_rewards_outstanding = vault_account.vesting_rewards_outstanding.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+        
+        lp_position.rewards_claimed = lp_position.rewards_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+        lp_position.last_rewards_claim_time = current_time;
+        
+        msg!("Credited {} tokens in rewards to LP vesting schedule", reward_amount);
+        
+        return Ok(());
+    }
+    
     // Transfer tokens from vault to user
     let bump = vault_account.nonce;
     let vault_key = vault_account.key();
@@ -92,12 +132,9 @@ pub fn handler(ctx: Context<DistributeIncentives>) -> Result<()> {
     
     token::transfer(cpi_ctx, reward_amount)?;
     
-    // Update the vault's accrued fees
-    vault_account.accrued_lp_fees = vault_account.accrued_lp_fees.checked_sub(reward_amount).ok_or(ErrorCode::MathOverflow)?;
-    
     // Update the LP's reward data
     lp_position.rewards_claimed = lp_position.rewards_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
-    lp_position.last_rewards_claim_time = Clock::get()?.unix_timestamp;
+    lp_position.last_rewards_claim_time = current_time;
     
     msg!("Distributed {} tokens in rewards to LP", reward_amount);
     
@@ -117,4 +154,16 @@ pub enum ErrorCode {
     
     #[msg("Calculated reward amount is too small")]
     RewardTooSmall,
+    
+    #[msg("Vesting schedule account is required when reward vesting is enabled")]
+    VestingScheduleRequired,
+    
+    #[msg("Only the position owner or its manager can claim rewards")]
+    Unauthorized,
+    
+    #[msg("Vault reinvests LP fees; they accrue to share value instead of being claimed")]
+    RetainedEarningsMode,
+    
+    #[msg("Protocol-owned liquidity earns no incentives; its yield is paid to the treasury on withdrawal")]
+    ProtocolPosition,
 } 
\ No newline at end of file
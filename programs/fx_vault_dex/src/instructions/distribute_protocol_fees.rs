@@ -1,10 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED};
+use crate::state::{VaultAccount, StakingPool, ProtocolConfig, Role, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, STAKING_POOL_SEED, PROTOCOL_CONFIG_SEED, PRECISION};
 
 #[derive(Accounts)]
 pub struct DistributeProtocolFees<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = vault_account.has_role(Role::FeeManager, &admin.key()) @ ErrorCode::Unauthorized,
+    )]
     pub admin: Signer<'info>,
     
     #[account(
@@ -42,6 +45,30 @@ pub struct DistributeProtocolFees<'info> {
     )]
     pub pda_treasury_account: Account<'info, TokenAccount>,
     
+    // Optional staking pool that receives a share of protocol fees paid in its reward mint
+    // Only the pool registered in the protocol config is accepted
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+        constraint = staking_pool.key() == protocol_config.staking_pool @ ErrorCode::InvalidStakingPool,
+    )]
+    pub staking_pool: Option<Account<'info, StakingPool>>,
+    
+    #[account(mut)]
+    pub staking_reward_vault: Option<Account<'info, TokenAccount>>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Insurance fund token account, required when protocol fees have an insurance carve-out
+    #[account(mut)]
+    pub insurance_fund_token: Option<Account<'info, TokenAccount>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -69,8 +96,84 @@ pub fn handler(ctx: Context<DistributeProtocolFees>) -> Result<()> {
     ];
     let signer_seeds = &[&seeds[..]];
     
-    // 1. Transfer protocol fees if any
-    if protocol_fee_amount > 0 {
+    // 1. Carve the insurance fund's share out of protocol fees
+    let insurance_fee_amount = (protocol_fee_amount as u128 * ctx.accounts.protocol_config.insurance_fee_bps as u128 / 10000) as u64;
+    if insurance_fee_amount > 0 {
+        let insurance_fund_token = ctx.accounts.insurance_fund_token.as_ref().ok_or(ErrorCode::InvalidInsuranceFund)?;
+        require!(
+            insurance_fund_token.mint == vault_account.token_mint
+                && insurance_fund_token.owner == ctx.accounts.protocol_config.insurance_fund,
+            ErrorCode::InvalidInsuranceFund
+        );
+        
+        let insurance_transfer_accounts = Transfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: insurance_fund_token.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        
+        let insurance_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            insurance_transfer_accounts,
+            signer_seeds,
+        );
+        
+        token::transfer(insurance_cpi_ctx, insurance_fee_amount)?;
+        
+        msg!("Distributed {} tokens in protocol fees to the insurance fund", insurance_fee_amount);
+    }
+    let shared_fee_amount = protocol_fee_amount - insurance_fee_amount;
+    
+    // 2. Route the stakers' share of the remaining protocol fees to the staking pool
+    let mut staker_fee_amount = 0;
+    if let (Some(staking_pool), Some(staking_reward_vault)) = (
+        ctx.accounts.staking_pool.as_mut(),
+        ctx.accounts.staking_reward_vault.as_ref(),
+    ) {
+        require!(
+            staking_reward_vault.key() == staking_pool.reward_vault,
+            ErrorCode::InvalidStakingRewardVault
+        );
+        
+        if staking_pool.reward_mint == vault_account.token_mint && staking_pool.total_staked > 0 {
+            staker_fee_amount = shared_fee_amount
+                .checked_mul(ctx.accounts.protocol_config.staker_fee_share_bps as u64)
+                .ok_or(ErrorCode::MathOverflow)?
+                .checked_div(10000)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        
+        if staker_fee_amount > 0 {
+            let staking_transfer_accounts = Transfer {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                to: staking_reward_vault.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            };
+            
+            let staking_cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                staking_transfer_accounts,
+                signer_seeds,
+            );
+            
+            token::transfer(staking_cpi_ctx, staker_fee_amount)?;
+            
+            // Grow the reward index so each staked token earns its share
+            let index_increase = (staker_fee_amount as u128)
+                .checked_mul(PRECISION as u128)
+                .ok_or(ErrorCode::MathOverflow)?
+                .checked_div(staking_pool.total_staked as u128)
+                .ok_or(ErrorCode::MathOverflow)?;
+            staking_pool.reward_index = staking_pool.reward_index.checked_add(index_increase).ok_or(ErrorCode::MathOverflow)?;
+            staking_pool.total_rewards_distributed = staking_pool.total_rewards_distributed.checked_add(staker_fee_amount).ok_or(ErrorCode::MathOverflow)?;
+            
+            msg!("Distributed {} tokens in protocol fees to stakers", staker_fee_amount);
+        }
+    }
+    let treasury_fee_amount = shared_fee_amount.checked_sub(staker_fee_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    // 3. Transfer the remaining protocol fees if any
+    if treasury_fee_amount > 0 {
         let protocol_transfer_accounts = Transfer {
             from: ctx.accounts.vault_token_account.to_account_info(),
             to: ctx.accounts.protocol_treasury_account.to_account_info(),
@@ -83,15 +186,15 @@ pub fn handler(ctx: Context<DistributeProtocolFees>) -> Result<()> {
             signer_seeds,
         );
         
-        token::transfer(protocol_cpi_ctx, protocol_fee_amount)?;
+        token::transfer(protocol_cpi_ctx, treasury_fee_amount)?;
         
-        // Update the vault's accrued protocol fees
-        vault_account.accrued_protocol_fees = 0;
-        
-        msg!("Distributed {} tokens in protocol fees", protocol_fee_amount);
+        msg!("Distributed {} tokens in protocol fees", treasury_fee_amount);
     }
     
-    // 2. Transfer PDA fees if any
+    // Update the vault's accrued protocol fees
+    vault_account.accrued_protocol_fees = 0;
+    
+    // 4. Transfer PDA fees if any
     if pda_fee_amount > 0 {
         let pda_transfer_accounts = Transfer {
             from: ctx.accounts.vault_token_account.to_account_info(),
@@ -118,6 +221,21 @@ pub fn handler(ctx: Context<DistributeProtocolFees>) -> Result<()> {
 
 #[error_code]
 pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
     #[msg("No fees available to claim")]
     NoFeesToClaim,
+    
+    #[msg("Staking reward vault does not match the staking pool")]
+    InvalidStakingRewardVault,
+    
+    #[msg("Staking pool is not the one registered in the protocol config")]
+    InvalidStakingPool,
+    
+    #[msg("Only the vault's fee manager can distribute protocol fees")]
+    Unauthorized,
+    
+    #[msg("Insurance fund token account is missing or does not match the configured fund")]
+    InvalidInsuranceFund,
 } 
\ No newline at end of file
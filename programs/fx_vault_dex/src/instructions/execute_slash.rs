@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{StakingPool, AdminLog, AdminAction, STAKING_POOL_SEED, MAX_SLASH_BPS, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct ExecuteSlash<'info> {
+    #[account(
+        constraint = authority.key() == staking_pool.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        constraint = stake_vault.key() == staking_pool.stake_vault,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = slash_recipient.key() == staking_pool.pending_slash_recipient,
+    )]
+    pub slash_recipient: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ExecuteSlash>) -> Result<()> {
+    let old_value = ctx.accounts.staking_pool.total_slashed;
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    let amount = staking_pool.pending_slash_amount;
+    require!(amount > 0, ErrorCode::NoSlashPending);
+    
+    // Ensure the timelock has elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time >= staking_pool.pending_slash_eta, ErrorCode::TimelockActive);
+    
+    // Re-check the bound against the current slashable stake
+    let slashable = staking_pool.total_staked
+        .checked_add(staking_pool.total_unstaking)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let max_slash = (slashable as u128)
+        .checked_mul(staking_pool.max_slash_bps.min(MAX_SLASH_BPS) as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+    let amount = amount.min(max_slash);
+    require!(amount > 0, ErrorCode::NothingToSlash);
+    
+    // Scale every stake by the same factor: (slashable - amount) / slashable
+    let remaining = (slashable - amount) as u128;
+    let slashable = slashable as u128;
+    staking_pool.slash_factor = staking_pool.slash_factor
+        .checked_mul(remaining)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(slashable)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    let new_total_staked = (staking_pool.total_staked as u128 * remaining / slashable) as u64;
+    let new_total_unstaking = (staking_pool.total_unstaking as u128 * remaining / slashable) as u64;
+    
+    // Transfer exactly what was removed from the totals so accounting stays in sync
+    let slashed_amount = (slashable as u64) - new_total_staked - new_total_unstaking;
+    staking_pool.total_staked = new_total_staked;
+    staking_pool.total_unstaking = new_total_unstaking;
+    
+    let stake_mint = staking_pool.stake_mint;
+    let seeds = &[
+        STAKING_POOL_SEED,
+        stake_mint.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.slash_recipient.to_account_info(),
+        authority: staking_pool.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, slashed_amount)?;
+    
+    staking_pool.total_slashed = staking_pool.total_slashed.checked_add(slashed_amount).ok_or(ErrorCode::MathOverflow)?;
+    staking_pool.pending_slash_amount = 0;
+    
+    msg!("Slashed {} staked tokens to cover vault {}", slashed_amount, staking_pool.pending_slash_vault);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::ExecuteSlash,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        ctx.accounts.staking_pool.total_slashed,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("No slash is pending")]
+    NoSlashPending,
+    
+    #[msg("Slash timelock has not elapsed")]
+    TimelockActive,
+    
+    #[msg("No slashable stake")]
+    NothingToSlash,
+} 
\ No newline at end of file
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, ADMIN_LOG_SEED};
+use crate::events::TokenAccountRotated;
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ExecuteTokenAccountRotation<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    // Re-checked in case the account changed during the timelock
+    #[account(
+        mut,
+        constraint = new_token_account.key() == vault_account.pending_token_account @ ErrorCode::NotPendingTokenAccount,
+        constraint = new_token_account.mint == vault_account.token_mint @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.owner == vault_authority.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.delegate.is_none() @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.close_authority.is_none() @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub new_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ExecuteTokenAccountRotation>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.vault_account.token_account);
+    
+    // Ensure the timelock has elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time >= ctx.accounts.vault_account.token_account_rotation_eta,
+        ErrorCode::TimelockActive
+    );
+    
+    // Move the full balance, including any untracked dust, to the new account
+    let amount = ctx.accounts.vault_token_account.amount;
+    if amount > 0 {
+        let vault_key = ctx.accounts.vault_account.key();
+        let seeds = &[
+            VAULT_AUTHORITY_SEED,
+            vault_key.as_ref(),
+            &[ctx.accounts.vault_account.nonce],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_cpi_accounts = Transfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: ctx.accounts.new_token_account.to_account_info(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_cpi_accounts,
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, amount)?;
+    }
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    let old_token_account = vault_account.token_account;
+    vault_account.token_account = vault_account.pending_token_account;
+    vault_account.pending_token_account = Pubkey::default();
+    vault_account.token_account_rotation_eta = 0;
+    
+    emit!(TokenAccountRotated {
+        vault: vault_account.key(),
+        old_token_account,
+        new_token_account: vault_account.token_account,
+        amount,
+    });
+    
+    msg!("Rotated vault token account from {} to {}, moving {} tokens",
+         old_token_account, vault_account.token_account, amount);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ExecuteTokenAccountRotation,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.token_account),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Token account is not the pending replacement")]
+    NotPendingTokenAccount,
+    
+    #[msg("Replacement must be an account for the vault mint, owned by the vault authority, with no delegate or close authority")]
+    InvalidTokenAccount,
+    
+    #[msg("Rotation timelock has not elapsed")]
+    TimelockActive,
+} 
\ No newline at end of file
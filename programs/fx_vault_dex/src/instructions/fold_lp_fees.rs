@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED};
+use crate::events::LpFeesFolded;
+
+#[derive(Accounts)]
+pub struct FoldLpFees<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+}
+
+pub fn handler(ctx: Context<FoldLpFees>) -> Result<()> {
+    let vault_key = ctx.accounts.vault_account.key();
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    require!(vault_account.retain_earnings, ErrorCode::RetainedEarningsDisabled);
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time - vault_account.last_fee_fold_time >= vault_account.fee_fold_interval,
+        ErrorCode::FoldTooSoon
+    );
+    
+    let folded_amount = vault_account.accrued_lp_fees;
+    require!(folded_amount > 0, ErrorCode::NoFeesToFold);
+    
+    // The fees already sit in the vault token account and back the shares, so folding
+    // them into TVL makes them swappable liquidity without moving the exchange rate
+    vault_account.tvl = vault_account.tvl.checked_add(folded_amount).ok_or(ErrorCode::MathOverflow)?;
+    vault_account.accrued_lp_fees = 0;
+    vault_account.last_fee_fold_time = current_time;
+    
+    let virtual_price = vault_account.virtual_price();
+    
+    emit!(LpFeesFolded {
+        vault: vault_key,
+        folded_amount,
+        tvl: vault_account.tvl,
+        total_shares: vault_account.total_shares,
+        virtual_price,
+    });
+    
+    msg!("Folded {} LP fee tokens into TVL, virtual price {}", folded_amount, virtual_price);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Retained-earnings mode is not enabled for this vault")]
+    RetainedEarningsDisabled,
+    
+    #[msg("Fold interval has not elapsed")]
+    FoldTooSoon,
+    
+    #[msg("No LP fees to fold")]
+    NoFeesToFold,
+} 
\ No newline at end of file
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Program semver, returned to the caller as return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct ProgramVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+#[derive(Accounts)]
+pub struct GetVersion {}
+
+pub fn handler(_ctx: Context<GetVersion>) -> Result<ProgramVersion> {
+    Ok(ProgramVersion {
+        major: env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap_or_default(),
+        minor: env!("CARGO_PKG_VERSION_MINOR").parse().unwrap_or_default(),
+        patch: env!("CARGO_PKG_VERSION_PATCH").parse().unwrap_or_default(),
+    })
+} 
\ No newline at end of file
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminLogEntry, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED, ADMIN_LOG_LEN};
+
+#[derive(Accounts)]
+pub struct InitializeAdminLog<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = AdminLog::LEN,
+        seeds = [ADMIN_LOG_SEED],
+        bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeAdminLog>) -> Result<()> {
+    let admin_log = &mut ctx.accounts.admin_log;
+    
+    admin_log.bump = *ctx.bumps.get("admin_log").unwrap();
+    admin_log.sequence = 0;
+    admin_log.upgrade_authority = None;
+    admin_log.head = 0;
+    admin_log.count = 0;
+    admin_log.entries = [AdminLogEntry::default(); ADMIN_LOG_LEN];
+    
+    msg!("Initialized admin log with {} entries", ADMIN_LOG_LEN);
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, EmergencyState, PROTOCOL_CONFIG_SEED, EMERGENCY_STATE_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeEmergencyState<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = EmergencyState::LEN,
+        seeds = [EMERGENCY_STATE_SEED],
+        bump,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeEmergencyState>, guardian: Pubkey) -> Result<()> {
+    let emergency_state = &mut ctx.accounts.emergency_state;
+    
+    emergency_state.guardian = guardian;
+    emergency_state.bump = *ctx.bumps.get("emergency_state").unwrap();
+    emergency_state.halted = false;
+    emergency_state.last_changed = Clock::get()?.unix_timestamp;
+    
+    msg!("Initialized emergency state with guardian: {}", guardian);
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, FeedRegistry, PROTOCOL_CONFIG_SEED, FEED_REGISTRY_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeFeedRegistry<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = FeedRegistry::LEN,
+        seeds = [FEED_REGISTRY_SEED],
+        bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeFeedRegistry>) -> Result<()> {
+    let feed_registry = &mut ctx.accounts.feed_registry;
+    
+    feed_registry.admin = ctx.accounts.admin.key();
+    feed_registry.pending_admin = Pubkey::default();
+    feed_registry.bump = *ctx.bumps.get("feed_registry").unwrap();
+    feed_registry.feeds = Vec::new();
+    feed_registry.deprecated_vaults = Vec::new();
+    
+    msg!("Initialized feed registry with admin: {}", ctx.accounts.admin.key());
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{ProtocolConfig, KeeperRegistry, PROTOCOL_CONFIG_SEED, KEEPER_REGISTRY_SEED};
+
+#[derive(Accounts)]
+pub struct InitializeKeeperRegistry<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == protocol_config.admin,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = KeeperRegistry::LEN,
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    pub bond_mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = bond_vault.mint == bond_mint.key(),
+        constraint = bond_vault.owner == keeper_registry.key(),
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeKeeperRegistry>,
+    min_bond: u64,
+    unbond_cooldown: i64,
+) -> Result<()> {
+    let keeper_registry = &mut ctx.accounts.keeper_registry;
+    
+    // Validate bonding parameters
+    require!(min_bond > 0, ErrorCode::InvalidMinBond);
+    require!(unbond_cooldown >= 0, ErrorCode::InvalidCooldown);
+    
+    keeper_registry.authority = ctx.accounts.authority.key();
+    keeper_registry.bond_mint = ctx.accounts.bond_mint.key();
+    keeper_registry.bond_vault = ctx.accounts.bond_vault.key();
+    keeper_registry.bump = *ctx.bumps.get("keeper_registry").unwrap();
+    keeper_registry.min_bond = min_bond;
+    keeper_registry.unbond_cooldown = unbond_cooldown;
+    keeper_registry.total_bonded = 0;
+    keeper_registry.total_unbonding = 0;
+    keeper_registry.total_slashed = 0;
+    
+    msg!("Initialized keeper registry for bond mint {} with minimum bond {}", ctx.accounts.bond_mint.key(), min_bond);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Minimum bond must be greater than zero")]
+    InvalidMinBond,
+    
+    #[msg("Unbond cooldown cannot be negative")]
+    InvalidCooldown,
+} 
\ No newline at end of file
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, DriftCurve, ParameterCurve, PenaltySplit, PROTOCOL_CONFIG_SEED, DEFAULT_SPREAD_SLOPE_PPM, DEFAULT_MAX_REBALANCE_PREMIUM_BPS};
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolConfig::LEN,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeProtocolConfig>) -> Result<()> {
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    
+    protocol_config.admin = ctx.accounts.admin.key();
+    protocol_config.pending_admin = Pubkey::default();
+    protocol_config.bump = *ctx.bumps.get("protocol_config").unwrap();
+    protocol_config.spread_slope_ppm = DEFAULT_SPREAD_SLOPE_PPM;
+    protocol_config.drift_curve = DriftCurve::default();
+    protocol_config.protocol_fee_curve = ParameterCurve::default_protocol_fee_curve();
+    protocol_config.withdrawal_fee_curve = ParameterCurve::default_withdrawal_fee_curve();
+    protocol_config.penalty_split = PenaltySplit::default(); // Penalties go to the PDA treasury until configured
+    protocol_config.insurance_fund = Pubkey::default();
+    protocol_config.max_rebalance_premium_bps = DEFAULT_MAX_REBALANCE_PREMIUM_BPS;
+    protocol_config.treasury_mint = Pubkey::default(); // Fee consolidation disabled until configured
+    protocol_config.utilization_fee_curve = ParameterCurve::default_utilization_fee_curve();
+    protocol_config.insurance_fee_bps = 0; // No carve-out until configured
+    protocol_config.keeper_liveness_window = 0; // No liveness monitoring until configured
+    protocol_config.degraded_spread_bps = 0;
+    protocol_config.degraded_max_trade_bps = 0;
+    protocol_config.staking_pool = Pubkey::default(); // No staker share until a pool is created
+    protocol_config.staker_fee_share_bps = 0;
+    
+    msg!("Initialized protocol config with admin: {}", ctx.accounts.admin.key());
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{ProtocolConfig, StakingPool, PROTOCOL_CONFIG_SEED, STAKING_POOL_SEED, PRECISION};
+
+#[derive(Accounts)]
+pub struct InitializeStakingPool<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == protocol_config.admin,
+    )]
+    pub authority: Signer<'info>,
+    
+    // Records the pool as the one protocol fees are routed to
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = StakingPool::LEN,
+        seeds = [STAKING_POOL_SEED, stake_mint.key().as_ref()],
+        bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    pub stake_mint: Account<'info, Mint>,
+    
+    pub reward_mint: Account<'info, Mint>,
+    
+    #[account(
+        constraint = stake_vault.mint == stake_mint.key(),
+        constraint = stake_vault.owner == staking_pool.key(),
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    #[account(
+        constraint = reward_vault.mint == reward_mint.key(),
+        constraint = reward_vault.owner == staking_pool.key(),
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+    
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(
+    ctx: Context<InitializeStakingPool>,
+    fee_share_bps: u16,
+    unstake_cooldown: i64,
+) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    // Validate staking parameters
+    require!(fee_share_bps <= 10000, ErrorCode::InvalidFeeShare);
+    require!(unstake_cooldown >= 0, ErrorCode::InvalidCooldown);
+    
+    staking_pool.authority = ctx.accounts.authority.key();
+    staking_pool.pending_authority = Pubkey::default();
+    staking_pool.stake_mint = ctx.accounts.stake_mint.key();
+    staking_pool.stake_vault = ctx.accounts.stake_vault.key();
+    staking_pool.reward_mint = ctx.accounts.reward_mint.key();
+    staking_pool.reward_vault = ctx.accounts.reward_vault.key();
+    staking_pool.bump = *ctx.bumps.get("staking_pool").unwrap();
+    staking_pool.unstake_cooldown = unstake_cooldown;
+    staking_pool.total_staked = 0;
+    staking_pool.reward_index = 0;
+    staking_pool.total_rewards_distributed = 0;
+    staking_pool.total_unstaking = 0;
+    staking_pool.slash_factor = PRECISION as u128; // No slashes yet
+    staking_pool.max_slash_bps = 0; // Slashing disabled until configured
+    staking_pool.slash_timelock = 0;
+    staking_pool.pending_slash_amount = 0;
+    staking_pool.total_slashed = 0;
+    
+    // Protocol fees only ever go to the pool registered here, at the share the admin chose
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.staking_pool = staking_pool.key();
+    protocol_config.staker_fee_share_bps = fee_share_bps;
+    
+    msg!("Initialized staking pool for stake mint: {}", ctx.accounts.stake_mint.key());
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Fee share must be at most 10000 basis points")]
+    InvalidFeeShare,
+    
+    #[msg("Unstake cooldown cannot be negative")]
+    InvalidCooldown,
+} 
\ No newline at end of file
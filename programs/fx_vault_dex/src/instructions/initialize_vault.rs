@@ -1,12 +1,23 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
-use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED};
+use crate::state::{VaultAccount, ProtocolConfig, FeedRegistry, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, PROTOCOL_CONFIG_SEED, FEED_REGISTRY_SEED, PRECISION};
+use crate::utils::get_oracle_price;
 
 #[derive(Accounts)]
 pub struct InitializeVault<'info> {
-    #[account(mut)]
+    // Vault PDAs are canonical per mint, so only the protocol admin may claim one
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin @ ErrorCode::Unauthorized,
+    )]
     pub admin: Signer<'info>,
     
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     #[account(
         init,
         payer = admin,
@@ -32,7 +43,16 @@ pub struct InitializeVault<'info> {
     )]
     pub vault_token_account: Account<'info, TokenAccount>,
     
-    /// CHECK: This will be validated in the handler
+    #[account(
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    /// CHECK: Must be an oracle approved in the feed registry for this mint
+    #[account(
+        constraint = feed_registry.is_approved(&token_mint.key(), &oracle.key()) @ ErrorCode::OracleNotApproved,
+    )]
     pub oracle: AccountInfo<'info>,
     
     /// CHECK: This account receives protocol fees
@@ -49,31 +69,85 @@ pub struct InitializeVault<'info> {
 pub fn handler(
     ctx: Context<InitializeVault>,
     vault_name: String,
-    nonce: u8,
     fee_basis_points: u16,
 ) -> Result<()> {
+    // A feed that cannot be parsed or is not publishing would leave the vault unpriceable
+    let initial_price = get_oracle_price(&ctx.accounts.oracle, Clock::get()?.unix_timestamp)?;
+    
     let vault_account = &mut ctx.accounts.vault_account;
     
     // Validate fee basis points
     require!(fee_basis_points <= 500, ErrorCode::FeeTooHigh); // Max 5%
     
     // Initialize vault data
-    vault_account.vault_name = vault_name;
+    vault_account.vault_name = VaultAccount::encode_name(&vault_name).ok_or(ErrorCode::VaultNameTooLong)?;
     vault_account.authority = ctx.accounts.vault_authority.key();
     vault_account.token_mint = ctx.accounts.token_mint.key();
     vault_account.token_account = ctx.accounts.vault_token_account.key();
-    vault_account.nonce = nonce;
+    vault_account.nonce = *ctx.bumps.get("vault_authority").unwrap(); // Canonical bump signing for the vault authority
     vault_account.tvl = 0;
+    vault_account.total_shares = 0;
+    vault_account.protocol_owned_liquidity = 0;
     vault_account.accrued_lp_fees = 0;
     vault_account.accrued_pda_fees = 0;
     vault_account.accrued_protocol_fees = 0;
     vault_account.fee_basis_points = fee_basis_points;
     vault_account.last_fee_update = Clock::get()?.unix_timestamp;
     vault_account.oracle = ctx.accounts.oracle.key();
-    vault_account.last_oracle_price = 0; // Will be updated on first swap
-    vault_account.last_update_timestamp = Clock::get()?.unix_timestamp;
+    vault_account.last_oracle_price = initial_price.price; // Feed price until the first swap records a pair rate
+    vault_account.last_update_timestamp = initial_price.publish_time;
     vault_account.treasury = ctx.accounts.treasury.key();
     vault_account.pda_treasury = ctx.accounts.pda_treasury.key();
+    vault_account.pending_treasury = Pubkey::default();
+    vault_account.admin = ctx.accounts.admin.key();
+    vault_account.pending_admin = Pubkey::default();
+    vault_account.balance_target_health_bps = 0; // Balance incentive disabled until configured
+    vault_account.balance_bonus_bps = 0;
+    vault_account.balance_bonus_cap = 0;
+    vault_account.balance_bonus_cooldown = 0;
+    vault_account.last_balance_bonus_time = 0;
+    vault_account.total_balance_bonus_paid = 0;
+    vault_account.launch_start_time = 0; // Launch mode disabled until configured
+    vault_account.launch_duration = 0;
+    vault_account.launch_spread_bps = 0;
+    vault_account.launch_withdrawal_fee_bps = 0;
+    vault_account.reward_vesting_duration = 0; // Rewards are paid instantly until vesting is configured
+    vault_account.early_claim_penalty_bps = 0;
+    vault_account.vesting_rewards_outstanding = 0;
+    vault_account.max_price_deviation_bps = 0; // Circuit breaker disabled until configured
+    vault_account.breaker_cooldown_slots = 0;
+    vault_account.breaker_tripped = false;
+    vault_account.halt_until_slot = 0;
+    vault_account.breaker_reference_price = 0;
+    vault_account.daily_volume_cap = 0; // Unlimited until a cap is set
+    vault_account.volume_day = 0;
+    vault_account.daily_volume = 0;
+    vault_account.cumulative_swap_inflow = 0;
+    vault_account.cumulative_swap_outflow = 0;
+    vault_account.net_swap_flow = 0;
+    vault_account.retain_earnings = false; // LP fees are claimable until retained-earnings mode is enabled
+    vault_account.fee_fold_interval = 0;
+    vault_account.last_fee_fold_time = 0;
+    vault_account.management_fee_bps = 0; // Asset-management fees disabled until configured
+    vault_account.performance_fee_bps = 0;
+    vault_account.high_water_mark = PRECISION; // Shares start at one token each
+    vault_account.last_management_fee_time = 0;
+    vault_account.min_deposit_amount = 0; // No dust limits until configured
+    vault_account.min_withdraw_amount = 0;
+    vault_account.min_swap_amount = 0;
+    vault_account.deposits_paused = false;
+    vault_account.withdrawals_paused = false;
+    vault_account.swaps_paused = false;
+    vault_account.wound_down = false;
+    vault_account.wind_down_time = 0;
+    vault_account.pending_token_account = Pubkey::default();
+    vault_account.token_account_rotation_eta = 0;
+    vault_account.last_swap_slot = 0; // Set by the first swap
+    vault_account.last_swap_publish_slot = 0;
+    vault_account.cumulative_swap_fees = 0;
+    vault_account.tvl_average = 0;
+    vault_account.tvl_average_time = 0;
+    vault_account.tx_guard_enabled = false; // Transaction guard off until configured
     
     msg!("Initialized vault for token mint: {}", ctx.accounts.token_mint.key());
     
@@ -84,4 +158,13 @@ pub fn handler(
 pub enum ErrorCode {
     #[msg("Fee is too high, maximum is 5%")]
     FeeTooHigh,
+    
+    #[msg("Oracle is not approved in the feed registry for this mint")]
+    OracleNotApproved,
+    
+    #[msg("Vault name is longer than 32 bytes")]
+    VaultNameTooLong,
+    
+    #[msg("Only the protocol admin can create vaults")]
+    Unauthorized,
 } 
\ No newline at end of file
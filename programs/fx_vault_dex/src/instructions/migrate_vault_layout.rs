@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::{VaultAccount, ProtocolConfig, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED};
+use crate::state::constants::PRECISION;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct MigrateVaultLayout<'info> {
+    // Legacy vaults predate per-vault admins, so the protocol admin migrates them and pays for the extra space
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin @ ErrorCode::Unauthorized,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    /// CHECK: Vault still in the legacy layout, which cannot be deserialized as VaultAccount; decoded by the handler
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [VAULT_ACCOUNT_SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault_account: AccountInfo<'info>,
+    
+    /// CHECK: Only used to derive the vault address; checked against the decoded vault
+    pub token_mint: AccountInfo<'info>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Vault as laid out before the fixed-offset layout, with the name as a length-prefixed String
+#[derive(AnchorDeserialize)]
+struct LegacyVaultAccount {
+    vault_name: String,
+    authority: Pubkey,
+    token_mint: Pubkey,
+    token_account: Pubkey,
+    nonce: u8,
+    tvl: u64,
+    accrued_lp_fees: u64,
+    accrued_pda_fees: u64,
+    accrued_protocol_fees: u64,
+    fee_basis_points: u16,
+    last_fee_update: i64,
+    oracle: Pubkey,
+    last_oracle_price: u64,
+    last_update_timestamp: i64,
+    treasury: Pubkey,
+    pda_treasury: Pubkey,
+}
+
+/// Rewrites a vault from the legacy layout, where `vault_name` was a length-prefixed
+/// String and the account ended after `pda_treasury`, to the current fixed-offset layout.
+/// The account grows to `VaultAccount::LEN`, with the extra rent paid by the admin, and
+/// every field added since starts at the value `initialize_vault` gives it.
+pub fn handler(ctx: Context<MigrateVaultLayout>) -> Result<()> {
+    let vault_info = ctx.accounts.vault_account.to_account_info();
+    
+    // Only a legacy vault is shorter than the current layout; a migrated one is rejected
+    let legacy = {
+        let data = vault_info.try_borrow_data()?;
+        require!(
+            data.len() < VaultAccount::LEN && data.len() >= 8 && data[..8] == VaultAccount::DISCRIMINATOR,
+            ErrorCode::InvalidLegacyLayout
+        );
+        LegacyVaultAccount::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::InvalidLegacyLayout)?
+    };
+    require_keys_eq!(legacy.token_mint, ctx.accounts.token_mint.key(), ErrorCode::InvalidLegacyLayout);
+    
+    let vault = VaultAccount {
+        vault_name: VaultAccount::encode_name(&legacy.vault_name).ok_or(ErrorCode::InvalidLegacyLayout)?,
+        authority: legacy.authority,
+        token_mint: legacy.token_mint,
+        token_account: legacy.token_account,
+        nonce: legacy.nonce,
+        tvl: legacy.tvl,
+        // Legacy positions recorded token amounts, so shares start at one token each
+        total_shares: legacy.tvl,
+        accrued_lp_fees: legacy.accrued_lp_fees,
+        accrued_pda_fees: legacy.accrued_pda_fees,
+        accrued_protocol_fees: legacy.accrued_protocol_fees,
+        fee_basis_points: legacy.fee_basis_points,
+        last_fee_update: legacy.last_fee_update,
+        oracle: legacy.oracle,
+        last_oracle_price: legacy.last_oracle_price,
+        last_update_timestamp: legacy.last_update_timestamp,
+        treasury: legacy.treasury,
+        pda_treasury: legacy.pda_treasury,
+        admin: ctx.accounts.admin.key(),
+        high_water_mark: PRECISION,
+        ..VaultAccount::default()
+    };
+    
+    // Top the account up to rent exemption at the new size before growing it
+    let required_lamports = Rent::get()?.minimum_balance(VaultAccount::LEN);
+    let shortfall = required_lamports.saturating_sub(vault_info.lamports());
+    if shortfall > 0 {
+        let transfer_accounts = Transfer {
+            from: ctx.accounts.admin.to_account_info(),
+            to: vault_info.clone(),
+        };
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_accounts,
+        );
+        
+        system_program::transfer(cpi_ctx, shortfall)?;
+    }
+    vault_info.realloc(VaultAccount::LEN, true)?;
+    
+    let mut data = vault_info.try_borrow_mut_data()?;
+    vault.try_serialize(&mut &mut data[..])?;
+    drop(data);
+    
+    msg!("Migrated vault {} to the fixed-offset layout", vault_info.key());
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::MigrateVaultLayout,
+        vault_info.key(),
+        0,
+        1,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Account is not a vault in the legacy layout")]
+    InvalidLegacyLayout,
+    
+    #[msg("Only the protocol admin can migrate a legacy vault")]
+    Unauthorized,
+} 
\ No newline at end of file
@@ -1,3 +1,5 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize_vault;
 pub mod deposit_liquidity;
 pub mod withdraw_liquidity;
@@ -5,6 +7,97 @@ pub mod swap;
 pub mod distribute_incentives;
 pub mod distribute_protocol_fees;
 pub mod rebalance_vault;
+pub mod set_balance_incentive;
+pub mod balance_incentive;
+pub mod seed_protocol_liquidity;
+pub mod configure_launch_mode;
+pub mod configure_reward_vesting;
+pub mod claim_vested;
+pub mod initialize_staking_pool;
+pub mod stake;
+pub mod unstake;
+pub mod withdraw_stake;
+pub mod claim_staking_rewards;
+pub mod configure_slashing;
+pub mod propose_slash;
+pub mod execute_slash;
+pub mod cancel_slash;
+pub mod snapshot_price;
+pub mod initialize_feed_registry;
+pub mod register_feed;
+pub mod remove_feed;
+pub mod configure_circuit_breaker;
+pub mod set_daily_volume_cap;
+pub mod configure_hedge;
+pub mod adjust_hedge;
+pub mod initialize_protocol_config;
+pub mod set_drift_curve;
+pub mod set_fee_allocation_curve;
+pub mod set_withdrawal_fee_curve;
+pub mod set_spread_slope;
+pub mod set_pair_config;
+pub mod deposit_pair;
+pub mod withdraw_pair_single;
+pub mod split_lp_position;
+pub mod merge_lp_position;
+pub mod set_position_manager;
+pub mod configure_retained_earnings;
+pub mod fold_lp_fees;
+pub mod configure_vault_fees;
+pub mod accrue_vault_fees;
+pub mod set_penalty_split;
+pub mod set_penalty_exemption;
+pub mod propose_protocol_admin;
+pub mod accept_protocol_admin;
+pub mod propose_registry_admin;
+pub mod accept_registry_admin;
+pub mod propose_vault_treasury;
+pub mod accept_vault_treasury;
+pub mod propose_staking_authority;
+pub mod accept_staking_authority;
+pub mod initialize_emergency_state;
+pub mod set_emergency_halt;
+pub mod set_guardian;
+pub mod initialize_admin_log;
+pub mod attest_upgrade_authority;
+pub mod set_minimum_amounts;
+pub mod sweep_dust;
+pub mod withdraw_all;
+pub mod claim_all;
+pub mod push_incentives;
+pub mod propose_vault_admin;
+pub mod accept_vault_admin;
+pub mod assign_role;
+pub mod revoke_role;
+pub mod set_vault_pauses;
+pub mod set_wind_down;
+pub mod propose_token_account_rotation;
+pub mod cancel_token_account_rotation;
+pub mod execute_token_account_rotation;
+pub mod recover_token;
+pub mod migrate_vault_layout;
+pub mod create_vault_pair;
+pub mod snapshot_vault_metrics;
+pub mod initialize_keeper_registry;
+pub mod bond_keeper;
+pub mod unbond_keeper;
+pub mod withdraw_keeper_bond;
+pub mod slash_keeper;
+pub mod set_rebalance_premium_cap;
+pub mod rebalance_from_fees;
+pub mod set_treasury_mint;
+pub mod consolidate_fees;
+pub mod set_utilization_fee_curve;
+pub mod rename_vault;
+pub mod quote_deposit;
+pub mod quote_withdraw;
+pub mod set_transaction_guard;
+pub mod swap_and_split;
+pub mod get_version;
+pub mod set_insurance_fee_share;
+pub mod set_keeper_liveness;
+pub mod record_keeper_heartbeat;
+pub mod withdraw_protocol_liquidity;
 
 pub use initialize_vault::*;
 pub use deposit_liquidity::*;
@@ -12,4 +105,93 @@ pub use withdraw_liquidity::*;
 pub use swap::*;
 pub use distribute_incentives::*;
 pub use distribute_protocol_fees::*;
-pub use rebalance_vault::*; 
\ No newline at end of file
+pub use rebalance_vault::*;
+pub use set_balance_incentive::*;
+pub use balance_incentive::*;
+pub use seed_protocol_liquidity::*;
+pub use configure_launch_mode::*;
+pub use configure_reward_vesting::*;
+pub use claim_vested::*;
+pub use initialize_staking_pool::*;
+pub use stake::*;
+pub use unstake::*;
+pub use withdraw_stake::*;
+pub use claim_staking_rewards::*;
+pub use configure_slashing::*;
+pub use propose_slash::*;
+pub use execute_slash::*;
+pub use cancel_slash::*;
+pub use snapshot_price::*;
+pub use initialize_feed_registry::*;
+pub use register_feed::*;
+pub use remove_feed::*;
+pub use configure_circuit_breaker::*;
+pub use set_daily_volume_cap::*;
+pub use configure_hedge::*;
+pub use adjust_hedge::*;
+pub use initialize_protocol_config::*;
+pub use set_drift_curve::*;
+pub use set_fee_allocation_curve::*;
+pub use set_withdrawal_fee_curve::*;
+pub use set_spread_slope::*;
+pub use set_pair_config::*;
+pub use deposit_pair::*;
+pub use withdraw_pair_single::*;
+pub use split_lp_position::*;
+pub use merge_lp_position::*;
+pub use set_position_manager::*;
+pub use configure_retained_earnings::*;
+pub use fold_lp_fees::*;
+pub use configure_vault_fees::*;
+pub use accrue_vault_fees::*;
+pub use set_penalty_split::*;
+pub use set_penalty_exemption::*;
+pub use propose_protocol_admin::*;
+pub use accept_protocol_admin::*;
+pub use propose_registry_admin::*;
+pub use accept_registry_admin::*;
+pub use propose_vault_treasury::*;
+pub use accept_vault_treasury::*;
+pub use propose_staking_authority::*;
+pub use accept_staking_authority::*;
+pub use initialize_emergency_state::*;
+pub use set_emergency_halt::*;
+pub use set_guardian::*;
+pub use initialize_admin_log::*;
+pub use attest_upgrade_authority::*;
+pub use set_minimum_amounts::*;
+pub use sweep_dust::*;
+pub use claim_all::*;
+pub use push_incentives::*;
+pub use propose_vault_admin::*;
+pub use accept_vault_admin::*;
+pub use assign_role::*;
+pub use revoke_role::*;
+pub use set_vault_pauses::*;
+pub use set_wind_down::*;
+pub use propose_token_account_rotation::*;
+pub use cancel_token_account_rotation::*;
+pub use execute_token_account_rotation::*;
+pub use recover_token::*;
+pub use migrate_vault_layout::*;
+pub use create_vault_pair::*;
+pub use snapshot_vault_metrics::*;
+pub use initialize_keeper_registry::*;
+pub use bond_keeper::*;
+pub use unbond_keeper::*;
+pub use withdraw_keeper_bond::*;
+pub use slash_keeper::*;
+pub use set_rebalance_premium_cap::*;
+pub use rebalance_from_fees::*;
+pub use set_treasury_mint::*;
+pub use consolidate_fees::*;
+pub use set_utilization_fee_curve::*;
+pub use rename_vault::*;
+pub use quote_deposit::*;
+pub use quote_withdraw::*;
+pub use set_transaction_guard::*;
+pub use get_version::*;
+pub use set_insurance_fee_share::*;
+pub use set_keeper_liveness::*;
+pub use record_keeper_heartbeat::*;
+pub use withdraw_protocol_liquidity::*; 
\ No newline at end of file
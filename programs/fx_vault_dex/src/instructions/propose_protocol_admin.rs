@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED};
+use crate::utils::{propose_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ProposeProtocolAdmin<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeProtocolAdmin>, new_admin: Pubkey) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.pending_admin);
+    
+    propose_handover(&mut ctx.accounts.protocol_config.pending_admin, new_admin);
+    
+    msg!("Proposed new protocol admin: {}", new_admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ProposeProtocolAdmin,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.pending_admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
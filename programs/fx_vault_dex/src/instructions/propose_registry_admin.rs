@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeedRegistry, AdminLog, AdminAction, FEED_REGISTRY_SEED, ADMIN_LOG_SEED};
+use crate::utils::{propose_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ProposeRegistryAdmin<'info> {
+    #[account(
+        constraint = admin.key() == feed_registry.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeRegistryAdmin>, new_admin: Pubkey) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.feed_registry.pending_admin);
+    
+    propose_handover(&mut ctx.accounts.feed_registry.pending_admin, new_admin);
+    
+    msg!("Proposed new feed registry admin: {}", new_admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ProposeRegistryAdmin,
+        ctx.accounts.feed_registry.key(),
+        old_value,
+        fingerprint(&ctx.accounts.feed_registry.pending_admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{StakingPool, VaultAccount, AdminLog, AdminAction, STAKING_POOL_SEED, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct ProposeSlash<'info> {
+    #[account(
+        constraint = authority.key() == staking_pool.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    // Vault whose shortfall the slash covers
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        constraint = slash_recipient.mint == staking_pool.stake_mint,
+    )]
+    pub slash_recipient: Account<'info, TokenAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeSlash>, amount: u64) -> Result<()> {
+    let old_value = ctx.accounts.staking_pool.pending_slash_amount;
+    
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(staking_pool.pending_slash_amount == 0, ErrorCode::SlashPending);
+    
+    // The slash is bounded by the max-slash share of all slashable stake
+    let slashable = staking_pool.total_staked
+        .checked_add(staking_pool.total_unstaking)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let max_slash = (slashable as u128)
+        .checked_mul(staking_pool.max_slash_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000)
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+    require!(amount <= max_slash, ErrorCode::SlashTooLarge);
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    staking_pool.pending_slash_amount = amount;
+    staking_pool.pending_slash_vault = ctx.accounts.vault_account.key();
+    staking_pool.pending_slash_recipient = ctx.accounts.slash_recipient.key();
+    staking_pool.pending_slash_eta = current_time
+        .checked_add(staking_pool.slash_timelock)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Proposed slash of {} staked tokens to cover vault {}, executable at {}",
+         amount, ctx.accounts.vault_account.key(), staking_pool.pending_slash_eta);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::ProposeSlash,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        ctx.accounts.staking_pool.pending_slash_amount,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Slash amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("A slash is already pending")]
+    SlashPending,
+    
+    #[msg("Slash exceeds the maximum slashable share of stake")]
+    SlashTooLarge,
+} 
\ No newline at end of file
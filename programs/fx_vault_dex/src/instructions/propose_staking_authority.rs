@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakingPool, AdminLog, AdminAction, STAKING_POOL_SEED, ADMIN_LOG_SEED};
+use crate::utils::{propose_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ProposeStakingAuthority<'info> {
+    #[account(
+        constraint = authority.key() == staking_pool.authority,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeStakingAuthority>, new_authority: Pubkey) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.staking_pool.pending_authority);
+    
+    propose_handover(&mut ctx.accounts.staking_pool.pending_authority, new_authority);
+    
+    msg!("Proposed new staking pool authority: {}", new_authority);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::ProposeStakingAuthority,
+        ctx.accounts.staking_pool.key(),
+        old_value,
+        fingerprint(&ctx.accounts.staking_pool.pending_authority),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, ADMIN_LOG_SEED, TOKEN_ACCOUNT_ROTATION_TIMELOCK};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ProposeTokenAccountRotation<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    // Replacement account, which must already be owned by the vault authority
+    #[account(
+        constraint = new_token_account.mint == vault_account.token_mint @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.owner == vault_authority.key() @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.delegate.is_none() @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.close_authority.is_none() @ ErrorCode::InvalidTokenAccount,
+        constraint = new_token_account.key() != vault_account.token_account @ ErrorCode::InvalidTokenAccount,
+    )]
+    pub new_token_account: Account<'info, TokenAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeTokenAccountRotation>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.vault_account.pending_token_account);
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    require!(vault_account.pending_token_account == Pubkey::default(), ErrorCode::RotationPending);
+    
+    // LPs get the timelock to react before the vault's tokens move
+    let current_time = Clock::get()?.unix_timestamp;
+    vault_account.pending_token_account = ctx.accounts.new_token_account.key();
+    vault_account.token_account_rotation_eta = current_time
+        .checked_add(TOKEN_ACCOUNT_ROTATION_TIMELOCK)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Proposed rotating the vault token account to {}, executable at {}",
+         vault_account.pending_token_account, vault_account.token_account_rotation_eta);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ProposeTokenAccountRotation,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.pending_token_account),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Replacement must be a different account for the vault mint, owned by the vault authority, with no delegate or close authority")]
+    InvalidTokenAccount,
+    
+    #[msg("A token account rotation is already pending")]
+    RotationPending,
+} 
\ No newline at end of file
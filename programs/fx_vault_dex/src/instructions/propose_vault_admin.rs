@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{propose_handover, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct ProposeVaultAdmin<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<ProposeVaultAdmin>, new_admin: Pubkey) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.vault_account.pending_admin);
+    
+    propose_handover(&mut ctx.accounts.vault_account.pending_admin, new_admin);
+    
+    msg!("Proposed new vault admin: {}", new_admin);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::ProposeVaultAdmin,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.pending_admin),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
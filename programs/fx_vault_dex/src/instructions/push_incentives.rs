@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::events::IncentivesPushed;
+use crate::state::{VaultAccount, LPPosition, RewardTracker, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, REWARD_TRACKER_SEED, MAX_PUSH_INCENTIVES_PAGE};
+use crate::utils::{load_program_account, store_program_account};
+
+// Accounts per position in remaining_accounts
+pub const PUSH_INCENTIVES_GROUP_LEN: usize = 2;
+
+#[derive(Accounts)]
+pub struct PushIncentives<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = RewardTracker::LEN,
+        seeds = [REWARD_TRACKER_SEED, vault_account.key().as_ref()],
+        bump,
+    )]
+    pub reward_tracker: Account<'info, RewardTracker>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    // Each position is passed through remaining_accounts as a pair of
+    // (lp_position, owner_token_account), in ascending order of position key
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PushIncentives<'info>>,
+    complete_pass: bool,
+    max_positions: u8,
+) -> Result<()> {
+    let remaining_accounts = ctx.remaining_accounts;
+    require!(
+        remaining_accounts.len() % PUSH_INCENTIVES_GROUP_LEN == 0,
+        ErrorCode::InvalidAccountGroups
+    );
+    
+    let vault_key = ctx.accounts.vault_account.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Pushed rewards are paid out instantly, like a pull claim without vesting
+    require!(!ctx.accounts.vault_account.retain_earnings, ErrorCode::RetainedEarningsMode);
+    require!(ctx.accounts.vault_account.reward_vesting_duration == 0, ErrorCode::VestingVault);
+    
+    let reward_tracker = &mut ctx.accounts.reward_tracker;
+    if reward_tracker.vault == Pubkey::default() {
+        reward_tracker.vault = vault_key;
+        reward_tracker.bump = *ctx.bumps.get("reward_tracker").unwrap();
+    }
+    
+    if reward_tracker.push_cursor == Pubkey::default() {
+        reward_tracker.pass_started_at = current_time;
+    }
+    
+    let bump = ctx.accounts.vault_account.nonce;
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let mut positions_credited: u32 = 0;
+    let mut page_amount: u64 = 0;
+    
+    // Stop after a bounded number of positions so a page never runs out of compute
+    // halfway; the cursor records where the next page resumes
+    let page_limit = match max_positions as usize {
+        0 => MAX_PUSH_INCENTIVES_PAGE,
+        limit => limit.min(MAX_PUSH_INCENTIVES_PAGE),
+    };
+    let page_truncated = remaining_accounts.len() / PUSH_INCENTIVES_GROUP_LEN > page_limit;
+    
+    for group in remaining_accounts.chunks(PUSH_INCENTIVES_GROUP_LEN).take(page_limit) {
+        let position_info = &group[0];
+        let owner_token_info = &group[1];
+        
+        // Positions are walked in key order so each one is credited at most once per pass
+        require!(position_info.key() > reward_tracker.push_cursor, ErrorCode::PositionOutOfOrder);
+        reward_tracker.push_cursor = position_info.key();
+        
+        let mut lp_position: LPPosition = load_program_account(position_info, ctx.program_id)?;
+        let owner_token_account: TokenAccount = load_program_account(owner_token_info, &token::ID)?;
+        
+        require_keys_eq!(lp_position.vault, vault_key, ErrorCode::PositionVaultMismatch);
+        
+        // Rewards are always paid to the position owner
+        require_keys_eq!(owner_token_account.mint, ctx.accounts.vault_account.token_mint, ErrorCode::InvalidOwnerTokenAccount);
+        require_keys_eq!(owner_token_account.owner, lp_position.owner, ErrorCode::InvalidOwnerTokenAccount);
+        
+        let vault_account = &mut ctx.accounts.vault_account;
+        // Protocol-owned liquidity earns no incentives; its yield goes to the treasury on withdrawal
+        if vault_account.accrued_lp_fees == 0 || lp_position.shares == 0 || lp_position.is_protocol {
+            continue;
+        }
+        
+        let (reward_amount, shares_burned) = vault_account.harvest_fee_share(lp_position.shares)?;
+        if reward_amount == 0 {
+            continue;
+        }
+        
+        lp_position.shares = lp_position.shares.checked_sub(shares_burned).ok_or(ErrorCode::MathOverflow)?;
+        lp_position.rewards_claimed = lp_position.rewards_claimed.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+        lp_position.last_rewards_claim_time = current_time;
+        
+        let transfer_cpi_accounts = Transfer {
+            from: ctx.accounts.vault_token_account.to_account_info(),
+            to: owner_token_info.clone(),
+            authority: ctx.accounts.vault_authority.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_cpi_accounts,
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, reward_amount)?;
+        
+        store_program_account(&lp_position, position_info)?;
+        
+        positions_credited += 1;
+        page_amount = page_amount.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?;
+    }
+    
+    reward_tracker.total_rewards = reward_tracker.total_rewards.checked_add(page_amount).ok_or(ErrorCode::MathOverflow)?;
+    reward_tracker.total_deposits = ctx.accounts.vault_account.total_shares;
+    reward_tracker.last_update_time = current_time;
+    
+    let cursor = reward_tracker.push_cursor;
+    let pass = reward_tracker.push_pass;
+    
+    // The cranker closes the pass after its last page; the next page starts a new one
+    // A truncated page leaves the pass open so the skipped positions are credited next
+    let pass_completed = complete_pass && !page_truncated;
+    if pass_completed {
+        reward_tracker.push_cursor = Pubkey::default();
+        reward_tracker.push_pass = reward_tracker.push_pass.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+    }
+    
+    emit!(IncentivesPushed {
+        vault: vault_key,
+        pass,
+        cursor,
+        positions_credited,
+        amount: page_amount,
+        pass_completed,
+    });
+    
+    msg!("Pushed {} tokens in rewards to {} LP positions", page_amount, positions_credited);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Remaining accounts must be pairs of LP position and owner token account")]
+    InvalidAccountGroups,
+    
+    #[msg("Positions must follow the pass cursor in ascending key order")]
+    PositionOutOfOrder,
+    
+    #[msg("LP position does not belong to the vault")]
+    PositionVaultMismatch,
+    
+    #[msg("Reward token account must be the position owner's account for the vault mint")]
+    InvalidOwnerTokenAccount,
+    
+    #[msg("Vault reinvests LP fees; they accrue to share value instead of being claimed")]
+    RetainedEarningsMode,
+    
+    #[msg("Vault vests rewards; LPs claim them with distribute_incentives")]
+    VestingVault,
+} 
\ No newline at end of file
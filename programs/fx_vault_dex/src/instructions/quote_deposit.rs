@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, LPPosition, ProtocolConfig, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED};
+use crate::instructions::calculate_withdrawal_fee_bps;
+
+/// Preview of a deposit, returned to the caller as return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct DepositQuote {
+    pub shares: u64,                 // Shares the deposit would mint at the current exchange rate
+    pub virtual_price: u64,          // Value of one share in tokens, scaled by PRECISION
+    pub withdrawal_fee_bps: u16,     // Penalty on withdrawing right after the deposit
+    pub position_shares: u64,        // Shares the position would hold after the deposit
+}
+
+#[derive(Accounts)]
+pub struct QuoteDeposit<'info> {
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Existing position the deposit would add to; omitted for a first deposit
+    #[account(
+        constraint = lp_position.vault == vault_account.key(),
+    )]
+    pub lp_position: Option<Account<'info, LPPosition>>,
+}
+
+pub fn handler(ctx: Context<QuoteDeposit>, amount: u64) -> Result<DepositQuote> {
+    let vault_account = &ctx.accounts.vault_account;
+    
+    require!(!vault_account.deposits_paused, ErrorCode::DepositsPaused);
+    require!(!vault_account.wound_down, ErrorCode::VaultWoundDown);
+    require!(amount >= vault_account.min_deposit_amount, ErrorCode::BelowMinimumAmount);
+    
+    let shares = vault_account.shares_for_deposit(amount)?;
+    
+    // A deposit restarts the position's penalty schedule
+    let current_time = Clock::get()?.unix_timestamp;
+    let mut lp_position = ctx.accounts.lp_position.as_ref().map(|position| (**position).clone()).unwrap_or_default();
+    lp_position.shares = lp_position.shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
+    lp_position.last_deposit_time = current_time;
+    
+    Ok(DepositQuote {
+        shares,
+        virtual_price: vault_account.virtual_price(),
+        withdrawal_fee_bps: calculate_withdrawal_fee_bps(vault_account, &lp_position, &ctx.accounts.protocol_config, current_time),
+        position_shares: lp_position.shares,
+    })
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Deposits into this vault are paused")]
+    DepositsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
+} 
\ No newline at end of file
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, LPPosition, ProtocolConfig, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED};
+use crate::instructions::calculate_withdrawal_fee_bps;
+use crate::utils::calculate_amount_for_shares;
+
+/// Preview of a withdrawal, returned to the caller as return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct WithdrawQuote {
+    pub amount: u64,                 // Tokens the shares are worth before the penalty
+    pub withdrawal_fee_bps: u16,     // Current penalty tier for the position
+    pub penalty_amount: u64,         // Tokens withheld as the withdrawal penalty
+    pub amount_out: u64,             // Tokens paid out after the penalty
+    pub pending_rewards: u64,        // Retained LP fees the whole position could claim now
+}
+
+#[derive(Accounts)]
+pub struct QuoteWithdraw<'info> {
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        constraint = lp_position.vault == vault_account.key(),
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+}
+
+pub fn handler(ctx: Context<QuoteWithdraw>, shares: u64) -> Result<WithdrawQuote> {
+    let vault_account = &ctx.accounts.vault_account;
+    let lp_position = &ctx.accounts.lp_position;
+    
+    require!(!vault_account.withdrawals_paused, ErrorCode::WithdrawalsPaused);
+    require!(lp_position.shares >= shares, ErrorCode::InsufficientFunds);
+    
+    let amount = calculate_amount_for_shares(shares, vault_account.lp_assets(), vault_account.total_shares)?;
+    require!(
+        amount >= vault_account.min_withdraw_amount || shares == lp_position.shares,
+        ErrorCode::BelowMinimumAmount
+    );
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let withdrawal_fee_bps = calculate_withdrawal_fee_bps(vault_account, lp_position, &ctx.accounts.protocol_config, current_time);
+    let penalty_amount = (amount as u128 * withdrawal_fee_bps as u128 / 10000) as u64;
+    
+    // LP fees are claimed through distribute_incentives unless the vault retains earnings
+    let pending_rewards = if vault_account.retain_earnings {
+        0
+    } else {
+        calculate_amount_for_shares(lp_position.shares, vault_account.accrued_lp_fees, vault_account.total_shares)?
+    };
+    
+    Ok(WithdrawQuote {
+        amount,
+        withdrawal_fee_bps,
+        penalty_amount,
+        amount_out: amount - penalty_amount,
+        pending_rewards,
+    })
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insufficient shares in LP position")]
+    InsufficientFunds,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Withdrawals from this vault are paused")]
+    WithdrawalsPaused,
+} 
\ No newline at end of file
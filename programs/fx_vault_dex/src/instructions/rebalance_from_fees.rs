@@ -0,0 +1,240 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{
+    VaultAccount, VaultPair, KeeperRegistry, KeeperBond, Role, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, VAULT_PAIR_SEED,
+    KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED, REBALANCE_FROM_FEES_HEALTH_BPS, PRICE_SCALE
+};
+use crate::events::RebalancedFromFees;
+use crate::utils::{calculate_vault_health, get_oracle_price, calculate_cross_rate};
+
+#[derive(Accounts)]
+pub struct RebalanceFromFees<'info> {
+    // Holds the rebalancer role on both vaults, or is a bonded keeper
+    pub rebalancer: Signer<'info>,
+    
+    // Vault with the lower TVL, whose accrued PDA fees are moved into its TVL
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, deficit_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub deficit_vault: Account<'info, VaultAccount>,
+    
+    // Vault with the higher TVL, whose accrued PDA fees are converted at the oracle cross rate
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, surplus_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub surplus_vault: Account<'info, VaultAccount>,
+    
+    #[account(
+        seeds = [
+            VAULT_PAIR_SEED,
+            deficit_vault.token_mint.min(surplus_vault.token_mint).as_ref(),
+            deficit_vault.token_mint.max(surplus_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    /// CHECK: Bound to the deficit vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = deficit_oracle.key() == deficit_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub deficit_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the surplus vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = surplus_oracle.key() == surplus_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub surplus_oracle: AccountInfo<'info>,
+    
+    /// CHECK: This is the surplus vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, surplus_vault.key().as_ref()],
+        bump = surplus_vault.nonce,
+    )]
+    pub surplus_vault_authority: AccountInfo<'info>,
+    
+    // Rebalancer token accounts, the counterparty for the surplus fee conversion
+    #[account(
+        mut,
+        constraint = rebalancer_deficit_token.mint == deficit_vault.token_mint,
+        constraint = rebalancer_deficit_token.owner == rebalancer.key(),
+    )]
+    pub rebalancer_deficit_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = rebalancer_surplus_token.mint == surplus_vault.token_mint,
+        constraint = rebalancer_surplus_token.owner == rebalancer.key(),
+    )]
+    pub rebalancer_surplus_token: Account<'info, TokenAccount>,
+    
+    // Vault token accounts
+    #[account(
+        mut,
+        constraint = deficit_vault_token.key() == deficit_vault.token_account,
+    )]
+    pub deficit_vault_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = surplus_vault_token.key() == surplus_vault.token_account,
+    )]
+    pub surplus_vault_token: Account<'info, TokenAccount>,
+    
+    // Only needed when the rebalancer calls as a bonded keeper
+    #[account(
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Option<Account<'info, KeeperRegistry>>,
+    
+    #[account(
+        seeds = [KEEPER_BOND_SEED, rebalancer.key().as_ref()],
+        bump = keeper_bond.bump,
+    )]
+    pub keeper_bond: Option<Account<'info, KeeperBond>>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<RebalanceFromFees>) -> Result<()> {
+    let rebalancer = ctx.accounts.rebalancer.key();
+    let is_role_holder = ctx.accounts.deficit_vault.has_role(Role::Rebalancer, &rebalancer)
+        && ctx.accounts.surplus_vault.has_role(Role::Rebalancer, &rebalancer);
+    let is_bonded_keeper = match (&ctx.accounts.keeper_registry, &ctx.accounts.keeper_bond) {
+        (Some(keeper_registry), Some(keeper_bond)) => keeper_bond.is_active(keeper_registry.min_bond),
+        _ => false,
+    };
+    require!(is_role_holder || is_bonded_keeper, ErrorCode::Unauthorized);
+    
+    let surplus_amount = ctx.accounts.surplus_vault.tvl;
+    let deficit_amount = ctx.accounts.deficit_vault.tvl;
+    require!(deficit_amount < surplus_amount, ErrorCode::NotDeficitVault);
+    
+    let health_before = calculate_vault_health(deficit_amount, surplus_amount);
+    require!(
+        ((health_before * 10000.0) as u16) < REBALANCE_FROM_FEES_HEALTH_BPS,
+        ErrorCode::NoRebalanceNeeded
+    );
+    
+    // Price of one surplus token in deficit tokens, from the two approved feeds
+    let oracle_time = Clock::get()?.unix_timestamp;
+    let deficit_price = get_oracle_price(&ctx.accounts.deficit_oracle, oracle_time)?;
+    let surplus_price = get_oracle_price(&ctx.accounts.surplus_oracle, oracle_time)?;
+    let oracle_price = calculate_cross_rate(surplus_price.price, deficit_price.price)?;
+    require!(oracle_price > 0, ErrorCode::InvalidOracle);
+    
+    // Top up just enough to bring health back to the threshold, as far as the fees allow
+    let target_amount = (surplus_amount as u128 * REBALANCE_FROM_FEES_HEALTH_BPS as u128 / 10000) as u64;
+    let needed = target_amount.saturating_sub(deficit_amount);
+    
+    // The deficit vault's PDA fees are already held in the deficit currency and its token account
+    let deficit_fees_used = needed.min(ctx.accounts.deficit_vault.accrued_pda_fees);
+    
+    // The rest is funded by converting the surplus vault's PDA fees at the oracle cross rate:
+    // the rebalancer pays the deficit currency into the deficit vault and takes the same value
+    // of surplus PDA fees out of the surplus vault
+    let surplus_fees_value = (ctx.accounts.surplus_vault.accrued_pda_fees as u128)
+        .checked_mul(oracle_price as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / PRICE_SCALE as u128;
+    let converted_amount = (needed - deficit_fees_used).min(surplus_fees_value.min(u64::MAX as u128) as u64);
+    let surplus_fees_converted = (converted_amount as u128)
+        .checked_mul(PRICE_SCALE as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        / oracle_price as u128;
+    let surplus_fees_converted = u64::try_from(surplus_fees_converted).map_err(|_| ErrorCode::MathOverflow)?;
+    
+    let top_up = deficit_fees_used.checked_add(converted_amount).ok_or(ErrorCode::MathOverflow)?;
+    require!(top_up > 0, ErrorCode::NoFeesAvailable);
+    
+    if converted_amount > 0 {
+        let transfer_accounts = Transfer {
+            from: ctx.accounts.rebalancer_deficit_token.to_account_info(),
+            to: ctx.accounts.deficit_vault_token.to_account_info(),
+            authority: ctx.accounts.rebalancer.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+        );
+        
+        token::transfer(cpi_ctx, converted_amount)?;
+        
+        let surplus_vault_key = ctx.accounts.surplus_vault.key();
+        let seeds = &[
+            VAULT_AUTHORITY_SEED,
+            surplus_vault_key.as_ref(),
+            &[ctx.accounts.surplus_vault.nonce],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        
+        let transfer_accounts = Transfer {
+            from: ctx.accounts.surplus_vault_token.to_account_info(),
+            to: ctx.accounts.rebalancer_surplus_token.to_account_info(),
+            authority: ctx.accounts.surplus_vault_authority.to_account_info(),
+        };
+        
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+        
+        token::transfer(cpi_ctx, surplus_fees_converted)?;
+    }
+    
+    let surplus_vault = &mut ctx.accounts.surplus_vault;
+    surplus_vault.accrued_pda_fees = surplus_vault.accrued_pda_fees
+        .checked_sub(surplus_fees_converted)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    let deficit_vault = &mut ctx.accounts.deficit_vault;
+    deficit_vault.accrued_pda_fees -= deficit_fees_used;
+    deficit_vault.tvl = deficit_vault.tvl.checked_add(top_up).ok_or(ErrorCode::MathOverflow)?;
+    
+    let health_after = calculate_vault_health(deficit_vault.tvl, surplus_amount);
+    
+    // The PDA treasury's fee entitlement spent on each side is recorded so the treasury can reconcile it
+    emit!(RebalancedFromFees {
+        deficit_vault: deficit_vault.key(),
+        surplus_vault: ctx.accounts.surplus_vault.key(),
+        amount: top_up,
+        deficit_fees_used,
+        surplus_fees_converted,
+        oracle_price,
+        health_before_bps: (health_before * 10000.0) as u16,
+        health_after_bps: (health_after * 10000.0) as u16,
+    });
+    
+    msg!("Rebalanced from fees: moved {} deficit PDA fees and {} converted surplus PDA fees into TVL. Vault health improved from {:.4} to {:.4}",
+         deficit_fees_used, surplus_fees_converted, health_before, health_after);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Signer neither holds the rebalancer role on both vaults nor is a bonded keeper")]
+    Unauthorized,
+    
+    #[msg("Deficit vault must hold less than the surplus vault")]
+    NotDeficitVault,
+    
+    #[msg("Vault health is above the rebalance-from-fees threshold")]
+    NoRebalanceNeeded,
+    
+    #[msg("Neither vault has accrued PDA fees to rebalance with")]
+    NoFeesAvailable,
+    
+    #[msg("Oracle account does not match the vault's approved feed")]
+    InvalidOracle,
+} 
\ No newline at end of file
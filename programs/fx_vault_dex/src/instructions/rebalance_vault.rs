@@ -1,10 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED};
-use crate::utils::calculate_vault_health;
+use crate::state::{
+    VaultAccount, ProtocolConfig, VaultPair, KeeperRegistry, KeeperBond, Role, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED,
+    VAULT_PAIR_SEED, PROTOCOL_CONFIG_SEED, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED, MAX_REBALANCE_PRICE_DEVIATION_BPS, PRICE_SCALE
+};
+use crate::utils::{calculate_vault_health, get_oracle_price, calculate_cross_rate, calculate_price_deviation_bps};
 
 #[derive(Accounts)]
 pub struct RebalanceVault<'info> {
+    // Holds the rebalancer role on both vaults, or is a bonded keeper
     #[account(mut)]
     pub rebalancer: Signer<'info>,
     
@@ -24,6 +28,36 @@ pub struct RebalanceVault<'info> {
     )]
     pub target_vault: Account<'info, VaultAccount>,
     
+    // Only vaults registered as a pair can be rebalanced against each other
+    #[account(
+        seeds = [
+            VAULT_PAIR_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    /// CHECK: Bound to the source vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = source_oracle.key() == source_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub source_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the target vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = target_oracle.key() == target_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub target_oracle: AccountInfo<'info>,
+    
     /// CHECK: This is the source vault authority PDA
     #[account(
         seeds = [VAULT_AUTHORITY_SEED, source_vault.key().as_ref()],
@@ -31,13 +65,6 @@ pub struct RebalanceVault<'info> {
     )]
     pub source_vault_authority: AccountInfo<'info>,
     
-    /// CHECK: Ensure rebalancer is the PDA treasury
-    #[account(
-        constraint = rebalancer.key() == target_vault.pda_treasury,
-        constraint = rebalancer.key() == source_vault.pda_treasury,
-    )]
-    pub pda_treasury: AccountInfo<'info>,
-    
     // Rebalancer token accounts
     #[account(
         mut,
@@ -66,6 +93,19 @@ pub struct RebalanceVault<'info> {
     )]
     pub target_vault_token: Account<'info, TokenAccount>,
     
+    // Only needed when the rebalancer calls as a bonded keeper
+    #[account(
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Option<Account<'info, KeeperRegistry>>,
+    
+    #[account(
+        seeds = [KEEPER_BOND_SEED, rebalancer.key().as_ref()],
+        bump = keeper_bond.bump,
+    )]
+    pub keeper_bond: Option<Account<'info, KeeperBond>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -75,22 +115,42 @@ pub fn handler(
     amount: u64,
     oracle_price: u64,
 ) -> Result<()> {
+    let rebalancer = ctx.accounts.rebalancer.key();
+    let is_role_holder = ctx.accounts.source_vault.has_role(Role::Rebalancer, &rebalancer)
+        && ctx.accounts.target_vault.has_role(Role::Rebalancer, &rebalancer);
+    let is_bonded_keeper = match (&ctx.accounts.keeper_registry, &ctx.accounts.keeper_bond) {
+        (Some(keeper_registry), Some(keeper_bond)) => keeper_bond.is_active(keeper_registry.min_bond),
+        _ => false,
+    };
+    require!(is_role_holder || is_bonded_keeper, ErrorCode::Unauthorized);
+    
+    // The rebalancer's quoted price is only a bound; the stored price comes from the oracles
+    let oracle_time = Clock::get()?.unix_timestamp;
+    let source_price = get_oracle_price(&ctx.accounts.source_oracle, oracle_time)?;
+    let target_price = get_oracle_price(&ctx.accounts.target_oracle, oracle_time)?;
+    let onchain_price = calculate_cross_rate(source_price.price, target_price.price)?;
+    require!(
+        calculate_price_deviation_bps(oracle_price, onchain_price) <= MAX_REBALANCE_PRICE_DEVIATION_BPS,
+        ErrorCode::PriceDeviationTooHigh
+    );
+    
     let source_vault = &mut ctx.accounts.source_vault;
     let target_vault = &mut ctx.accounts.target_vault;
     
     // Calculate vault health to determine injection rate
     let source_amount = source_vault.tvl;
     let target_amount = target_vault.tvl;
+    require!(target_amount < source_amount, ErrorCode::TargetNotDeficit);
     let vault_health = calculate_vault_health(source_amount, target_amount);
     
     // Determine injection rate based on vault health
-    let injection_rate: f64 = if vault_health >= 0.40 && vault_health < 0.50 {
+    let injection_rate: f64 = if (0.40..0.50).contains(&vault_health) {
         // Mild imbalance - 30% of deficit
         0.30
-    } else if vault_health >= 0.30 && vault_health < 0.40 {
+    } else if (0.30..0.40).contains(&vault_health) {
         // Moderate imbalance - 50% of deficit
         0.50
-    } else if vault_health >= 0.20 && vault_health < 0.30 {
+    } else if (0.20..0.30).contains(&vault_health) {
         // Critical imbalance - 75% of deficit
         0.75
     } else {
@@ -98,10 +158,8 @@ pub fn handler(
         return Err(ErrorCode::NoRebalanceNeeded.into());
     };
     
-    // Calculate deficit and injection amount
-    let smaller_amount = source_amount.min(target_amount) as f64;
-    let larger_amount = source_amount.max(target_amount) as f64;
-    let deficit = larger_amount - (smaller_amount / vault_health);
+    // The deficit is the gap between the two vaults' TVLs, the injection that would bring health to 1.0
+    let deficit = (source_amount - target_amount) as f64;
     let injection_amount = (deficit * injection_rate) as u64;
     
     // Validate injection amount doesn't exceed provided amount
@@ -124,15 +182,59 @@ pub fn handler(
     // Update the target vault's TVL
     target_vault.tvl = target_vault.tvl.checked_add(injection_amount).ok_or(ErrorCode::MathOverflow)?;
     
+    // The rebalancer is repaid in the surplus currency at its quoted rate, which may beat
+    // the oracle rate by at most the configured premium
+    let premium_bps = if oracle_price < onchain_price {
+        calculate_price_deviation_bps(oracle_price, onchain_price)
+    } else {
+        0
+    };
+    require!(
+        premium_bps <= ctx.accounts.protocol_config.max_rebalance_premium_bps as u64,
+        ErrorCode::RebalancePremiumTooHigh
+    );
+    
+    let repayment = (injection_amount as u128)
+        .checked_mul(PRICE_SCALE as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(oracle_price as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let repayment = u64::try_from(repayment).map_err(|_| ErrorCode::MathOverflow)?;
+    require!(source_vault.tvl >= repayment, ErrorCode::InsufficientSurplus);
+    
+    let source_vault_key = source_vault.key();
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        source_vault_key.as_ref(),
+        &[source_vault.nonce],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let repayment_accounts = Transfer {
+        from: ctx.accounts.source_vault_token.to_account_info(),
+        to: ctx.accounts.rebalancer_source_token.to_account_info(),
+        authority: ctx.accounts.source_vault_authority.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        repayment_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, repayment)?;
+    
+    source_vault.tvl = source_vault.tvl.checked_sub(repayment).ok_or(ErrorCode::MathOverflow)?;
+    
     // Calculate new vault health after injection
-    let new_vault_health = calculate_vault_health(source_amount, target_vault.tvl);
+    let new_vault_health = calculate_vault_health(source_vault.tvl, target_vault.tvl);
     
     // Update oracle price data
-    source_vault.last_oracle_price = oracle_price;
-    source_vault.last_update_timestamp = Clock::get()?.unix_timestamp;
+    source_vault.last_oracle_price = onchain_price;
+    source_vault.last_update_timestamp = oracle_time;
     
-    msg!("Rebalanced vault: Injected {} tokens. Vault health improved from {:.4} to {:.4}", 
-         injection_amount, vault_health, new_vault_health);
+    msg!("Rebalanced vault: Injected {} tokens for {} surplus tokens. Vault health improved from {:.4} to {:.4}", 
+         injection_amount, repayment, vault_health, new_vault_health);
     
     Ok(())
 }
@@ -147,4 +249,22 @@ pub enum ErrorCode {
     
     #[msg("Insufficient injection amount for required rebalancing")]
     InsufficientInjectionAmount,
+    
+    #[msg("Signer neither holds the rebalancer role on both vaults nor is a bonded keeper")]
+    Unauthorized,
+    
+    #[msg("Oracle account does not match the vault's feed")]
+    InvalidOracle,
+    
+    #[msg("Rebalancer's price deviates too far from the oracle price")]
+    PriceDeviationTooHigh,
+    
+    #[msg("Rebalancer's rate beats the oracle rate by more than the allowed premium")]
+    RebalancePremiumTooHigh,
+    
+    #[msg("Source vault cannot cover the surplus repayment")]
+    InsufficientSurplus,
+    
+    #[msg("Target vault must hold less than the source vault")]
+    TargetNotDeficit,
 } 
\ No newline at end of file
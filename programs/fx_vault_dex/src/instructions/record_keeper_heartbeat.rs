@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{KeeperRegistry, KeeperBond, KeeperHeartbeat, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED, KEEPER_HEARTBEAT_SEED};
+
+#[derive(Accounts)]
+pub struct RecordKeeperHeartbeat<'info> {
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    #[account(
+        seeds = [KEEPER_BOND_SEED, keeper.key().as_ref()],
+        bump = keeper_bond.bump,
+        constraint = keeper_bond.is_active(keeper_registry.min_bond) @ ErrorCode::KeeperNotBonded,
+    )]
+    pub keeper_bond: Account<'info, KeeperBond>,
+    
+    // Shared by all keepers; any bonded keeper checking in keeps the protocol live
+    #[account(
+        init_if_needed,
+        payer = keeper,
+        space = KeeperHeartbeat::LEN,
+        seeds = [KEEPER_HEARTBEAT_SEED],
+        bump,
+    )]
+    pub keeper_heartbeat: Account<'info, KeeperHeartbeat>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RecordKeeperHeartbeat>) -> Result<()> {
+    let keeper_heartbeat = &mut ctx.accounts.keeper_heartbeat;
+    
+    if keeper_heartbeat.bump == 0 {
+        keeper_heartbeat.bump = *ctx.bumps.get("keeper_heartbeat").unwrap();
+    }
+    
+    keeper_heartbeat.last_keeper = ctx.accounts.keeper.key();
+    keeper_heartbeat.last_heartbeat_time = Clock::get()?.unix_timestamp;
+    keeper_heartbeat.heartbeat_count = keeper_heartbeat.heartbeat_count.saturating_add(1);
+    
+    msg!("Keeper {} heartbeat #{}", keeper_heartbeat.last_keeper, keeper_heartbeat.heartbeat_count);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Keeper has not bonded the registry minimum")]
+    KeeperNotBonded,
+} 
\ No newline at end of file
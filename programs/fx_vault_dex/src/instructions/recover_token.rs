@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, ADMIN_LOG_SEED};
+use crate::events::TokenRecovered;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct RecoverToken<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    // Token account of a foreign mint held by the vault authority; the vault mint can never be recovered
+    #[account(
+        mut,
+        constraint = stray_token_account.owner == vault_authority.key(),
+        constraint = stray_token_account.mint != vault_account.token_mint @ ErrorCode::VaultMintNotRecoverable,
+        constraint = stray_token_account.key() != vault_account.token_account @ ErrorCode::VaultMintNotRecoverable,
+    )]
+    pub stray_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == stray_token_account.mint,
+        constraint = treasury_token_account.owner == vault_account.treasury,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<RecoverToken>) -> Result<()> {
+    let amount = ctx.accounts.stray_token_account.amount;
+    require!(amount > 0, ErrorCode::NothingToRecover);
+    
+    // Transfer the whole stray balance to the treasury
+    let vault_key = ctx.accounts.vault_account.key();
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[ctx.accounts.vault_account.nonce],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.stray_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    emit!(TokenRecovered {
+        vault: vault_key,
+        mint: ctx.accounts.stray_token_account.mint,
+        source: ctx.accounts.stray_token_account.key(),
+        destination: ctx.accounts.treasury_token_account.key(),
+        amount,
+    });
+    
+    msg!("Recovered {} tokens of mint {} to the treasury", amount, ctx.accounts.stray_token_account.mint);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::RecoverToken,
+        ctx.accounts.stray_token_account.key(),
+        amount,
+        0,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("The vault's own mint cannot be recovered")]
+    VaultMintNotRecoverable,
+    
+    #[msg("Token account holds nothing to recover")]
+    NothingToRecover,
+} 
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeedRegistry, FeedEntry, AdminLog, AdminAction, FEED_REGISTRY_SEED, MAX_REGISTERED_FEEDS, ADMIN_LOG_SEED};
+use crate::utils::{validate_oracle_data, record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct RegisterFeed<'info> {
+    #[account(
+        constraint = admin.key() == feed_registry.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    /// CHECK: Parsed as a Pyth price account in the handler
+    pub oracle: AccountInfo<'info>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterFeed>,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.feed_registry.feeds);
+    
+    let feed_registry = &mut ctx.accounts.feed_registry;
+    let oracle = ctx.accounts.oracle.key();
+    
+    // Only real price accounts can be approved
+    validate_oracle_data(&ctx.accounts.oracle)?;
+    
+    // Replace the approved feed for an existing pair, otherwise add it
+    if let Some(feed) = feed_registry.feeds
+        .iter_mut()
+        .find(|feed| feed.base_mint == base_mint && feed.quote_mint == quote_mint)
+    {
+        feed.oracle = oracle;
+    } else {
+        require!(feed_registry.feeds.len() < MAX_REGISTERED_FEEDS, ErrorCode::RegistryFull);
+        feed_registry.feeds.push(FeedEntry {
+            base_mint,
+            quote_mint,
+            oracle,
+        });
+    }
+    
+    msg!("Registered oracle {} for {}/{}", oracle, base_mint, quote_mint);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::RegisterFeed,
+        ctx.accounts.feed_registry.key(),
+        old_value,
+        fingerprint(&ctx.accounts.feed_registry.feeds),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Feed registry is full")]
+    RegistryFull,
+} 
\ No newline at end of file
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{FeedRegistry, AdminLog, AdminAction, FEED_REGISTRY_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct RemoveFeed<'info> {
+    #[account(
+        constraint = admin.key() == feed_registry.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<RemoveFeed>,
+    base_mint: Pubkey,
+    quote_mint: Pubkey,
+) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.feed_registry.feeds);
+    
+    let feed_registry = &mut ctx.accounts.feed_registry;
+    
+    let feeds_before = feed_registry.feeds.len();
+    feed_registry.feeds.retain(|feed| !(feed.base_mint == base_mint && feed.quote_mint == quote_mint));
+    require!(feed_registry.feeds.len() < feeds_before, ErrorCode::FeedNotFound);
+    
+    msg!("Removed approved feed for {}/{}", base_mint, quote_mint);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::RemoveFeed,
+        ctx.accounts.feed_registry.key(),
+        old_value,
+        fingerprint(&ctx.accounts.feed_registry.feeds),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No approved feed registered for this mint pair")]
+    FeedNotFound,
+} 
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct RenameVault<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<RenameVault>, vault_name: String) -> Result<()> {
+    require!(!vault_name.is_empty(), ErrorCode::EmptyVaultName);
+    
+    let old_value = fingerprint(&ctx.accounts.vault_account.vault_name);
+    
+    ctx.accounts.vault_account.vault_name = VaultAccount::encode_name(&vault_name).ok_or(ErrorCode::VaultNameTooLong)?;
+    
+    msg!("Vault renamed to {}", vault_name);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::RenameVault,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&ctx.accounts.vault_account.vault_name),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vault name is longer than 32 bytes")]
+    VaultNameTooLong,
+    
+    #[msg("Vault name cannot be empty")]
+    EmptyVaultName,
+} 
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, Role, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    let previous = ctx.accounts.vault_account.roles.get(role);
+    let old_value = fingerprint(&(role, previous));
+    
+    // The role falls back to its default holder
+    ctx.accounts.vault_account.roles.set(role, Pubkey::default());
+    
+    msg!("Revoked role {:?} from {}; now held by {}", role, previous, ctx.accounts.vault_account.role_holder(role));
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::RevokeRole,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(role, Pubkey::default())),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
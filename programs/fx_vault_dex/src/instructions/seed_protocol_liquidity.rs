@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{VaultAccount, LPPosition, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, POL_POSITION_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, check_transaction_guard};
+
+#[derive(Accounts)]
+pub struct SeedProtocolLiquidity<'info> {
+    #[account(
+        mut,
+        constraint = treasury.key() == vault_account.treasury,
+    )]
+    pub treasury: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Protocol-owned LP position, created on the first seed
+    // Keyed by the vault alone so it is never shared with the treasury's own deposits
+    // and survives a treasury handover
+    #[account(
+        init_if_needed,
+        payer = treasury,
+        space = LPPosition::LEN,
+        seeds = [POL_POSITION_SEED, vault_account.key().as_ref()],
+        bump,
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == vault_account.token_mint,
+        constraint = treasury_token_account.owner == treasury.key(),
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<SeedProtocolLiquidity>, amount: u64) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.vault_account, &ctx.accounts.vault_account.key(), instructions_sysvar.as_ref())?;
+    
+    let old_value = ctx.accounts.vault_account.protocol_owned_liquidity;
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    let lp_position = &mut ctx.accounts.lp_position;
+    
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(!vault_account.deposits_paused, ErrorCode::DepositsPaused);
+    require!(!vault_account.wound_down, ErrorCode::VaultWoundDown);
+    
+    // Initialize the protocol position on first use
+    if lp_position.vault == Pubkey::default() {
+        lp_position.vault = vault_account.key();
+        lp_position.bump = *ctx.bumps.get("lp_position").unwrap();
+        lp_position.is_protocol = true;
+    }
+    
+    // Transfer tokens from treasury to vault
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.treasury_token_account.to_account_info(),
+        to: ctx.accounts.vault_token_account.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    // Update the vault's total value locked and protocol-owned share
+    let shares = vault_account.issue_shares(amount)?;
+    vault_account.protocol_owned_liquidity = vault_account.protocol_owned_liquidity.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    // Update the protocol position; its owner follows the current treasury
+    lp_position.owner = ctx.accounts.treasury.key();
+    lp_position.shares = lp_position.shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
+    lp_position.last_deposit_time = Clock::get()?.unix_timestamp;
+    
+    msg!("Seeded {} tokens of protocol-owned liquidity into vault", amount);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.treasury.key(),
+        AdminAction::SeedProtocolLiquidity,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        ctx.accounts.vault_account.protocol_owned_liquidity,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Seed amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Deposits into this vault are paused")]
+    DepositsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
+} 
\ No newline at end of file
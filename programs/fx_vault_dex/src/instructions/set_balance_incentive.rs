@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, MAX_BALANCE_BONUS_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetBalanceIncentive<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetBalanceIncentive>,
+    target_health_bps: u16,
+    bonus_bps: u16,
+    bonus_cap: u64,
+    cooldown: i64,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.vault_account.balance_target_health_bps, ctx.accounts.vault_account.balance_bonus_bps, ctx.accounts.vault_account.balance_bonus_cap, ctx.accounts.vault_account.balance_bonus_cooldown));
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    // Validate the incentive parameters
+    require!(target_health_bps <= 10000, ErrorCode::InvalidTargetHealth);
+    require!(bonus_bps <= MAX_BALANCE_BONUS_BPS, ErrorCode::BonusTooHigh);
+    require!(cooldown >= 0, ErrorCode::InvalidCooldown);
+    
+    vault_account.balance_target_health_bps = target_health_bps;
+    vault_account.balance_bonus_bps = bonus_bps;
+    vault_account.balance_bonus_cap = bonus_cap;
+    vault_account.balance_bonus_cooldown = cooldown;
+    
+    msg!("Balance incentive set: target health {} bps, bonus {} bps, cap {}, cooldown {}s",
+         target_health_bps, bonus_bps, bonus_cap, cooldown);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetBalanceIncentive,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.vault_account.balance_target_health_bps, ctx.accounts.vault_account.balance_bonus_bps, ctx.accounts.vault_account.balance_bonus_cap, ctx.accounts.vault_account.balance_bonus_cooldown)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Target health must be at most 10000 basis points")]
+    InvalidTargetHealth,
+    
+    #[msg("Bonus is too high, maximum is 1%")]
+    BonusTooHigh,
+    
+    #[msg("Cooldown cannot be negative")]
+    InvalidCooldown,
+} 
\ No newline at end of file
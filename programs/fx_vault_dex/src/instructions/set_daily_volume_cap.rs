@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetDailyVolumeCap<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetDailyVolumeCap>, daily_volume_cap: u64) -> Result<()> {
+    let old_value = ctx.accounts.vault_account.daily_volume_cap;
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    vault_account.daily_volume_cap = daily_volume_cap;
+    
+    msg!("Daily volume cap set to {} (0 = unlimited)", daily_volume_cap);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetDailyVolumeCap,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        ctx.accounts.vault_account.daily_volume_cap,
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
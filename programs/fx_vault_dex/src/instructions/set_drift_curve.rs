@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, DriftCurve, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, MAX_DRIFT_SLOPE_PPM, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetDriftCurve<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetDriftCurve>, drift_curve: DriftCurve) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.drift_curve);
+    
+    // Validate the curve shape
+    require!(drift_curve.start_health_bps <= 10000, ErrorCode::InvalidHealth);
+    require!(drift_curve.kink_health_bps <= drift_curve.start_health_bps, ErrorCode::InvalidKink);
+    require!(
+        drift_curve.gentle_slope_ppm <= drift_curve.steep_slope_ppm,
+        ErrorCode::InvalidSlopes
+    );
+    require!(drift_curve.steep_slope_ppm <= MAX_DRIFT_SLOPE_PPM, ErrorCode::SlopeTooHigh);
+    
+    ctx.accounts.protocol_config.drift_curve = drift_curve;
+    
+    msg!("Drift curve set: start {} bps, kink {} bps, slopes {}/{} ppm",
+         drift_curve.start_health_bps, drift_curve.kink_health_bps,
+         drift_curve.gentle_slope_ppm, drift_curve.steep_slope_ppm);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetDriftCurve,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.drift_curve),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Start health must be at most 10000 basis points")]
+    InvalidHealth,
+    
+    #[msg("Kink health must not exceed the start health")]
+    InvalidKink,
+    
+    #[msg("Gentle slope must not exceed the steep slope")]
+    InvalidSlopes,
+    
+    #[msg("Drift slope is too high, maximum is 10% per unit of health")]
+    SlopeTooHigh,
+} 
\ No newline at end of file
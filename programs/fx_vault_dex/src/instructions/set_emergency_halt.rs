@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{EmergencyState, AdminLog, AdminAction, EMERGENCY_STATE_SEED, ADMIN_LOG_SEED};
+use crate::events::EmergencyHaltChanged;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetEmergencyHalt<'info> {
+    #[account(
+        constraint = guardian.key() == emergency_state.guardian,
+    )]
+    pub guardian: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetEmergencyHalt>, halted: bool) -> Result<()> {
+    let old_value = ctx.accounts.emergency_state.halted as u64;
+    
+    let emergency_state = &mut ctx.accounts.emergency_state;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    emergency_state.halted = halted;
+    emergency_state.last_changed = current_time;
+    
+    emit!(EmergencyHaltChanged {
+        guardian: ctx.accounts.guardian.key(),
+        halted,
+        timestamp: current_time,
+    });
+    
+    msg!("Emergency halt {}", if halted { "engaged" } else { "lifted" });
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.guardian.key(),
+        AdminAction::SetEmergencyHalt,
+        ctx.accounts.emergency_state.key(),
+        old_value,
+        ctx.accounts.emergency_state.halted as u64,
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
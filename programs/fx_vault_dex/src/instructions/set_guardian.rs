@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, EmergencyState, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, EMERGENCY_STATE_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.emergency_state.guardian);
+    
+    ctx.accounts.emergency_state.guardian = guardian;
+    
+    msg!("Emergency guardian set to {}", guardian);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetGuardian,
+        ctx.accounts.emergency_state.key(),
+        old_value,
+        fingerprint(&ctx.accounts.emergency_state.guardian),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
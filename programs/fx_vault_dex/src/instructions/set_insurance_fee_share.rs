@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED, MAX_INSURANCE_FEE_BPS};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetInsuranceFeeShare<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetInsuranceFeeShare>, insurance_fee_bps: u16) -> Result<()> {
+    let old_value = ctx.accounts.protocol_config.insurance_fee_bps as u64;
+    
+    require!(insurance_fee_bps <= MAX_INSURANCE_FEE_BPS, ErrorCode::InsuranceFeeTooHigh);
+    require!(
+        insurance_fee_bps == 0 || ctx.accounts.protocol_config.insurance_fund != Pubkey::default(),
+        ErrorCode::MissingInsuranceFund
+    );
+    
+    ctx.accounts.protocol_config.insurance_fee_bps = insurance_fee_bps;
+    
+    msg!("Insurance fund share of protocol fees set to {} bps", insurance_fee_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetInsuranceFeeShare,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        ctx.accounts.protocol_config.insurance_fee_bps as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Insurance share cannot exceed 50% of protocol fees")]
+    InsuranceFeeTooHigh,
+    
+    #[msg("An insurance fund must be configured with set_penalty_split first")]
+    MissingInsuranceFund,
+} 
\ No newline at end of file
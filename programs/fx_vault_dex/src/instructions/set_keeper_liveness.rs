@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED, MAX_DEGRADED_SPREAD_BPS};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetKeeperLiveness<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetKeeperLiveness>,
+    keeper_liveness_window: i64,
+    degraded_spread_bps: u16,
+    degraded_max_trade_bps: u16,
+) -> Result<()> {
+    let old_value = ctx.accounts.protocol_config.keeper_liveness_window as u64;
+    
+    require!(keeper_liveness_window >= 0, ErrorCode::InvalidWindow);
+    require!(degraded_spread_bps <= MAX_DEGRADED_SPREAD_BPS, ErrorCode::DegradedSpreadTooHigh);
+    require!(degraded_max_trade_bps <= 10000, ErrorCode::InvalidTradeLimit);
+    
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.keeper_liveness_window = keeper_liveness_window;
+    protocol_config.degraded_spread_bps = degraded_spread_bps;
+    protocol_config.degraded_max_trade_bps = degraded_max_trade_bps;
+    
+    msg!("Keeper liveness window set to {}s (degraded spread: {} bps, degraded max trade: {} bps)",
+         keeper_liveness_window, degraded_spread_bps, degraded_max_trade_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetKeeperLiveness,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        keeper_liveness_window as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Liveness window cannot be negative")]
+    InvalidWindow,
+    
+    #[msg("Degraded spread cannot exceed 1%")]
+    DegradedSpreadTooHigh,
+    
+    #[msg("Degraded trade limit cannot exceed 10000 bps")]
+    InvalidTradeLimit,
+} 
\ No newline at end of file
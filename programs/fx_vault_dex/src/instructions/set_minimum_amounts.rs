@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetMinimumAmounts<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetMinimumAmounts>,
+    min_deposit_amount: u64,
+    min_withdraw_amount: u64,
+    min_swap_amount: u64,
+) -> Result<()> {
+    let vault_account = &mut ctx.accounts.vault_account;
+    let old_value = fingerprint(&(
+        vault_account.min_deposit_amount,
+        vault_account.min_withdraw_amount,
+        vault_account.min_swap_amount,
+    ));
+    
+    vault_account.min_deposit_amount = min_deposit_amount;
+    vault_account.min_withdraw_amount = min_withdraw_amount;
+    vault_account.min_swap_amount = min_swap_amount;
+    
+    msg!("Minimum amounts set: deposit {}, withdraw {}, swap {} (0 = no minimum)",
+         min_deposit_amount, min_withdraw_amount, min_swap_amount);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetMinimumAmounts,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        fingerprint(&(min_deposit_amount, min_withdraw_amount, min_swap_amount)),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
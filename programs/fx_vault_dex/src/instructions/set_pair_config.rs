@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    ProtocolConfig, PairConfig, VaultAccount, DriftCurve, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED,
+    PAIR_CONFIG_SEED, VAULT_ACCOUNT_SEED, MAX_SPREAD_SLOPE_PPM, MAX_STABLESWAP_AMP, ADMIN_LOG_SEED
+};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetPairConfig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // The two vaults of the pair, ordered by mint
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_a.token_mint.as_ref()],
+        bump,
+        constraint = vault_a.token_mint < vault_b.token_mint @ ErrorCode::VaultsNotOrdered,
+    )]
+    pub vault_a: Account<'info, VaultAccount>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_b.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_b: Account<'info, VaultAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PairConfig::LEN,
+        seeds = [PAIR_CONFIG_SEED, vault_a.token_mint.as_ref(), vault_b.token_mint.as_ref()],
+        bump,
+    )]
+    pub pair_config: Account<'info, PairConfig>,
+    
+    pub system_program: Program<'info, System>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetPairConfig>,
+    spread_slope_ppm: u32,
+    drift_curve: Option<DriftCurve>,
+    stableswap_amp: u16,
+    max_execution_deviation_bps: u16,
+) -> Result<()> {
+    let old_value = fingerprint(&(
+        ctx.accounts.pair_config.spread_slope_ppm,
+        ctx.accounts.pair_config.drift_curve,
+        ctx.accounts.pair_config.stableswap_amp,
+        ctx.accounts.pair_config.max_execution_deviation_bps,
+    ));
+    
+    require!(spread_slope_ppm <= MAX_SPREAD_SLOPE_PPM, ErrorCode::SpreadSlopeTooHigh);
+    if let Some(curve) = drift_curve {
+        require!(curve.is_valid(), ErrorCode::InvalidDriftCurve);
+    }
+    require!(stableswap_amp <= MAX_STABLESWAP_AMP, ErrorCode::StableswapAmpTooHigh);
+    require!(max_execution_deviation_bps <= 10000, ErrorCode::InvalidExecutionDeviationCap);
+    
+    let pair_config = &mut ctx.accounts.pair_config;
+    pair_config.mint_a = ctx.accounts.vault_a.token_mint;
+    pair_config.mint_b = ctx.accounts.vault_b.token_mint;
+    pair_config.bump = *ctx.bumps.get("pair_config").unwrap();
+    
+    // Zero or None falls back to the global protocol config
+    pair_config.spread_slope_ppm = spread_slope_ppm;
+    pair_config.drift_curve = drift_curve;
+    
+    // Zero keeps swaps halted while an oracle is stale
+    pair_config.stableswap_amp = stableswap_amp;
+    
+    // Zero leaves execution bounded only by the trader's slippage limit
+    pair_config.max_execution_deviation_bps = max_execution_deviation_bps;
+    
+    msg!("Pair config set for {} / {}: spread slope {} ppm, drift override {}, fallback amp {}, execution cap {} bps",
+         pair_config.mint_a, pair_config.mint_b, spread_slope_ppm, drift_curve.is_some(), stableswap_amp, max_execution_deviation_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetPairConfig,
+        ctx.accounts.pair_config.key(),
+        old_value,
+        fingerprint(&(
+            ctx.accounts.pair_config.spread_slope_ppm,
+            ctx.accounts.pair_config.drift_curve,
+            ctx.accounts.pair_config.stableswap_amp,
+            ctx.accounts.pair_config.max_execution_deviation_bps,
+        )),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vault A's mint must sort before vault B's mint")]
+    VaultsNotOrdered,
+    
+    #[msg("Spread slope is too high, maximum is 5% per unit of health")]
+    SpreadSlopeTooHigh,
+    
+    #[msg("Drift curve must have kink below start and ordered, bounded slopes")]
+    InvalidDriftCurve,
+    
+    #[msg("Stableswap fallback amplification is too high")]
+    StableswapAmpTooHigh,
+    
+    #[msg("Execution deviation cap cannot exceed 10000 basis points")]
+    InvalidExecutionDeviationCap,
+} 
\ No newline at end of file
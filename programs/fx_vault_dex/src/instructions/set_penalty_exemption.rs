@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, LPPosition, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, LP_POSITION_SEED, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetPenaltyExemption<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, lp_position.vault.as_ref(), lp_position.owner.as_ref()],
+        bump = lp_position.bump,
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetPenaltyExemption>, exempt: bool) -> Result<()> {
+    let old_value = ctx.accounts.lp_position.penalty_exempt as u64;
+    
+    let lp_position = &mut ctx.accounts.lp_position;
+    lp_position.penalty_exempt = exempt;
+    
+    msg!("Withdrawal penalty exemption {} for {} in vault {}",
+         if exempt { "granted" } else { "revoked" }, lp_position.owner, lp_position.vault);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetPenaltyExemption,
+        ctx.accounts.lp_position.key(),
+        old_value,
+        ctx.accounts.lp_position.penalty_exempt as u64,
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
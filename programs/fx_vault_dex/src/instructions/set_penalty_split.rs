@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, PenaltySplit, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetPenaltySplit<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetPenaltySplit>,
+    penalty_split: PenaltySplit,
+    insurance_fund: Pubkey,
+) -> Result<()> {
+    let old_value = fingerprint(&(ctx.accounts.protocol_config.penalty_split, ctx.accounts.protocol_config.insurance_fund));
+    
+    require!(penalty_split.is_valid(), ErrorCode::InvalidPenaltySplit);
+    require!(
+        penalty_split.insurance_bps == 0 || insurance_fund != Pubkey::default(),
+        ErrorCode::MissingInsuranceFund
+    );
+    
+    let protocol_config = &mut ctx.accounts.protocol_config;
+    protocol_config.penalty_split = penalty_split;
+    protocol_config.insurance_fund = insurance_fund;
+    
+    msg!("Withdrawal penalty split set: {} bps to LPs, {} bps to insurance, {} bps to treasury",
+         penalty_split.lp_bps, penalty_split.insurance_bps, penalty_split.treasury_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetPenaltySplit,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&(ctx.accounts.protocol_config.penalty_split, ctx.accounts.protocol_config.insurance_fund)),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Penalty split must add up to 10000 basis points")]
+    InvalidPenaltySplit,
+    
+    #[msg("An insurance fund is required when it receives a share of penalties")]
+    MissingInsuranceFund,
+} 
\ No newline at end of file
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, LPPosition, VAULT_ACCOUNT_SEED, LP_POSITION_SEED};
+
+#[derive(Accounts)]
+pub struct SetPositionManager<'info> {
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), owner.key().as_ref()],
+        bump,
+        constraint = lp_position.owner == owner.key(),
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+}
+
+pub fn handler(ctx: Context<SetPositionManager>, manager: Pubkey) -> Result<()> {
+    let lp_position = &mut ctx.accounts.lp_position;
+    
+    // Pubkey::default() revokes the current manager
+    lp_position.manager = manager;
+    
+    if manager == Pubkey::default() {
+        msg!("Revoked LP position manager");
+    } else {
+        msg!("Set LP position manager to {}", manager);
+    }
+    
+    Ok(())
+} 
\ No newline at end of file
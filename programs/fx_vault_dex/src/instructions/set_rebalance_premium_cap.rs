@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, MAX_REBALANCE_PREMIUM_BPS, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetRebalancePremiumCap<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetRebalancePremiumCap>, max_rebalance_premium_bps: u16) -> Result<()> {
+    let old_value = ctx.accounts.protocol_config.max_rebalance_premium_bps as u64;
+    
+    require!(
+        max_rebalance_premium_bps <= MAX_REBALANCE_PREMIUM_BPS,
+        ErrorCode::PremiumCapTooHigh
+    );
+    
+    ctx.accounts.protocol_config.max_rebalance_premium_bps = max_rebalance_premium_bps;
+    
+    msg!("Rebalance premium cap set to {} bps", max_rebalance_premium_bps);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetRebalancePremiumCap,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        ctx.accounts.protocol_config.max_rebalance_premium_bps as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Rebalance premium cap is too high, maximum is 0.5%")]
+    PremiumCapTooHigh,
+} 
\ No newline at end of file
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, MAX_SPREAD_SLOPE_PPM, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetSpreadSlope<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetSpreadSlope>, spread_slope_ppm: u32) -> Result<()> {
+    let old_value = ctx.accounts.protocol_config.spread_slope_ppm as u64;
+    
+    require!(
+        spread_slope_ppm > 0 && spread_slope_ppm <= MAX_SPREAD_SLOPE_PPM,
+        ErrorCode::InvalidSpreadSlope
+    );
+    
+    ctx.accounts.protocol_config.spread_slope_ppm = spread_slope_ppm;
+    
+    msg!("Global spread slope set to {} ppm", spread_slope_ppm);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetSpreadSlope,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        ctx.accounts.protocol_config.spread_slope_ppm as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Spread slope must be positive and at most 5% per unit of health")]
+    InvalidSpreadSlope,
+} 
\ No newline at end of file
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetTransactionGuard<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetTransactionGuard>, tx_guard_enabled: bool) -> Result<()> {
+    let old_value = ctx.accounts.vault_account.tx_guard_enabled as u64;
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    vault_account.tx_guard_enabled = tx_guard_enabled;
+    
+    msg!("Transaction guard {}", if tx_guard_enabled { "enabled" } else { "disabled" });
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetTransactionGuard,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        ctx.accounts.vault_account.tx_guard_enabled as u64,
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
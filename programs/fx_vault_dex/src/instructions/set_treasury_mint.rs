@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::{ProtocolConfig, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetTreasuryMint<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Currency protocol fees will be consolidated into
+    pub treasury_mint: Account<'info, Mint>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetTreasuryMint>) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.treasury_mint);
+    
+    ctx.accounts.protocol_config.treasury_mint = ctx.accounts.treasury_mint.key();
+    
+    msg!("Treasury currency set to {}", ctx.accounts.treasury_mint.key());
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetTreasuryMint,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.treasury_mint),
+    )?;
+    
+    Ok(())
+} 
\ No newline at end of file
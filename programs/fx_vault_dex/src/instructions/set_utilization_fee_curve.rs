@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, ParameterCurve, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, MAX_UTILIZATION_FEE_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetUtilizationFeeCurve<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetUtilizationFeeCurve>, utilization_fee_curve: ParameterCurve) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.utilization_fee_curve);
+    
+    require!(utilization_fee_curve.is_valid(MAX_UTILIZATION_FEE_BPS), ErrorCode::InvalidCurve);
+    
+    msg!("Utilization fee curve set with {} breakpoints", utilization_fee_curve.points.len());
+    
+    ctx.accounts.protocol_config.utilization_fee_curve = utilization_fee_curve;
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetUtilizationFeeCurve,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.utilization_fee_curve),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Curve must have sorted breakpoints and a utilization fee of at most 2%")]
+    InvalidCurve,
+} 
\ No newline at end of file
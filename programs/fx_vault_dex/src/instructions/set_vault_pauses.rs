@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, AdminLog, AdminAction, Role, VAULT_ACCOUNT_SEED, ADMIN_LOG_SEED};
+use crate::events::VaultPausesChanged;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetVaultPauses<'info> {
+    #[account(
+        constraint = vault_account.has_role(Role::Pauser, &pauser.key()) @ ErrorCode::Unauthorized,
+    )]
+    pub pauser: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(
+    ctx: Context<SetVaultPauses>,
+    deposits_paused: bool,
+    withdrawals_paused: bool,
+    swaps_paused: bool,
+) -> Result<()> {
+    let old_value = ctx.accounts.vault_account.pause_flags();
+    
+    // Each operation is paused independently, e.g. swaps during an oracle incident while LPs can still exit
+    let vault_account = &mut ctx.accounts.vault_account;
+    vault_account.deposits_paused = deposits_paused;
+    vault_account.withdrawals_paused = withdrawals_paused;
+    vault_account.swaps_paused = swaps_paused;
+    
+    emit!(VaultPausesChanged {
+        vault: vault_account.key(),
+        pauser: ctx.accounts.pauser.key(),
+        deposits_paused,
+        withdrawals_paused,
+        swaps_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Vault pauses set: deposits {}, withdrawals {}, swaps {}",
+         deposits_paused, withdrawals_paused, swaps_paused);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.pauser.key(),
+        AdminAction::SetVaultPauses,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        ctx.accounts.vault_account.pause_flags(),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Signer does not hold the vault's pauser role")]
+    Unauthorized,
+} 
\ No newline at end of file
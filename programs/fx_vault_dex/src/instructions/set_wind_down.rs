@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, FeedRegistry, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, FEED_REGISTRY_SEED, ADMIN_LOG_SEED, MAX_DEPRECATED_VAULTS};
+use crate::events::VaultWoundDown;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SetWindDown<'info> {
+    #[account(
+        constraint = admin.key() == vault_account.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+        constraint = !vault_account.wound_down @ ErrorCode::AlreadyWoundDown,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // The vault is listed as deprecated here so integrators stop routing to it
+    #[account(
+        mut,
+        seeds = [FEED_REGISTRY_SEED],
+        bump = feed_registry.bump,
+    )]
+    pub feed_registry: Account<'info, FeedRegistry>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetWindDown>) -> Result<()> {
+    let old_value = ctx.accounts.vault_account.wound_down as u64;
+    
+    let vault_key = ctx.accounts.vault_account.key();
+    let feed_registry = &mut ctx.accounts.feed_registry;
+    
+    require!(feed_registry.deprecated_vaults.len() < MAX_DEPRECATED_VAULTS, ErrorCode::RegistryFull);
+    feed_registry.deprecated_vaults.push(vault_key);
+    
+    // Wind-down is permanent: there is no instruction to reverse it
+    let vault_account = &mut ctx.accounts.vault_account;
+    let current_time = Clock::get()?.unix_timestamp;
+    vault_account.wound_down = true;
+    vault_account.wind_down_time = current_time;
+    
+    emit!(VaultWoundDown {
+        vault: vault_key,
+        admin: ctx.accounts.admin.key(),
+        tvl: vault_account.tvl,
+        timestamp: current_time,
+    });
+    
+    msg!("Vault {} wound down; only withdrawals remain open", vault_key);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetWindDown,
+        vault_key,
+        old_value,
+        ctx.accounts.vault_account.wound_down as u64,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Vault is already wound down")]
+    AlreadyWoundDown,
+    
+    #[msg("Feed registry cannot list more deprecated vaults")]
+    RegistryFull,
+} 
\ No newline at end of file
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{ProtocolConfig, ParameterCurve, AdminLog, AdminAction, PROTOCOL_CONFIG_SEED, MAX_WITHDRAWAL_FEE_BPS, ADMIN_LOG_SEED};
+use crate::utils::{record_admin_action, fingerprint};
+
+#[derive(Accounts)]
+pub struct SetWithdrawalFeeCurve<'info> {
+    #[account(
+        constraint = admin.key() == protocol_config.admin,
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SetWithdrawalFeeCurve>, withdrawal_fee_curve: ParameterCurve) -> Result<()> {
+    let old_value = fingerprint(&ctx.accounts.protocol_config.withdrawal_fee_curve);
+    
+    require!(withdrawal_fee_curve.is_valid(MAX_WITHDRAWAL_FEE_BPS), ErrorCode::InvalidCurve);
+    
+    msg!("Withdrawal fee curve set with {} breakpoints", withdrawal_fee_curve.points.len());
+    
+    ctx.accounts.protocol_config.withdrawal_fee_curve = withdrawal_fee_curve;
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.admin.key(),
+        AdminAction::SetWithdrawalFeeCurve,
+        ctx.accounts.protocol_config.key(),
+        old_value,
+        fingerprint(&ctx.accounts.protocol_config.withdrawal_fee_curve),
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Curve must have sorted breakpoints and a withdrawal fee of at most 10%")]
+    InvalidCurve,
+} 
\ No newline at end of file
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{KeeperRegistry, KeeperBond, AdminLog, AdminAction, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED, ADMIN_LOG_SEED};
+use crate::events::KeeperSlashed;
+use crate::utils::record_admin_action;
+
+#[derive(Accounts)]
+pub struct SlashKeeper<'info> {
+    #[account(
+        constraint = authority.key() == keeper_registry.authority @ ErrorCode::Unauthorized,
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_BOND_SEED, keeper_bond.keeper.as_ref()],
+        bump = keeper_bond.bump,
+    )]
+    pub keeper_bond: Account<'info, KeeperBond>,
+    
+    #[account(
+        mut,
+        constraint = bond_vault.key() == keeper_registry.bond_vault,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    // Token account receiving the slashed tokens
+    #[account(
+        mut,
+        constraint = slash_recipient.mint == keeper_registry.bond_mint,
+    )]
+    pub slash_recipient: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+}
+
+pub fn handler(ctx: Context<SlashKeeper>, amount: u64) -> Result<()> {
+    let keeper_registry = &mut ctx.accounts.keeper_registry;
+    let keeper_bond = &mut ctx.accounts.keeper_bond;
+    let old_value = keeper_bond.total_slashed;
+    
+    // Take from the active bond first, then from tokens still cooling down
+    let from_bonded = amount.min(keeper_bond.bonded_amount);
+    let from_unbonding = (amount - from_bonded).min(keeper_bond.unbonding_amount);
+    let slashed_amount = from_bonded + from_unbonding;
+    require!(slashed_amount > 0, ErrorCode::NothingToSlash);
+    
+    keeper_bond.bonded_amount -= from_bonded;
+    keeper_bond.unbonding_amount -= from_unbonding;
+    keeper_bond.total_slashed = keeper_bond.total_slashed.checked_add(slashed_amount).ok_or(ErrorCode::MathOverflow)?;
+    keeper_registry.total_bonded = keeper_registry.total_bonded.saturating_sub(from_bonded);
+    keeper_registry.total_unbonding = keeper_registry.total_unbonding.saturating_sub(from_unbonding);
+    keeper_registry.total_slashed = keeper_registry.total_slashed.checked_add(slashed_amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    let seeds = &[
+        KEEPER_REGISTRY_SEED,
+        &[keeper_registry.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.bond_vault.to_account_info(),
+        to: ctx.accounts.slash_recipient.to_account_info(),
+        authority: keeper_registry.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, slashed_amount)?;
+    
+    emit!(KeeperSlashed {
+        keeper: keeper_bond.keeper,
+        amount: slashed_amount,
+        remaining_bond: keeper_bond.bonded_amount,
+        recipient: ctx.accounts.slash_recipient.key(),
+    });
+    
+    msg!("Slashed {} bonded tokens from keeper {}", slashed_amount, keeper_bond.keeper);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.authority.key(),
+        AdminAction::SlashKeeper,
+        ctx.accounts.keeper_bond.key(),
+        old_value,
+        ctx.accounts.keeper_bond.total_slashed,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Only the keeper registry authority can slash keepers")]
+    Unauthorized,
+    
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Keeper has no bonded tokens to slash")]
+    NothingToSlash,
+} 
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{VaultAccount, PriceHistory, PriceSnapshot, VAULT_ACCOUNT_SEED, PRICE_HISTORY_SEED};
+use crate::utils::{get_oracle_price, check_circuit_breaker};
+
+#[derive(Accounts)]
+pub struct SnapshotPrice<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = PriceHistory::LEN,
+        seeds = [PRICE_HISTORY_SEED, vault_account.key().as_ref()],
+        bump,
+    )]
+    pub price_history: Account<'info, PriceHistory>,
+    
+    /// CHECK: Validated against the vault's oracle and parsed as a Pyth price account
+    #[account(
+        constraint = oracle.key() == vault_account.oracle,
+    )]
+    pub oracle: AccountInfo<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SnapshotPrice>) -> Result<()> {
+    let price_history = &mut ctx.accounts.price_history;
+    
+    // Initialize the history on first use
+    if price_history.vault == Pubkey::default() {
+        price_history.vault = ctx.accounts.vault_account.key();
+        price_history.bump = *ctx.bumps.get("price_history").unwrap();
+    }
+    
+    let oracle_price = get_oracle_price(&ctx.accounts.oracle, Clock::get()?.unix_timestamp)?;
+    
+    // Only record a price once per publish
+    if let Some(latest) = price_history.latest() {
+        require!(oracle_price.publish_time > latest.timestamp, ErrorCode::DuplicateSnapshot);
+    }
+    
+    // Trip the circuit breaker on a large move from the reference swaps also check against,
+    // or resume it once the price is back within band
+    let vault_key = ctx.accounts.vault_account.key();
+    let current_slot = Clock::get()?.slot;
+    check_circuit_breaker(&mut ctx.accounts.vault_account, vault_key, oracle_price.price, current_slot);
+    
+    price_history.push(PriceSnapshot {
+        price: oracle_price.price,
+        conf: oracle_price.conf,
+        timestamp: oracle_price.publish_time,
+    });
+    
+    msg!("Recorded price snapshot {} (conf {}) published at {}",
+         oracle_price.price, oracle_price.conf, oracle_price.publish_time);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Oracle price has already been recorded")]
+    DuplicateSnapshot,
+} 
\ No newline at end of file
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    VaultAccount, VaultPair, MetricsHistory, MetricsSample, VAULT_ACCOUNT_SEED, VAULT_PAIR_SEED,
+    METRICS_HISTORY_SEED, METRICS_SAMPLE_INTERVAL
+};
+use crate::utils::calculate_vault_health;
+
+#[derive(Accounts)]
+pub struct SnapshotVaultMetrics<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    // Paired vault the health is measured against
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, counter_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub counter_vault: Account<'info, VaultAccount>,
+    
+    #[account(
+        seeds = [
+            VAULT_PAIR_SEED,
+            vault_account.token_mint.min(counter_vault.token_mint).as_ref(),
+            vault_account.token_mint.max(counter_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = MetricsHistory::LEN,
+        seeds = [METRICS_HISTORY_SEED, vault_account.key().as_ref()],
+        bump,
+    )]
+    pub metrics_history: Box<Account<'info, MetricsHistory>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SnapshotVaultMetrics>) -> Result<()> {
+    let metrics_history = &mut ctx.accounts.metrics_history;
+    
+    // Initialize the history on first use; health is always measured against the same vault
+    if metrics_history.vault == Pubkey::default() {
+        metrics_history.vault = ctx.accounts.vault_account.key();
+        metrics_history.counter_vault = ctx.accounts.counter_vault.key();
+        metrics_history.bump = *ctx.bumps.get("metrics_history").unwrap();
+    }
+    require_keys_eq!(
+        metrics_history.counter_vault,
+        ctx.accounts.counter_vault.key(),
+        ErrorCode::CounterVaultMismatch
+    );
+    
+    // At most one sample per interval
+    let current_time = Clock::get()?.unix_timestamp;
+    if let Some(latest) = metrics_history.latest() {
+        require!(
+            current_time >= latest.timestamp.saturating_add(METRICS_SAMPLE_INTERVAL),
+            ErrorCode::SampleTooSoon
+        );
+    }
+    
+    let vault_account = &ctx.accounts.vault_account;
+    let health = calculate_vault_health(vault_account.tvl, ctx.accounts.counter_vault.tvl);
+    let sample = MetricsSample {
+        timestamp: current_time,
+        tvl: vault_account.tvl,
+        health_bps: (health * 10000.0) as u16,
+        cumulative_fees: vault_account.cumulative_swap_fees,
+    };
+    metrics_history.push(sample);
+    
+    msg!("Recorded vault metrics: TVL {}, health {} bps, cumulative fees {}, trailing 7d fee APR {} bps",
+         sample.tvl, sample.health_bps, sample.cumulative_fees, metrics_history.trailing_7d_fee_apr_bps);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Metrics history measures health against a different vault")]
+    CounterVaultMismatch,
+    
+    #[msg("A metrics sample was already taken this interval")]
+    SampleTooSoon,
+} 
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use crate::state::{VaultAccount, LPPosition, VAULT_ACCOUNT_SEED, LP_POSITION_SEED};
+use crate::utils::{calculate_amount_for_shares, check_transaction_guard};
+
+#[derive(Accounts)]
+pub struct SplitLpPosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), owner.key().as_ref()],
+        bump,
+        constraint = lp_position.owner == owner.key(),
+        constraint = !lp_position.is_protocol @ ErrorCode::ProtocolPosition,
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+    
+    /// CHECK: Wallet that will own the split-off tranche
+    #[account(
+        constraint = recipient.key() != owner.key() @ ErrorCode::SameOwner,
+    )]
+    pub recipient: AccountInfo<'info>,
+    
+    // Recipient's position in the same vault, created if needed
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = LPPosition::LEN,
+        seeds = [LP_POSITION_SEED, vault_account.key().as_ref(), recipient.key().as_ref()],
+        bump,
+        constraint = !recipient_position.is_protocol @ ErrorCode::ProtocolPosition,
+    )]
+    pub recipient_position: Account<'info, LPPosition>,
+    
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<SplitLpPosition>, shares: u64) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.vault_account, &ctx.accounts.vault_account.key(), instructions_sysvar.as_ref())?;
+    
+    let lp_position = &mut ctx.accounts.lp_position;
+    let recipient_position = &mut ctx.accounts.recipient_position;
+    
+    require!(shares > 0, ErrorCode::InvalidAmount);
+    require!(lp_position.shares >= shares, ErrorCode::InsufficientFunds);
+    
+    // Split-off tranches open new positions, so they must clear the deposit dust limit
+    let vault_account = &ctx.accounts.vault_account;
+    let tranche_value = calculate_amount_for_shares(shares, vault_account.lp_assets(), vault_account.total_shares)?;
+    require!(
+        tranche_value >= vault_account.min_deposit_amount || shares == lp_position.shares,
+        ErrorCode::BelowMinimumAmount
+    );
+    
+    if recipient_position.owner == Pubkey::default() {
+        recipient_position.owner = ctx.accounts.recipient.key();
+        recipient_position.vault = ctx.accounts.vault_account.key();
+        recipient_position.bump = *ctx.bumps.get("recipient_position").unwrap();
+    }
+    
+    // The tranche keeps its deposit time, so its withdrawal penalty is unchanged
+    lp_position.shares = lp_position.shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
+    recipient_position
+        .absorb_tranche(shares, lp_position.last_deposit_time)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Split {} shares of LP position to {}", shares, ctx.accounts.recipient.key());
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Split must move at least one share")]
+    InvalidAmount,
+    
+    #[msg("Insufficient shares in LP position")]
+    InsufficientFunds,
+    
+    #[msg("Recipient must differ from the position owner")]
+    SameOwner,
+    
+    #[msg("Protocol-owned positions cannot be split or merged")]
+    ProtocolPosition,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+} 
\ No newline at end of file
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{StakingPool, StakePosition, STAKING_POOL_SEED, STAKE_POSITION_SEED};
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakePosition::LEN,
+        seeds = [STAKE_POSITION_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        constraint = user_stake_token.mint == staking_pool.stake_mint,
+        constraint = user_stake_token.owner == user.key(),
+    )]
+    pub user_stake_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = stake_vault.key() == staking_pool.stake_vault,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Stake>, amount: u64) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    
+    // Initialize the position on first stake
+    if stake_position.owner == Pubkey::default() {
+        stake_position.owner = ctx.accounts.user.key();
+        stake_position.staking_pool = staking_pool.key();
+        stake_position.bump = *ctx.bumps.get("stake_position").unwrap();
+        stake_position.reward_index_checkpoint = staking_pool.reward_index;
+        stake_position.slash_factor_checkpoint = staking_pool.slash_factor;
+    }
+    
+    // Settle rewards earned on the existing stake before it changes
+    stake_position.settle(staking_pool.reward_index, staking_pool.slash_factor)?;
+    
+    // Transfer stake tokens from user to the stake vault
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.user_stake_token.to_account_info(),
+        to: ctx.accounts.stake_vault.to_account_info(),
+        authority: ctx.accounts.user.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    stake_position.amount = stake_position.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    staking_pool.total_staked = staking_pool.total_staked.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Staked {} tokens", amount);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Stake amount must be greater than zero")]
+    InvalidAmount,
+} 
\ No newline at end of file
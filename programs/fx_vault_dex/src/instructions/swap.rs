@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, LP_FEE_PERCENT};
-use crate::utils::{calculate_amount_out, calculate_spread, calculate_drift, calculate_fee_allocation};
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{Token, TokenAccount};
+use crate::state::{VaultAccount, ProtocolConfig, PairConfig, PairState, VaultPair, UserStats, EmergencyState, KeeperHeartbeat, VAULT_ACCOUNT_SEED, PROTOCOL_CONFIG_SEED, PAIR_CONFIG_SEED, PAIR_STATE_SEED, VAULT_PAIR_SEED, USER_STATS_SEED, VAULT_AUTHORITY_SEED, EMERGENCY_STATE_SEED, KEEPER_HEARTBEAT_SEED};
+use crate::utils::{
+    trip_pair_circuit_breakers, price_pair_swap, settle_pair_swap, PairPricingAccounts, PairSettlementAccounts, PairSwapFees,
+    check_transaction_guard
+};
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
+    // Account order is frozen for integrators (see interface.rs); append new accounts at the end
     #[account(mut)]
     pub user: Signer<'info>,
     
@@ -24,6 +29,72 @@ pub struct Swap<'info> {
     )]
     pub target_vault: Account<'info, VaultAccount>,
     
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    /// CHECK: Per-pair pricing overrides, may be uninitialized when the pair uses the global parameters
+    #[account(
+        seeds = [
+            PAIR_CONFIG_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_config: AccountInfo<'info>,
+    
+    // Shared price record for the pair, updated by swaps in either direction
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PairState::LEN,
+        seeds = [
+            PAIR_STATE_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_state: Box<Account<'info, PairState>>,
+    
+    // Admin-registered pair; swaps between vaults that were never paired are rejected
+    #[account(
+        mut,
+        seeds = [
+            VAULT_PAIR_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    // Opt-in trader statistics; omitted by traders who do not want them
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStats::LEN,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_stats: Option<Box<Account<'info, UserStats>>>,
+    
+    /// CHECK: Bound to the source vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = source_oracle.key() == source_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub source_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the target vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = target_oracle.key() == target_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub target_oracle: AccountInfo<'info>,
+    
     /// CHECK: This is the source vault authority PDA
     #[account(
         seeds = [VAULT_AUTHORITY_SEED, target_vault.key().as_ref()],
@@ -59,120 +130,134 @@ pub struct Swap<'info> {
     )]
     pub target_vault_token: Account<'info, TokenAccount>,
     
+    // Global kill switch; swaps and deposits are rejected while it is on
+    #[account(
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+        constraint = !emergency_state.halted @ ErrorCode::EmergencyHalt,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    
+    // Keeper liveness record; leaving it out counts as no heartbeat while monitoring is on
+    #[account(
+        seeds = [KEEPER_HEARTBEAT_SEED],
+        bump = keeper_heartbeat.bump,
+    )]
+    pub keeper_heartbeat: Option<Account<'info, KeeperHeartbeat>>,
 }
 
 pub fn handler(
     ctx: Context<Swap>,
     amount_in: u64,
     minimum_amount_out: u64,
-    oracle_price: u64, // Added parameter for oracle price from API
 ) -> Result<()> {
-    let source_vault = &mut ctx.accounts.source_vault;
-    let target_vault = &mut ctx.accounts.target_vault;
+    let source_vault_key = ctx.accounts.source_vault.key();
+    let target_vault_key = ctx.accounts.target_vault.key();
+    
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.source_vault, &source_vault_key, instructions_sysvar.as_ref())?;
+    check_transaction_guard(&ctx.accounts.target_vault, &target_vault_key, instructions_sysvar.as_ref())?;
     
-    // Get the FX rate from the provided oracle price parameter
-    // Note: ensure the price is already scaled to 10^9 when passed from API
+    // Dust swaps bloat state and skew fee accounting
+    require!(amount_in >= ctx.accounts.source_vault.min_swap_amount, ErrorCode::BelowMinimumAmount);
+    require!(!ctx.accounts.source_vault.swaps_paused && !ctx.accounts.target_vault.swaps_paused, ErrorCode::SwapsPaused);
     
-    // Calculate the spread based on vault health (imbalance)
-    let source_amount = source_vault.tvl;
-    let target_amount = target_vault.tvl;
-    let spread_bps = calculate_spread(source_amount, target_amount);
+    // A wound-down vault only pays out; swapping into it would add new liquidity
+    require!(!ctx.accounts.source_vault.wound_down, ErrorCode::VaultWoundDown);
     
-    // Calculate the drift based on vault health (imbalance)
-    let drift_percentage = calculate_drift(source_amount, target_amount);
+    // A breaker trip or resume must persist, so a halted pair ends the swap without moving funds
+    if !trip_pair_circuit_breakers(
+        &mut ctx.accounts.source_vault,
+        source_vault_key,
+        &mut ctx.accounts.target_vault,
+        target_vault_key,
+        &ctx.accounts.source_oracle,
+        &ctx.accounts.target_oracle,
+    )? {
+        msg!("Swap not executed: circuit breaker active");
+        return Ok(());
+    }
     
-    // Calculate the amount out and fees
-    let (amount_out, fee_amount) = calculate_amount_out(
+    // Price the swap from the vaults' oracles rather than a caller-supplied rate
+    let pair_config = PairConfig::load(&ctx.accounts.pair_config)?;
+    let quote = price_pair_swap(
+        PairPricingAccounts {
+            source_vault: &mut ctx.accounts.source_vault,
+            target_vault: &mut ctx.accounts.target_vault,
+            source_oracle: &ctx.accounts.source_oracle,
+            target_oracle: &ctx.accounts.target_oracle,
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_config: pair_config.as_ref(),
+            pair_state: &mut ctx.accounts.pair_state,
+            vault_pair: &ctx.accounts.vault_pair,
+            keeper_heartbeat: ctx.accounts.keeper_heartbeat.as_deref(),
+        },
         amount_in,
-        oracle_price,
-        spread_bps,
-        drift_percentage,
-        true, // source to target direction
     )?;
     
     // Ensure the amount out meets the user's minimum
-    require!(amount_out >= minimum_amount_out, ErrorCode::SlippageExceeded);
-    
-    // Ensure the target vault has enough funds
-    require!(target_vault.tvl >= amount_out, ErrorCode::InsufficientLiquidity);
-    
-    // 1. Transfer tokens from user to source vault
-    let transfer_in_accounts = Transfer {
-        from: ctx.accounts.user_source_token.to_account_info(),
-        to: ctx.accounts.source_vault_token.to_account_info(),
-        authority: ctx.accounts.user.to_account_info(),
-    };
-    
-    let cpi_ctx_in = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_in_accounts,
-    );
-    
-    token::transfer(cpi_ctx_in, amount_in)?;
-    
-    // 2. Transfer tokens from target vault to user
-    let bump = target_vault.nonce;
-    let target_vault_key = target_vault.key();
-    let seeds = &[
-        VAULT_AUTHORITY_SEED,
-        target_vault_key.as_ref(),
-        &[bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
-    
-    let transfer_out_accounts = Transfer {
-        from: ctx.accounts.target_vault_token.to_account_info(),
-        to: ctx.accounts.user_target_token.to_account_info(),
-        authority: ctx.accounts.target_vault_authority.to_account_info(),
-    };
-    
-    let cpi_ctx_out = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        transfer_out_accounts,
-        signer_seeds,
-    );
-    
-    token::transfer(cpi_ctx_out, amount_out)?;
-    
-    // 3. Calculate and distribute fees
-    // Get fee allocation percentages based on vault health
-    let (pda_percent, protocol_percent) = calculate_fee_allocation(source_amount, target_amount);
-    
-    // Calculate fee amounts (the sum is always 30% of total fee)
-    let lp_fee_amount = fee_amount.checked_mul(LP_FEE_PERCENT as u64).ok_or(ErrorCode::MathOverflow)?.checked_div(100).ok_or(ErrorCode::MathOverflow)?;
-    let pda_fee_amount = fee_amount.checked_mul(pda_percent as u64).ok_or(ErrorCode::MathOverflow)?.checked_div(100).ok_or(ErrorCode::MathOverflow)?;
-    let protocol_fee_amount = fee_amount.checked_mul(protocol_percent as u64).ok_or(ErrorCode::MathOverflow)?.checked_div(100).ok_or(ErrorCode::MathOverflow)?;
-    
-    // Update the source vault's TVL
-    source_vault.tvl = source_vault.tvl.checked_add(amount_in).ok_or(ErrorCode::MathOverflow)?;
-    
-    // Update the target vault's TVL and record accrued fees
-    target_vault.tvl = target_vault.tvl.checked_sub(amount_out).ok_or(ErrorCode::MathOverflow)?;
-    target_vault.accrued_lp_fees = target_vault.accrued_lp_fees.checked_add(lp_fee_amount).ok_or(ErrorCode::MathOverflow)?;
-    target_vault.accrued_pda_fees = target_vault.accrued_pda_fees.checked_add(pda_fee_amount).ok_or(ErrorCode::MathOverflow)?;
-    target_vault.accrued_protocol_fees = target_vault.accrued_protocol_fees.checked_add(protocol_fee_amount).ok_or(ErrorCode::MathOverflow)?;
-    target_vault.last_fee_update = Clock::get()?.unix_timestamp;
-    
-    // Update oracle price data
-    source_vault.last_oracle_price = oracle_price;
-    source_vault.last_update_timestamp = Clock::get()?.unix_timestamp;
+    require!(quote.amount_out >= minimum_amount_out, ErrorCode::SlippageExceeded);
+    
+    let PairSwapFees {
+        lp_fee_amount,
+        pda_fee_amount,
+        protocol_fee_amount,
+    } = settle_pair_swap(
+        PairSettlementAccounts {
+            user: ctx.accounts.user.to_account_info(),
+            source_vault: &mut ctx.accounts.source_vault,
+            source_vault_key,
+            target_vault: &mut ctx.accounts.target_vault,
+            target_vault_key,
+            user_source_token: Some(ctx.accounts.user_source_token.to_account_info()),
+            source_vault_token: ctx.accounts.source_vault_token.to_account_info(),
+            target_vault_token: ctx.accounts.target_vault_token.to_account_info(),
+            user_target_token: ctx.accounts.user_target_token.to_account_info(),
+            target_vault_authority: ctx.accounts.target_vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_state: &mut ctx.accounts.pair_state,
+            pair_state_bump: *ctx.bumps.get("pair_state").unwrap(),
+            vault_pair: &mut ctx.accounts.vault_pair,
+            user_stats: ctx.accounts.user_stats.as_deref_mut().map(|user_stats| &mut **user_stats),
+            user_stats_bump: ctx.bumps.get("user_stats").copied().unwrap_or_default(),
+        },
+        &quote,
+        amount_in,
+        quote.amount_out,
+    )?;
     
     msg!("Swapped {} source tokens for {} target tokens with {} fee (LP: {}, PDA: {}, Protocol: {})", 
-         amount_in, amount_out, fee_amount, lp_fee_amount, pda_fee_amount, protocol_fee_amount);
+         amount_in, quote.amount_out, quote.fee_amount, lp_fee_amount, pda_fee_amount, protocol_fee_amount);
     
     Ok(())
 }
 
 #[error_code]
 pub enum ErrorCode {
-    #[msg("Math operation resulted in overflow")]
-    MathOverflow,
-    
-    #[msg("Insufficient liquidity in target vault")]
-    InsufficientLiquidity,
-    
     #[msg("Slippage tolerance exceeded")]
     SlippageExceeded,
+    
+    #[msg("Oracle account does not match the vault's feed")]
+    InvalidOracle,
+    
+    #[msg("Protocol is halted by the emergency switch; only withdrawals are open")]
+    EmergencyHalt,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Swaps through this vault are paused")]
+    SwapsPaused,
+    
+    #[msg("Vault is wound down and only accepts withdrawals")]
+    VaultWoundDown,
 } 
\ No newline at end of file
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+use crate::events::SwapOutputSplit;
+use crate::instructions::Swap;
+use crate::state::MAX_SPLIT_RECIPIENTS;
+
+/// Swaps once with the same accounts as `swap`, then pays the output out to the recipient
+/// token accounts passed through remaining_accounts, in proportion to split_bps
+/// Rounding dust goes to the last recipient
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    split_bps: Vec<u16>,
+) -> Result<()> {
+    let recipients = ctx.remaining_accounts.to_vec();
+    require!(
+        !split_bps.is_empty() && split_bps.len() <= MAX_SPLIT_RECIPIENTS && split_bps.len() == recipients.len(),
+        ErrorCode::InvalidRecipients
+    );
+    require!(split_bps.iter().map(|bps| *bps as u32).sum::<u32>() == 10000, ErrorCode::InvalidSplit);
+    
+    let user = ctx.accounts.user.to_account_info();
+    let user_target_token = ctx.accounts.user_target_token.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let target_vault = ctx.accounts.target_vault.key();
+    let target_mint = ctx.accounts.target_vault.token_mint;
+    let balance_before = ctx.accounts.user_target_token.amount;
+    
+    // The swap pays out into the user's target token account first
+    crate::instructions::swap::handler(ctx, amount_in, minimum_amount_out)?;
+    
+    let balance_after = Account::<TokenAccount>::try_from(&user_target_token)?.amount;
+    let amount_out = balance_after.checked_sub(balance_before).ok_or(ErrorCode::MathOverflow)?;
+    
+    let mut remaining = amount_out;
+    for (index, (recipient, bps)) in recipients.iter().zip(split_bps.iter()).enumerate() {
+        let recipient_token = Account::<TokenAccount>::try_from(recipient)?;
+        require!(recipient_token.mint == target_mint, ErrorCode::InvalidRecipients);
+        
+        let share = if index == recipients.len() - 1 {
+            remaining
+        } else {
+            (amount_out as u128 * *bps as u128 / 10000) as u64
+        };
+        remaining -= share;
+        
+        if share == 0 {
+            continue;
+        }
+        
+        let transfer_accounts = Transfer {
+            from: user_target_token.clone(),
+            to: recipient.clone(),
+            authority: user.clone(),
+        };
+        
+        token::transfer(CpiContext::new(token_program.clone(), transfer_accounts), share)?;
+    }
+    
+    emit!(SwapOutputSplit {
+        user: user.key(),
+        target_vault,
+        amount_out,
+        recipient_count: recipients.len() as u8,
+    });
+    
+    msg!("Split {} output tokens across {} recipients", amount_out, recipients.len());
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Recipients must be 1 to 10 token accounts of the target currency, one per split")]
+    InvalidRecipients,
+    
+    #[msg("Split proportions must add up to 10000 basis points")]
+    InvalidSplit,
+} 
\ No newline at end of file
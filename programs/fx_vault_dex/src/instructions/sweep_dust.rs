@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::{VaultAccount, VAULT_ACCOUNT_SEED};
+use crate::events::DustSwept;
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    pub cranker: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    #[account(
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(ctx: Context<SweepDust>) -> Result<()> {
+    let vault_key = ctx.accounts.vault_account.key();
+    let vault_account = &mut ctx.accounts.vault_account;
+    
+    let residual = ctx.accounts.vault_token_account.amount.saturating_sub(vault_account.tracked_balance());
+    require!(residual > 0, ErrorCode::NoDustToSweep);
+    
+    // Larger surpluses are not rounding residue
+    require!(residual <= vault_account.dust_limit(), ErrorCode::SurplusTooLarge);
+    
+    // Residue goes to protocol fees, never to LP assets: anyone can donate to the token
+    // account and sweep it, so crediting LPs would let a donor move the exchange rate
+    vault_account.accrued_protocol_fees = vault_account.accrued_protocol_fees.checked_add(residual).ok_or(ErrorCode::MathOverflow)?;
+    
+    emit!(DustSwept {
+        vault: vault_key,
+        amount: residual,
+    });
+    
+    msg!("Swept {} tokens of untracked dust into protocol fees", residual);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Vault token balance matches its accounting")]
+    NoDustToSweep,
+    
+    #[msg("Untracked balance exceeds the dust limit")]
+    SurplusTooLarge,
+} 
\ No newline at end of file
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::{KeeperRegistry, KeeperBond, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED};
+
+#[derive(Accounts)]
+pub struct UnbondKeeper<'info> {
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_BOND_SEED, keeper.key().as_ref()],
+        bump = keeper_bond.bump,
+        constraint = keeper_bond.keeper == keeper.key(),
+    )]
+    pub keeper_bond: Account<'info, KeeperBond>,
+}
+
+pub fn handler(ctx: Context<UnbondKeeper>, amount: u64) -> Result<()> {
+    let keeper_registry = &mut ctx.accounts.keeper_registry;
+    let keeper_bond = &mut ctx.accounts.keeper_bond;
+    
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    require!(keeper_bond.bonded_amount >= amount, ErrorCode::InsufficientBond);
+    
+    // Move the tokens into the cooldown, where they stay slashable; a new request restarts it
+    keeper_bond.bonded_amount -= amount;
+    keeper_bond.unbonding_amount = keeper_bond.unbonding_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    keeper_bond.unbond_request_time = Clock::get()?.unix_timestamp;
+    
+    keeper_registry.total_bonded = keeper_registry.total_bonded.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+    keeper_registry.total_unbonding = keeper_registry.total_unbonding.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Keeper {} requested unbond of {} tokens, withdrawable after {}s cooldown",
+         keeper_bond.keeper, amount, keeper_registry.unbond_cooldown);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Unbond amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Insufficient bonded tokens")]
+    InsufficientBond,
+} 
\ No newline at end of file
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::{StakingPool, StakePosition, STAKING_POOL_SEED, STAKE_POSITION_SEED};
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == user.key(),
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+}
+
+pub fn handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    
+    require!(amount > 0, ErrorCode::InvalidAmount);
+    
+    // Settle slashes and rewards before the stake stops earning
+    stake_position.settle(staking_pool.reward_index, staking_pool.slash_factor)?;
+    require!(stake_position.amount >= amount, ErrorCode::InsufficientStake);
+    
+    // Move the tokens into the cooldown; a new request restarts the cooldown
+    stake_position.amount = stake_position.amount.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+    stake_position.unstaking_amount = stake_position.unstaking_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    stake_position.unstake_request_time = Clock::get()?.unix_timestamp;
+    
+    staking_pool.total_staked = staking_pool.total_staked.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+    staking_pool.total_unstaking = staking_pool.total_unstaking.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Requested unstake of {} tokens, withdrawable after {}s cooldown",
+         amount, staking_pool.unstake_cooldown);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Unstake amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Insufficient staked tokens")]
+    InsufficientStake,
+} 
\ No newline at end of file
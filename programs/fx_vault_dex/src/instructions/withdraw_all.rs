@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::instructions::{WithdrawLiquidity, withdraw_shares};
+
+/// Exits an LP position in one instruction, using the same accounts as `withdraw_liquidity`
+/// Retained LP fees are paid out with the shares, so there is nothing left to claim
+pub fn handler(ctx: Context<WithdrawLiquidity>, close_position: bool) -> Result<()> {
+    let shares = ctx.accounts.lp_position.shares;
+    require!(shares > 0 || close_position, ErrorCode::NothingToWithdraw);
+    
+    let withdraw_amount = if shares > 0 {
+        withdraw_shares(ctx.accounts, shares)?
+    } else {
+        0
+    };
+    
+    // Return the position's rent to the owner once it is empty
+    if close_position {
+        ctx.accounts.lp_position.close(ctx.accounts.user.to_account_info())?;
+    }
+    
+    msg!("Withdrew all {} shares for {} tokens{}",
+         shares, withdraw_amount, if close_position { " and closed the position" } else { "" });
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Position holds no shares")]
+    NothingToWithdraw,
+} 
\ No newline at end of file
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{KeeperRegistry, KeeperBond, KEEPER_REGISTRY_SEED, KEEPER_BOND_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawKeeperBond<'info> {
+    pub keeper: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_REGISTRY_SEED],
+        bump = keeper_registry.bump,
+    )]
+    pub keeper_registry: Account<'info, KeeperRegistry>,
+    
+    #[account(
+        mut,
+        seeds = [KEEPER_BOND_SEED, keeper.key().as_ref()],
+        bump = keeper_bond.bump,
+        constraint = keeper_bond.keeper == keeper.key(),
+    )]
+    pub keeper_bond: Account<'info, KeeperBond>,
+    
+    #[account(
+        mut,
+        constraint = keeper_token.mint == keeper_registry.bond_mint,
+        constraint = keeper_token.owner == keeper.key(),
+    )]
+    pub keeper_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = bond_vault.key() == keeper_registry.bond_vault,
+    )]
+    pub bond_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawKeeperBond>) -> Result<()> {
+    let keeper_registry = &mut ctx.accounts.keeper_registry;
+    let keeper_bond = &mut ctx.accounts.keeper_bond;
+    
+    let amount = keeper_bond.unbonding_amount;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    
+    // Ensure the cooldown has elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time - keeper_bond.unbond_request_time >= keeper_registry.unbond_cooldown,
+        ErrorCode::CooldownActive
+    );
+    
+    // Transfer the unbonded tokens from the bond vault back to the keeper
+    let seeds = &[
+        KEEPER_REGISTRY_SEED,
+        &[keeper_registry.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.bond_vault.to_account_info(),
+        to: ctx.accounts.keeper_token.to_account_info(),
+        authority: keeper_registry.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    keeper_bond.unbonding_amount = 0;
+    keeper_registry.total_unbonding = keeper_registry.total_unbonding.saturating_sub(amount);
+    
+    msg!("Keeper {} withdrew {} unbonded tokens", keeper_bond.keeper, amount);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No unbonded tokens to withdraw")]
+    NothingToWithdraw,
+    
+    #[msg("Unbond cooldown has not elapsed")]
+    CooldownActive,
+} 
\ No newline at end of file
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::{
-    VaultAccount, LPPosition, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, VAULT_AUTHORITY_SEED,
-    WITHDRAWAL_FEE_TIER_1, WITHDRAWAL_FEE_TIER_2, WITHDRAWAL_FEE_TIER_3, WITHDRAWAL_FEE_TIER_4, WITHDRAWAL_FEE_TIER_5,
-    HOURS_60_IN_SECONDS, HOURS_120_IN_SECONDS, HOURS_180_IN_SECONDS, HOURS_240_IN_SECONDS
+    VaultAccount, LPPosition, ProtocolConfig, VAULT_ACCOUNT_SEED, LP_POSITION_SEED, VAULT_AUTHORITY_SEED,
+    PROTOCOL_CONFIG_SEED
 };
+use crate::events::PenaltyExemptionUsed;
+use crate::utils::{calculate_launch_decay, interpolate_curve, check_transaction_guard};
 
 #[derive(Accounts)]
 pub struct WithdrawLiquidity<'info> {
+    // Account order is frozen for integrators (see interface.rs); append new accounts at the end
     #[account(mut)]
     pub user: Signer<'info>,
     
@@ -18,6 +21,13 @@ pub struct WithdrawLiquidity<'info> {
     )]
     pub vault_account: Account<'info, VaultAccount>,
     
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
     /// CHECK: This is the vault authority PDA
     #[account(
         seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
@@ -31,6 +41,7 @@ pub struct WithdrawLiquidity<'info> {
         bump,
         constraint = lp_position.owner == user.key(),
         constraint = lp_position.vault == vault_account.key(),
+        constraint = !lp_position.is_protocol @ ErrorCode::ProtocolPosition,
     )]
     pub lp_position: Account<'info, LPPosition>,
     
@@ -60,35 +71,56 @@ pub struct WithdrawLiquidity<'info> {
     )]
     pub pda_treasury_token: Account<'info, TokenAccount>,
     
+    // Insurance fund token account, required when the penalty split has an insurance share
+    #[account(mut)]
+    pub insurance_fund_token: Option<Account<'info, TokenAccount>>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<WithdrawLiquidity>, amount: u64) -> Result<()> {
-    let vault_account = &mut ctx.accounts.vault_account;
-    let lp_position = &mut ctx.accounts.lp_position;
+pub fn handler(ctx: Context<WithdrawLiquidity>, shares: u64) -> Result<()> {
+    withdraw_shares(ctx.accounts, shares)?;
+    Ok(())
+}
+
+/// Redeems shares from the position and routes the withdrawal penalty
+/// Returns the tokens paid out to the user after the penalty
+pub fn withdraw_shares(accounts: &mut WithdrawLiquidity, shares: u64) -> Result<u64> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&accounts.vault_account, &accounts.vault_account.key(), instructions_sysvar.as_ref())?;
+    
+    let vault_account = &mut accounts.vault_account;
+    let lp_position = &mut accounts.lp_position;
+    
+    require!(!vault_account.withdrawals_paused, ErrorCode::WithdrawalsPaused);
     
-    // Ensure the user has enough liquidity
-    require!(lp_position.amount >= amount, ErrorCode::InsufficientFunds);
+    // Ensure the user has enough shares
+    require!(lp_position.shares >= shares, ErrorCode::InsufficientFunds);
     
-    // Ensure the vault has enough funds
-    require!(vault_account.tvl >= amount, ErrorCode::InsufficientVaultFunds);
+    // Burn the shares at the current exchange rate; the vault must cover the payout
+    let amount = vault_account.redeem_shares(shares).map_err(|_| ErrorCode::InsufficientVaultFunds)?;
+    
+    // Partial withdrawals must clear the dust limit; closing out a position always can
+    require!(
+        amount >= vault_account.min_withdraw_amount || shares == lp_position.shares,
+        ErrorCode::BelowMinimumAmount
+    );
     
     // Calculate withdrawal penalty based on time since deposit
     let current_time = Clock::get()?.unix_timestamp;
-    let time_since_deposit = current_time - lp_position.last_deposit_time;
-    
-    let withdrawal_fee_bps = if time_since_deposit < HOURS_60_IN_SECONDS {
-        WITHDRAWAL_FEE_TIER_1
-    } else if time_since_deposit < HOURS_120_IN_SECONDS {
-        WITHDRAWAL_FEE_TIER_2
-    } else if time_since_deposit < HOURS_180_IN_SECONDS {
-        WITHDRAWAL_FEE_TIER_3
-    } else if time_since_deposit < HOURS_240_IN_SECONDS {
-        WITHDRAWAL_FEE_TIER_4
-    } else {
-        WITHDRAWAL_FEE_TIER_5
-    };
+    let withdrawal_fee_bps = calculate_withdrawal_fee_bps(
+        vault_account,
+        lp_position,
+        &accounts.protocol_config,
+        current_time,
+    );
+    report_penalty_exemption(vault_account, lp_position, &accounts.protocol_config, amount, current_time);
     
     // Calculate the penalty amount and amount to withdraw
     let penalty_amount = if withdrawal_fee_bps > 0 {
@@ -114,48 +146,148 @@ pub fn handler(ctx: Context<WithdrawLiquidity>, amount: u64) -> Result<()> {
     let signer_seeds = &[&seeds[..]];
     
     let transfer_cpi_accounts = Transfer {
-        from: ctx.accounts.vault_token_account.to_account_info(),
-        to: ctx.accounts.user_token_account.to_account_info(),
-        authority: ctx.accounts.vault_authority.to_account_info(),
+        from: accounts.vault_token_account.to_account_info(),
+        to: accounts.user_token_account.to_account_info(),
+        authority: accounts.vault_authority.to_account_info(),
     };
     
     let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        accounts.token_program.to_account_info(),
         transfer_cpi_accounts,
         signer_seeds,
     );
     
     token::transfer(cpi_ctx, withdraw_amount)?;
     
-    // If there's a penalty, transfer it to the PDA treasury
+    // Share the penalty out between the remaining LPs, the insurance fund and the PDA treasury
     if penalty_amount > 0 {
-        let penalty_transfer_accounts = Transfer {
-            from: ctx.accounts.vault_token_account.to_account_info(),
-            to: ctx.accounts.pda_treasury_token.to_account_info(),
-            authority: ctx.accounts.vault_authority.to_account_info(),
-        };
+        let (mut lp_amount, insurance_amount, mut treasury_amount) =
+            accounts.protocol_config.penalty_split.split(penalty_amount);
+        
+        // With no shares left there are no LPs to credit
+        if vault_account.total_shares == 0 {
+            treasury_amount += lp_amount;
+            lp_amount = 0;
+        }
         
-        let penalty_cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            penalty_transfer_accounts,
-            signer_seeds,
-        );
+        // The LP share stays in the vault as retained fees, raising the share price
+        vault_account.accrued_lp_fees = vault_account.accrued_lp_fees.checked_add(lp_amount).ok_or(ErrorCode::MathOverflow)?;
         
-        token::transfer(penalty_cpi_ctx, penalty_amount)?;
+        if insurance_amount > 0 {
+            let insurance_fund_token = accounts.insurance_fund_token.as_ref().ok_or(ErrorCode::InvalidInsuranceFund)?;
+            require!(
+                insurance_fund_token.mint == vault_account.token_mint
+                    && insurance_fund_token.owner == accounts.protocol_config.insurance_fund,
+                ErrorCode::InvalidInsuranceFund
+            );
+            
+            let insurance_transfer_accounts = Transfer {
+                from: accounts.vault_token_account.to_account_info(),
+                to: insurance_fund_token.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            };
+            
+            let insurance_cpi_ctx = CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                insurance_transfer_accounts,
+                signer_seeds,
+            );
+            
+            token::transfer(insurance_cpi_ctx, insurance_amount)?;
+        }
         
-        msg!("Applied withdrawal penalty of {} tokens ({}%)", 
-             penalty_amount, withdrawal_fee_bps as f64 / 100.0);
+        if treasury_amount > 0 {
+            let penalty_transfer_accounts = Transfer {
+                from: accounts.vault_token_account.to_account_info(),
+                to: accounts.pda_treasury_token.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            };
+            
+            let penalty_cpi_ctx = CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                penalty_transfer_accounts,
+                signer_seeds,
+            );
+            
+            token::transfer(penalty_cpi_ctx, treasury_amount)?;
+        }
+        
+        msg!("Applied withdrawal penalty of {} tokens ({}%): {} to LPs, {} to insurance, {} to treasury", 
+             penalty_amount, withdrawal_fee_bps as f64 / 100.0, lp_amount, insurance_amount, treasury_amount);
     }
     
-    // Update the vault's total value locked
-    vault_account.tvl = vault_account.tvl.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
-    
     // Update the LP's position
-    lp_position.amount = lp_position.amount.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+    lp_position.shares = lp_position.shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
     
-    msg!("Withdrew {} tokens from vault (after penalty: {})", amount, withdraw_amount);
+    msg!("Redeemed {} shares for {} tokens (after penalty: {})", shares, amount, withdraw_amount);
     
-    Ok(())
+    Ok(withdraw_amount)
+}
+
+/// Withdrawal penalty in basis points for a position at the given time
+/// Protocol-owned liquidity, allowlisted positions and wound-down vaults are never penalized
+pub fn calculate_withdrawal_fee_bps(
+    vault_account: &VaultAccount,
+    lp_position: &LPPosition,
+    protocol_config: &ProtocolConfig,
+    current_time: i64,
+) -> u16 {
+    if lp_position.is_penalty_exempt() || vault_account.wound_down {
+        return 0;
+    }
+    
+    scheduled_withdrawal_fee_bps(vault_account, lp_position, protocol_config, current_time)
+}
+
+/// Emits an event when an exempt position withdraws within the penalty schedule
+pub fn report_penalty_exemption(
+    vault_account: &VaultAccount,
+    lp_position: &LPPosition,
+    protocol_config: &ProtocolConfig,
+    amount: u64,
+    current_time: i64,
+) {
+    if !lp_position.is_penalty_exempt() {
+        return;
+    }
+    
+    let waived_fee_bps = scheduled_withdrawal_fee_bps(vault_account, lp_position, protocol_config, current_time);
+    if waived_fee_bps == 0 {
+        return;
+    }
+    
+    emit!(PenaltyExemptionUsed {
+        vault: lp_position.vault,
+        owner: lp_position.owner,
+        protocol_owned: lp_position.is_protocol,
+        waived_fee_bps,
+        waived_amount: (amount as u128 * waived_fee_bps as u128 / 10000) as u64,
+    });
+}
+
+/// Withdrawal penalty in basis points before exemptions
+/// The higher of the time-based curve and the launch-phase penalty applies
+fn scheduled_withdrawal_fee_bps(
+    vault_account: &VaultAccount,
+    lp_position: &LPPosition,
+    protocol_config: &ProtocolConfig,
+    current_time: i64,
+) -> u16 {
+    let time_since_deposit = current_time - lp_position.last_deposit_time;
+    let tier_fee_bps = interpolate_curve(
+        &protocol_config.withdrawal_fee_curve,
+        time_since_deposit.max(0) as u64,
+    ) as u16;
+    
+    // During the launch phase the decaying launch penalty applies if it is higher
+    let launch_fee_bps = calculate_launch_decay(
+        vault_account.launch_withdrawal_fee_bps,
+        vault_account.launch_start_time,
+        vault_account.launch_duration,
+        current_time,
+    );
+    
+    tier_fee_bps.max(launch_fee_bps)
 }
 
 #[error_code]
@@ -163,9 +295,21 @@ pub enum ErrorCode {
     #[msg("Math operation resulted in overflow")]
     MathOverflow,
     
-    #[msg("Insufficient funds in LP position")]
+    #[msg("Insufficient shares in LP position")]
     InsufficientFunds,
     
     #[msg("Insufficient funds in vault")]
     InsufficientVaultFunds,
+    
+    #[msg("Insurance fund token account is missing or invalid")]
+    InvalidInsuranceFund,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Withdrawals from this vault are paused")]
+    WithdrawalsPaused,
+    
+    #[msg("Protocol-owned liquidity is withdrawn with withdraw_protocol_liquidity")]
+    ProtocolPosition,
 } 
\ No newline at end of file
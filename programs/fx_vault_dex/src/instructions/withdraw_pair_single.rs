@@ -0,0 +1,406 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{
+    VaultAccount, LPPosition, ProtocolConfig, PairConfig, PairState, VaultPair, UserStats, EmergencyState, KeeperHeartbeat, VAULT_ACCOUNT_SEED, LP_POSITION_SEED,
+    VAULT_AUTHORITY_SEED, PROTOCOL_CONFIG_SEED, PAIR_CONFIG_SEED, PAIR_STATE_SEED, VAULT_PAIR_SEED, USER_STATS_SEED, EMERGENCY_STATE_SEED, KEEPER_HEARTBEAT_SEED
+};
+use crate::instructions::{calculate_withdrawal_fee_bps, report_penalty_exemption};
+use crate::utils::{
+    trip_pair_circuit_breakers, price_pair_swap, settle_pair_swap, PairPricingAccounts, PairSettlementAccounts, check_transaction_guard
+};
+
+#[derive(Accounts)]
+pub struct WithdrawPairSingle<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    // Vault whose withdrawal is swapped into the target currency
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, source_vault.token_mint.as_ref()],
+        bump,
+    )]
+    pub source_vault: Box<Account<'info, VaultAccount>>,
+    
+    // Vault of the currency the user exits to
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, target_vault.token_mint.as_ref()],
+        bump,
+        constraint = target_vault.key() != source_vault.key() @ ErrorCode::SameVault,
+    )]
+    pub target_vault: Box<Account<'info, VaultAccount>>,
+    
+    // Global protocol parameters
+    #[account(
+        seeds = [PROTOCOL_CONFIG_SEED],
+        bump = protocol_config.bump,
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    
+    /// CHECK: Per-pair pricing overrides, may be uninitialized when the pair uses the global parameters
+    #[account(
+        seeds = [
+            PAIR_CONFIG_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_config: AccountInfo<'info>,
+    
+    // Shared price record for the pair, updated by swaps in either direction
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = PairState::LEN,
+        seeds = [
+            PAIR_STATE_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump,
+    )]
+    pub pair_state: Box<Account<'info, PairState>>,
+    
+    // Admin-registered pair; swaps between vaults that were never paired are rejected
+    #[account(
+        mut,
+        seeds = [
+            VAULT_PAIR_SEED,
+            source_vault.token_mint.min(target_vault.token_mint).as_ref(),
+            source_vault.token_mint.max(target_vault.token_mint).as_ref(),
+        ],
+        bump = vault_pair.bump,
+    )]
+    pub vault_pair: Box<Account<'info, VaultPair>>,
+    
+    /// CHECK: Bound to the source vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = source_oracle.key() == source_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub source_oracle: AccountInfo<'info>,
+    
+    /// CHECK: Bound to the target vault's approved feed and parsed as a Pyth price account
+    #[account(
+        constraint = target_oracle.key() == target_vault.oracle @ ErrorCode::InvalidOracle,
+    )]
+    pub target_oracle: AccountInfo<'info>,
+    
+    /// CHECK: This is the source vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, source_vault.key().as_ref()],
+        bump = source_vault.nonce,
+    )]
+    pub source_vault_authority: AccountInfo<'info>,
+    
+    /// CHECK: This is the target vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, target_vault.key().as_ref()],
+        bump = target_vault.nonce,
+    )]
+    pub target_vault_authority: AccountInfo<'info>,
+    
+    // LP positions being withdrawn
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, source_vault.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = source_lp_position.owner == user.key(),
+        constraint = !source_lp_position.is_protocol @ ErrorCode::ProtocolPosition,
+    )]
+    pub source_lp_position: Account<'info, LPPosition>,
+    
+    #[account(
+        mut,
+        seeds = [LP_POSITION_SEED, target_vault.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = target_lp_position.owner == user.key(),
+        constraint = !target_lp_position.is_protocol @ ErrorCode::ProtocolPosition,
+    )]
+    pub target_lp_position: Account<'info, LPPosition>,
+    
+    // User token account receiving the single-currency payout
+    #[account(
+        mut,
+        constraint = user_target_token.mint == target_vault.token_mint,
+        constraint = user_target_token.owner == user.key(),
+    )]
+    pub user_target_token: Account<'info, TokenAccount>,
+    
+    // Vault token accounts
+    #[account(
+        mut,
+        constraint = source_vault_token.key() == source_vault.token_account,
+    )]
+    pub source_vault_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = target_vault_token.key() == target_vault.token_account,
+    )]
+    pub target_vault_token: Account<'info, TokenAccount>,
+    
+    // PDA treasury token accounts receiving withdrawal penalties
+    #[account(
+        mut,
+        constraint = source_pda_treasury_token.mint == source_vault.token_mint,
+        constraint = source_pda_treasury_token.owner == source_vault.pda_treasury,
+    )]
+    pub source_pda_treasury_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = target_pda_treasury_token.mint == target_vault.token_mint,
+        constraint = target_pda_treasury_token.owner == target_vault.pda_treasury,
+    )]
+    pub target_pda_treasury_token: Account<'info, TokenAccount>,
+    
+    // Global kill switch; swaps and deposits are rejected while it is on
+    #[account(
+        seeds = [EMERGENCY_STATE_SEED],
+        bump = emergency_state.bump,
+        constraint = !emergency_state.halted @ ErrorCode::EmergencyHalt,
+    )]
+    pub emergency_state: Account<'info, EmergencyState>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    // Keeper liveness record; leaving it out counts as no heartbeat while monitoring is on
+    #[account(
+        seeds = [KEEPER_HEARTBEAT_SEED],
+        bump = keeper_heartbeat.bump,
+    )]
+    pub keeper_heartbeat: Option<Account<'info, KeeperHeartbeat>>,
+    
+    // Opt-in trader statistics, updated exactly as by swap
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserStats::LEN,
+        seeds = [USER_STATS_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_stats: Option<Box<Account<'info, UserStats>>>,
+}
+
+pub fn handler(
+    ctx: Context<WithdrawPairSingle>,
+    source_shares: u64,
+    target_shares: u64,
+    minimum_amount_out: u64,
+) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.source_vault, &ctx.accounts.source_vault.key(), instructions_sysvar.as_ref())?;
+    check_transaction_guard(&ctx.accounts.target_vault, &ctx.accounts.target_vault.key(), instructions_sysvar.as_ref())?;
+    
+    let current_time = Clock::get()?.unix_timestamp;
+    let source_vault_key = ctx.accounts.source_vault.key();
+    let target_vault_key = ctx.accounts.target_vault.key();
+    
+    // A breaker trip or resume must persist, so a halted pair ends the exit before any share
+    // is redeemed; the LP can still exit each vault with withdraw_liquidity
+    if !trip_pair_circuit_breakers(
+        &mut ctx.accounts.source_vault,
+        source_vault_key,
+        &mut ctx.accounts.target_vault,
+        target_vault_key,
+        &ctx.accounts.source_oracle,
+        &ctx.accounts.target_oracle,
+    )? {
+        msg!("Single-currency exit not executed: circuit breaker active");
+        return Ok(());
+    }
+    
+    let source_vault = &mut ctx.accounts.source_vault;
+    let target_vault = &mut ctx.accounts.target_vault;
+    let source_lp_position = &mut ctx.accounts.source_lp_position;
+    let target_lp_position = &mut ctx.accounts.target_lp_position;
+    
+    // Ensure both positions hold the shares being redeemed
+    require!(source_lp_position.shares >= source_shares, ErrorCode::InsufficientFunds);
+    require!(target_lp_position.shares >= target_shares, ErrorCode::InsufficientFunds);
+    
+    // Exiting both legs is a withdrawal from each vault plus a swap between them
+    require!(!source_vault.withdrawals_paused && !target_vault.withdrawals_paused, ErrorCode::WithdrawalsPaused);
+    require!(!source_vault.swaps_paused && !target_vault.swaps_paused, ErrorCode::SwapsPaused);
+    
+    // 1. Redeem both legs at their vaults' exchange rates and apply withdrawal penalties
+    let source_withdraw_amount = source_vault.redeem_shares(source_shares).map_err(|_| ErrorCode::InsufficientVaultFunds)?;
+    let target_withdraw_amount = target_vault.redeem_shares(target_shares).map_err(|_| ErrorCode::InsufficientVaultFunds)?;
+    
+    // Partial withdrawals must clear the dust limit; closing out a position always can
+    require!(
+        source_withdraw_amount >= source_vault.min_withdraw_amount || source_shares == source_lp_position.shares,
+        ErrorCode::BelowMinimumAmount
+    );
+    require!(
+        target_withdraw_amount >= target_vault.min_withdraw_amount || target_shares == target_lp_position.shares,
+        ErrorCode::BelowMinimumAmount
+    );
+    
+    let source_fee_bps = calculate_withdrawal_fee_bps(source_vault, source_lp_position, &ctx.accounts.protocol_config, current_time);
+    let target_fee_bps = calculate_withdrawal_fee_bps(target_vault, target_lp_position, &ctx.accounts.protocol_config, current_time);
+    report_penalty_exemption(source_vault, source_lp_position, &ctx.accounts.protocol_config, source_withdraw_amount, current_time);
+    report_penalty_exemption(target_vault, target_lp_position, &ctx.accounts.protocol_config, target_withdraw_amount, current_time);
+    
+    let source_penalty = source_withdraw_amount.checked_mul(source_fee_bps as u64).ok_or(ErrorCode::MathOverflow)?.checked_div(10000).ok_or(ErrorCode::MathOverflow)?;
+    let target_penalty = target_withdraw_amount.checked_mul(target_fee_bps as u64).ok_or(ErrorCode::MathOverflow)?.checked_div(10000).ok_or(ErrorCode::MathOverflow)?;
+    
+    // 2. Swap the source leg into the target currency, priced after both withdrawals
+    let amount_in = source_withdraw_amount.checked_sub(source_penalty).ok_or(ErrorCode::MathOverflow)?;
+    
+    // The conversion is priced exactly like a regular swap, including its breakers and caps
+    let pair_config = PairConfig::load(&ctx.accounts.pair_config)?;
+    let quote = price_pair_swap(
+        PairPricingAccounts {
+            source_vault,
+            target_vault,
+            source_oracle: &ctx.accounts.source_oracle,
+            target_oracle: &ctx.accounts.target_oracle,
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_config: pair_config.as_ref(),
+            pair_state: &mut ctx.accounts.pair_state,
+            vault_pair: &ctx.accounts.vault_pair,
+            keeper_heartbeat: ctx.accounts.keeper_heartbeat.as_deref(),
+        },
+        amount_in,
+    )?;
+    
+    // One slippage bound covers the whole single-currency payout
+    let total_out = target_withdraw_amount
+        .checked_sub(target_penalty)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_add(quote.amount_out)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(total_out >= minimum_amount_out, ErrorCode::SlippageExceeded);
+    
+    // 3. Settle the conversion like a regular swap; the source leg is already in the source vault
+    // and the target leg is paid out alongside the amount out
+    settle_pair_swap(
+        PairSettlementAccounts {
+            user: ctx.accounts.user.to_account_info(),
+            source_vault,
+            source_vault_key,
+            target_vault,
+            target_vault_key,
+            user_source_token: None,
+            source_vault_token: ctx.accounts.source_vault_token.to_account_info(),
+            target_vault_token: ctx.accounts.target_vault_token.to_account_info(),
+            user_target_token: ctx.accounts.user_target_token.to_account_info(),
+            target_vault_authority: ctx.accounts.target_vault_authority.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            protocol_config: &ctx.accounts.protocol_config,
+            pair_state: &mut ctx.accounts.pair_state,
+            pair_state_bump: *ctx.bumps.get("pair_state").unwrap(),
+            vault_pair: &mut ctx.accounts.vault_pair,
+            user_stats: ctx.accounts.user_stats.as_deref_mut().map(|user_stats| &mut **user_stats),
+            user_stats_bump: ctx.bumps.get("user_stats").copied().unwrap_or_default(),
+        },
+        &quote,
+        amount_in,
+        total_out,
+    )?;
+    
+    // 4. Transfer the target penalty from the target vault
+    if target_penalty > 0 {
+        let target_bump = target_vault.nonce;
+        let target_seeds = &[
+            VAULT_AUTHORITY_SEED,
+            target_vault_key.as_ref(),
+            &[target_bump],
+        ];
+        let target_signer_seeds = &[&target_seeds[..]];
+        
+        let penalty_transfer_accounts = Transfer {
+            from: ctx.accounts.target_vault_token.to_account_info(),
+            to: ctx.accounts.target_pda_treasury_token.to_account_info(),
+            authority: ctx.accounts.target_vault_authority.to_account_info(),
+        };
+        
+        let penalty_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            penalty_transfer_accounts,
+            target_signer_seeds,
+        );
+        
+        token::transfer(penalty_cpi_ctx, target_penalty)?;
+    }
+    
+    // 5. The converted source leg stays in the source vault; only its penalty leaves
+    if source_penalty > 0 {
+        let source_bump = source_vault.nonce;
+        let source_seeds = &[
+            VAULT_AUTHORITY_SEED,
+            source_vault_key.as_ref(),
+            &[source_bump],
+        ];
+        let source_signer_seeds = &[&source_seeds[..]];
+        
+        let penalty_transfer_accounts = Transfer {
+            from: ctx.accounts.source_vault_token.to_account_info(),
+            to: ctx.accounts.source_pda_treasury_token.to_account_info(),
+            authority: ctx.accounts.source_vault_authority.to_account_info(),
+        };
+        
+        let penalty_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            penalty_transfer_accounts,
+            source_signer_seeds,
+        );
+        
+        token::transfer(penalty_cpi_ctx, source_penalty)?;
+    }
+    
+    // 6. Update position accounting
+    source_lp_position.shares = source_lp_position.shares.checked_sub(source_shares).ok_or(ErrorCode::MathOverflow)?;
+    target_lp_position.shares = target_lp_position.shares.checked_sub(target_shares).ok_or(ErrorCode::MathOverflow)?;
+    
+    msg!("Withdrew {} source and {} target tokens, converted to {} target tokens (penalties: {} / {})",
+         source_withdraw_amount, target_withdraw_amount, total_out, source_penalty, target_penalty);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+    
+    #[msg("Insufficient shares in LP position")]
+    InsufficientFunds,
+    
+    #[msg("Insufficient funds in vault")]
+    InsufficientVaultFunds,
+    
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+    
+    #[msg("A pair withdrawal needs two different vaults")]
+    SameVault,
+    
+    #[msg("Oracle account does not match the vault's feed")]
+    InvalidOracle,
+    
+    #[msg("Protocol is halted by the emergency switch; only withdrawals are open")]
+    EmergencyHalt,
+    
+    #[msg("Amount is below the vault's minimum")]
+    BelowMinimumAmount,
+    
+    #[msg("Withdrawals from this vault are paused")]
+    WithdrawalsPaused,
+    
+    #[msg("Swaps through this vault are paused")]
+    SwapsPaused,
+    
+    #[msg("Protocol-owned liquidity is withdrawn with withdraw_protocol_liquidity")]
+    ProtocolPosition,
+} 
\ No newline at end of file
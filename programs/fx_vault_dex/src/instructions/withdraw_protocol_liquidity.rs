@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{
+    VaultAccount, LPPosition, AdminLog, AdminAction, VAULT_ACCOUNT_SEED, VAULT_AUTHORITY_SEED, POL_POSITION_SEED,
+    ADMIN_LOG_SEED
+};
+use crate::events::ProtocolLiquidityWithdrawn;
+use crate::utils::{record_admin_action, check_transaction_guard};
+
+#[derive(Accounts)]
+pub struct WithdrawProtocolLiquidity<'info> {
+    #[account(
+        constraint = treasury.key() == vault_account.treasury,
+    )]
+    pub treasury: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_ACCOUNT_SEED, vault_account.token_mint.as_ref()],
+        bump,
+    )]
+    pub vault_account: Account<'info, VaultAccount>,
+    
+    /// CHECK: This is the vault authority PDA
+    #[account(
+        seeds = [VAULT_AUTHORITY_SEED, vault_account.key().as_ref()],
+        bump = vault_account.nonce,
+    )]
+    pub vault_authority: AccountInfo<'info>,
+    
+    // Protocol-owned LP position seeded by seed_protocol_liquidity
+    #[account(
+        mut,
+        seeds = [POL_POSITION_SEED, vault_account.key().as_ref()],
+        bump = lp_position.bump,
+    )]
+    pub lp_position: Account<'info, LPPosition>,
+    
+    // Principal and yield are both paid to the vault's treasury
+    #[account(
+        mut,
+        constraint = treasury_token_account.mint == vault_account.token_mint,
+        constraint = treasury_token_account.owner == vault_account.treasury,
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault_account.token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    // Append-only record of admin actions
+    #[account(
+        mut,
+        seeds = [ADMIN_LOG_SEED],
+        bump = admin_log.bump,
+    )]
+    pub admin_log: Box<Account<'info, AdminLog>>,
+    
+    /// CHECK: Instructions sysvar, required when a vault's transaction guard is on
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+pub fn handler(ctx: Context<WithdrawProtocolLiquidity>, shares: u64) -> Result<()> {
+    // Guarded vaults reject other operations on them in the same transaction
+    let instructions_sysvar = ctx.accounts.instructions_sysvar.as_ref().map(|sysvar| sysvar.to_account_info());
+    check_transaction_guard(&ctx.accounts.vault_account, &ctx.accounts.vault_account.key(), instructions_sysvar.as_ref())?;
+    
+    let old_value = ctx.accounts.vault_account.protocol_owned_liquidity;
+    
+    let vault_account = &mut ctx.accounts.vault_account;
+    let lp_position = &mut ctx.accounts.lp_position;
+    
+    require!(shares > 0, ErrorCode::InvalidAmount);
+    require!(!vault_account.withdrawals_paused, ErrorCode::WithdrawalsPaused);
+    require!(lp_position.shares >= shares, ErrorCode::InsufficientFunds);
+    
+    // Protocol-owned liquidity pays no withdrawal penalty
+    let amount = vault_account.redeem_shares(shares).map_err(|_| ErrorCode::InsufficientVaultFunds)?;
+    
+    // The position is tracked at cost; anything above the outstanding cost is earned yield
+    let principal = amount.min(vault_account.protocol_owned_liquidity);
+    let yield_amount = amount - principal;
+    
+    let bump = vault_account.nonce;
+    let vault_key = vault_account.key();
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        vault_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.vault_token_account.to_account_info(),
+        to: ctx.accounts.treasury_token_account.to_account_info(),
+        authority: ctx.accounts.vault_authority.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    vault_account.protocol_owned_liquidity -= principal;
+    lp_position.shares -= shares;
+    
+    emit!(ProtocolLiquidityWithdrawn {
+        vault: vault_key,
+        treasury: ctx.accounts.treasury.key(),
+        shares,
+        principal,
+        yield_amount,
+    });
+    
+    msg!("Withdrew {} tokens of protocol-owned liquidity to the treasury: {} principal, {} yield",
+         amount, principal, yield_amount);
+    
+    record_admin_action(
+        &mut ctx.accounts.admin_log,
+        ctx.accounts.treasury.key(),
+        AdminAction::WithdrawProtocolLiquidity,
+        ctx.accounts.vault_account.key(),
+        old_value,
+        ctx.accounts.vault_account.protocol_owned_liquidity,
+    )?;
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Withdrawal must redeem at least one share")]
+    InvalidAmount,
+    
+    #[msg("Withdrawals from this vault are paused")]
+    WithdrawalsPaused,
+    
+    #[msg("Insufficient shares in the protocol-owned position")]
+    InsufficientFunds,
+    
+    #[msg("Vault cannot cover the withdrawal")]
+    InsufficientVaultFunds,
+} 
\ No newline at end of file
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{StakingPool, StakePosition, STAKING_POOL_SEED, STAKE_POSITION_SEED};
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    pub user: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [STAKING_POOL_SEED, staking_pool.stake_mint.as_ref()],
+        bump = staking_pool.bump,
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, staking_pool.key().as_ref(), user.key().as_ref()],
+        bump = stake_position.bump,
+        constraint = stake_position.owner == user.key(),
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        mut,
+        constraint = user_stake_token.mint == staking_pool.stake_mint,
+        constraint = user_stake_token.owner == user.key(),
+    )]
+    pub user_stake_token: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = stake_vault.key() == staking_pool.stake_vault,
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let stake_position = &mut ctx.accounts.stake_position;
+    
+    // Apply any slashes taken while the tokens were cooling down
+    stake_position.settle(staking_pool.reward_index, staking_pool.slash_factor)?;
+    
+    let amount = stake_position.unstaking_amount;
+    require!(amount > 0, ErrorCode::NothingToWithdraw);
+    
+    // Ensure the cooldown has elapsed
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        current_time - stake_position.unstake_request_time >= staking_pool.unstake_cooldown,
+        ErrorCode::CooldownActive
+    );
+    
+    // Transfer stake tokens from the stake vault back to the user
+    let stake_mint = staking_pool.stake_mint;
+    let seeds = &[
+        STAKING_POOL_SEED,
+        stake_mint.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_cpi_accounts = Transfer {
+        from: ctx.accounts.stake_vault.to_account_info(),
+        to: ctx.accounts.user_stake_token.to_account_info(),
+        authority: staking_pool.to_account_info(),
+    };
+    
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_cpi_accounts,
+        signer_seeds,
+    );
+    
+    token::transfer(cpi_ctx, amount)?;
+    
+    stake_position.unstaking_amount = 0;
+    staking_pool.total_unstaking = staking_pool.total_unstaking.saturating_sub(amount);
+    
+    msg!("Withdrew {} unstaked tokens", amount);
+    
+    Ok(())
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("No unstaked tokens to withdraw")]
+    NothingToWithdraw,
+    
+    #[msg("Unstake cooldown has not elapsed")]
+    CooldownActive,
+} 
\ No newline at end of file
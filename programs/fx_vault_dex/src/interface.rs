@@ -0,0 +1,129 @@
+//! Frozen interface for CPI integrators and aggregator adapters
+//!
+//! The instruction discriminators below are pinned and checked at compile time, so renaming
+//! one of these instructions fails the build instead of silently breaking integrators.
+//! Their account orders are frozen as well; new accounts are only ever appended, and the
+//! tests below check the generated account metas against the orders listed here.
+//!
+//! swap: user, source_vault, target_vault, protocol_config, pair_config, pair_state,
+//! vault_pair, user_stats, source_oracle, target_oracle, target_vault_authority,
+//! user_source_token, user_target_token, source_vault_token, target_vault_token,
+//! emergency_state, instructions_sysvar, token_program, system_program, keeper_heartbeat
+//!
+//! deposit_liquidity: user, vault_account, position_owner, lp_position, user_token_account,
+//! vault_token_account, emergency_state, instructions_sysvar, token_program, system_program
+//!
+//! withdraw_liquidity: user, vault_account, protocol_config, vault_authority, lp_position,
+//! user_token_account, vault_token_account, pda_treasury, pda_treasury_token,
+//! insurance_fund_token, instructions_sysvar, token_program, system_program
+use anchor_lang::Discriminator;
+use crate::instruction;
+
+/// First 8 bytes of SHA-256("global:swap")
+pub const SWAP_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// First 8 bytes of SHA-256("global:deposit_liquidity")
+pub const DEPOSIT_LIQUIDITY_DISCRIMINATOR: [u8; 8] = [245, 99, 59, 25, 151, 71, 233, 249];
+
+/// First 8 bytes of SHA-256("global:withdraw_liquidity")
+pub const WITHDRAW_LIQUIDITY_DISCRIMINATOR: [u8; 8] = [149, 158, 33, 185, 47, 243, 253, 31];
+
+const fn discriminator_eq(a: &[u8; 8], b: &[u8; 8]) -> bool {
+    let mut i = 0;
+    while i < 8 {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const _: () = assert!(discriminator_eq(&instruction::Swap::DISCRIMINATOR, &SWAP_DISCRIMINATOR));
+const _: () = assert!(discriminator_eq(&instruction::DepositLiquidity::DISCRIMINATOR, &DEPOSIT_LIQUIDITY_DISCRIMINATOR));
+const _: () = assert!(discriminator_eq(&instruction::WithdrawLiquidity::DISCRIMINATOR, &WITHDRAW_LIQUIDITY_DISCRIMINATOR));
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::*;
+    use crate::accounts;
+    
+    /// One distinct key per frozen position, in order
+    fn frozen_keys(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+    
+    fn meta_keys(metas: Vec<AccountMeta>) -> Vec<Pubkey> {
+        metas.iter().map(|meta| meta.pubkey).collect()
+    }
+    
+    #[test]
+    fn swap_account_order_is_frozen() {
+        let keys = frozen_keys(20);
+        let swap = accounts::Swap {
+            user: keys[0],
+            source_vault: keys[1],
+            target_vault: keys[2],
+            protocol_config: keys[3],
+            pair_config: keys[4],
+            pair_state: keys[5],
+            vault_pair: keys[6],
+            user_stats: Some(keys[7]),
+            source_oracle: keys[8],
+            target_oracle: keys[9],
+            target_vault_authority: keys[10],
+            user_source_token: keys[11],
+            user_target_token: keys[12],
+            source_vault_token: keys[13],
+            target_vault_token: keys[14],
+            emergency_state: keys[15],
+            instructions_sysvar: Some(keys[16]),
+            token_program: keys[17],
+            system_program: keys[18],
+            keeper_heartbeat: Some(keys[19]),
+        };
+        
+        assert_eq!(meta_keys(swap.to_account_metas(None)), keys);
+    }
+    
+    #[test]
+    fn deposit_liquidity_account_order_is_frozen() {
+        let keys = frozen_keys(10);
+        let deposit = accounts::DepositLiquidity {
+            user: keys[0],
+            vault_account: keys[1],
+            position_owner: keys[2],
+            lp_position: keys[3],
+            user_token_account: keys[4],
+            vault_token_account: keys[5],
+            emergency_state: keys[6],
+            instructions_sysvar: Some(keys[7]),
+            token_program: keys[8],
+            system_program: keys[9],
+        };
+        
+        assert_eq!(meta_keys(deposit.to_account_metas(None)), keys);
+    }
+    
+    #[test]
+    fn withdraw_liquidity_account_order_is_frozen() {
+        let keys = frozen_keys(13);
+        let withdraw = accounts::WithdrawLiquidity {
+            user: keys[0],
+            vault_account: keys[1],
+            protocol_config: keys[2],
+            vault_authority: keys[3],
+            lp_position: keys[4],
+            user_token_account: keys[5],
+            vault_token_account: keys[6],
+            pda_treasury: keys[7],
+            pda_treasury_token: keys[8],
+            insurance_fund_token: Some(keys[9]),
+            instructions_sysvar: Some(keys[10]),
+            token_program: keys[11],
+            system_program: keys[12],
+        };
+        
+        assert_eq!(meta_keys(withdraw.to_account_metas(None)), keys);
+    }
+} 
\ No newline at end of file
@@ -1,10 +1,17 @@
+#![allow(clippy::result_large_err)]
+// Anchor 0.28's macros emit cfgs (anchor-debug, custom-heap, target_os = "solana") newer toolchains do not know
+#![allow(unexpected_cfgs)]
+
 use anchor_lang::prelude::*;
 
+pub mod events;
 pub mod instructions;
+pub mod interface;
 pub mod state;
 pub mod utils;
 
 use instructions::*;
+use state::{DriftCurve, ParameterCurve, PenaltySplit, Role};
 
 declare_id!("5mm6uP4Qgumg3gXiiLg7jgWJkcUFXHKdUutz5HfmWnSs");
 
@@ -15,10 +22,9 @@ pub mod fx_vault_dex {
     pub fn initialize_vault(
         ctx: Context<InitializeVault>,
         vault_name: String,
-        nonce: u8,
         fee_basis_points: u16
     ) -> Result<()> {
-        instructions::initialize_vault::handler(ctx, vault_name, nonce, fee_basis_points)
+        instructions::initialize_vault::handler(ctx, vault_name, fee_basis_points)
     }
 
     pub fn deposit_liquidity(
@@ -30,18 +36,17 @@ pub mod fx_vault_dex {
 
     pub fn withdraw_liquidity(
         ctx: Context<WithdrawLiquidity>,
-        amount: u64,
+        shares: u64,
     ) -> Result<()> {
-        instructions::withdraw_liquidity::handler(ctx, amount)
+        instructions::withdraw_liquidity::handler(ctx, shares)
     }
 
     pub fn swap(
         ctx: Context<Swap>,
         amount_in: u64,
         minimum_amount_out: u64,
-        oracle_price: u64,
     ) -> Result<()> {
-        instructions::swap::handler(ctx, amount_in, minimum_amount_out, oracle_price)
+        instructions::swap::handler(ctx, amount_in, minimum_amount_out)
     }
 
     pub fn distribute_incentives(
@@ -63,4 +68,644 @@ pub mod fx_vault_dex {
     ) -> Result<()> {
         instructions::rebalance_vault::handler(ctx, amount, oracle_price)
     }
+    
+    pub fn set_balance_incentive(
+        ctx: Context<SetBalanceIncentive>,
+        target_health_bps: u16,
+        bonus_bps: u16,
+        bonus_cap: u64,
+        cooldown: i64,
+    ) -> Result<()> {
+        instructions::set_balance_incentive::handler(ctx, target_health_bps, bonus_bps, bonus_cap, cooldown)
+    }
+    
+    pub fn balance_incentive(
+        ctx: Context<BalanceIncentive>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+    ) -> Result<()> {
+        instructions::balance_incentive::handler(ctx, amount_in, minimum_amount_out)
+    }
+    
+    pub fn seed_protocol_liquidity(
+        ctx: Context<SeedProtocolLiquidity>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::seed_protocol_liquidity::handler(ctx, amount)
+    }
+    
+    pub fn configure_launch_mode(
+        ctx: Context<ConfigureLaunchMode>,
+        spread_bps: u16,
+        withdrawal_fee_bps: u16,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::configure_launch_mode::handler(ctx, spread_bps, withdrawal_fee_bps, duration)
+    }
+    
+    pub fn configure_reward_vesting(
+        ctx: Context<ConfigureRewardVesting>,
+        vesting_duration: i64,
+        early_claim_penalty_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_reward_vesting::handler(ctx, vesting_duration, early_claim_penalty_bps)
+    }
+    
+    pub fn claim_vested(
+        ctx: Context<ClaimVested>,
+        claim_unvested: bool,
+    ) -> Result<()> {
+        instructions::claim_vested::handler(ctx, claim_unvested)
+    }
+    
+    pub fn initialize_staking_pool(
+        ctx: Context<InitializeStakingPool>,
+        fee_share_bps: u16,
+        unstake_cooldown: i64,
+    ) -> Result<()> {
+        instructions::initialize_staking_pool::handler(ctx, fee_share_bps, unstake_cooldown)
+    }
+    
+    pub fn stake(
+        ctx: Context<Stake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stake::handler(ctx, amount)
+    }
+    
+    pub fn unstake(
+        ctx: Context<Unstake>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unstake::handler(ctx, amount)
+    }
+    
+    pub fn withdraw_stake(
+        ctx: Context<WithdrawStake>,
+    ) -> Result<()> {
+        instructions::withdraw_stake::handler(ctx)
+    }
+    
+    pub fn claim_staking_rewards(
+        ctx: Context<ClaimStakingRewards>,
+    ) -> Result<()> {
+        instructions::claim_staking_rewards::handler(ctx)
+    }
+    
+    pub fn configure_slashing(
+        ctx: Context<ConfigureSlashing>,
+        max_slash_bps: u16,
+        slash_timelock: i64,
+    ) -> Result<()> {
+        instructions::configure_slashing::handler(ctx, max_slash_bps, slash_timelock)
+    }
+    
+    pub fn propose_slash(
+        ctx: Context<ProposeSlash>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::propose_slash::handler(ctx, amount)
+    }
+    
+    pub fn execute_slash(
+        ctx: Context<ExecuteSlash>,
+    ) -> Result<()> {
+        instructions::execute_slash::handler(ctx)
+    }
+    
+    pub fn cancel_slash(
+        ctx: Context<CancelSlash>,
+    ) -> Result<()> {
+        instructions::cancel_slash::handler(ctx)
+    }
+    
+    pub fn snapshot_price(
+        ctx: Context<SnapshotPrice>,
+    ) -> Result<()> {
+        instructions::snapshot_price::handler(ctx)
+    }
+    
+    pub fn initialize_feed_registry(
+        ctx: Context<InitializeFeedRegistry>,
+    ) -> Result<()> {
+        instructions::initialize_feed_registry::handler(ctx)
+    }
+    
+    pub fn register_feed(
+        ctx: Context<RegisterFeed>,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::register_feed::handler(ctx, base_mint, quote_mint)
+    }
+    
+    pub fn remove_feed(
+        ctx: Context<RemoveFeed>,
+        base_mint: Pubkey,
+        quote_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_feed::handler(ctx, base_mint, quote_mint)
+    }
+    
+    pub fn configure_circuit_breaker(
+        ctx: Context<ConfigureCircuitBreaker>,
+        max_price_deviation_bps: u16,
+        cooldown_slots: u64,
+    ) -> Result<()> {
+        instructions::configure_circuit_breaker::handler(ctx, max_price_deviation_bps, cooldown_slots)
+    }
+    
+    pub fn set_daily_volume_cap(
+        ctx: Context<SetDailyVolumeCap>,
+        daily_volume_cap: u64,
+    ) -> Result<()> {
+        instructions::set_daily_volume_cap::handler(ctx, daily_volume_cap)
+    }
+    
+    pub fn configure_hedge(
+        ctx: Context<ConfigureHedge>,
+        keeper: Pubkey,
+        exposure_threshold: u64,
+        max_hedge_notional: u64,
+    ) -> Result<()> {
+        instructions::configure_hedge::handler(ctx, keeper, exposure_threshold, max_hedge_notional)
+    }
+    
+    pub fn adjust_hedge<'info>(
+        ctx: Context<'_, '_, '_, 'info, AdjustHedge<'info>>,
+        target_hedge_notional: i64,
+        perp_instruction_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::adjust_hedge::handler(ctx, target_hedge_notional, perp_instruction_data)
+    }
+    
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+    ) -> Result<()> {
+        instructions::initialize_protocol_config::handler(ctx)
+    }
+    
+    pub fn set_drift_curve(
+        ctx: Context<SetDriftCurve>,
+        drift_curve: DriftCurve,
+    ) -> Result<()> {
+        instructions::set_drift_curve::handler(ctx, drift_curve)
+    }
+    
+    pub fn set_fee_allocation_curve(
+        ctx: Context<SetFeeAllocationCurve>,
+        protocol_fee_curve: ParameterCurve,
+    ) -> Result<()> {
+        instructions::set_fee_allocation_curve::handler(ctx, protocol_fee_curve)
+    }
+    
+    pub fn set_withdrawal_fee_curve(
+        ctx: Context<SetWithdrawalFeeCurve>,
+        withdrawal_fee_curve: ParameterCurve,
+    ) -> Result<()> {
+        instructions::set_withdrawal_fee_curve::handler(ctx, withdrawal_fee_curve)
+    }
+    
+    pub fn set_spread_slope(
+        ctx: Context<SetSpreadSlope>,
+        spread_slope_ppm: u32,
+    ) -> Result<()> {
+        instructions::set_spread_slope::handler(ctx, spread_slope_ppm)
+    }
+    
+    pub fn set_pair_config(
+        ctx: Context<SetPairConfig>,
+        spread_slope_ppm: u32,
+        drift_curve: Option<DriftCurve>,
+        stableswap_amp: u16,
+        max_execution_deviation_bps: u16,
+    ) -> Result<()> {
+        instructions::set_pair_config::handler(ctx, spread_slope_ppm, drift_curve, stableswap_amp, max_execution_deviation_bps)
+    }
+    
+    pub fn deposit_pair(
+        ctx: Context<DepositPair>,
+        amount_a: u64,
+        amount_b: u64,
+    ) -> Result<()> {
+        instructions::deposit_pair::handler(ctx, amount_a, amount_b)
+    }
+    
+    pub fn withdraw_pair_single(
+        ctx: Context<WithdrawPairSingle>,
+        source_shares: u64,
+        target_shares: u64,
+        minimum_amount_out: u64,
+    ) -> Result<()> {
+        instructions::withdraw_pair_single::handler(ctx, source_shares, target_shares, minimum_amount_out)
+    }
+    
+    pub fn split_lp_position(
+        ctx: Context<SplitLpPosition>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::split_lp_position::handler(ctx, shares)
+    }
+    
+    pub fn merge_lp_position(
+        ctx: Context<MergeLpPosition>,
+    ) -> Result<()> {
+        instructions::merge_lp_position::handler(ctx)
+    }
+    
+    pub fn set_position_manager(
+        ctx: Context<SetPositionManager>,
+        manager: Pubkey,
+    ) -> Result<()> {
+        instructions::set_position_manager::handler(ctx, manager)
+    }
+    
+    pub fn configure_retained_earnings(
+        ctx: Context<ConfigureRetainedEarnings>,
+        retain_earnings: bool,
+        fee_fold_interval: i64,
+    ) -> Result<()> {
+        instructions::configure_retained_earnings::handler(ctx, retain_earnings, fee_fold_interval)
+    }
+    
+    pub fn fold_lp_fees(
+        ctx: Context<FoldLpFees>,
+    ) -> Result<()> {
+        instructions::fold_lp_fees::handler(ctx)
+    }
+    
+    pub fn configure_vault_fees(
+        ctx: Context<ConfigureVaultFees>,
+        management_fee_bps: u16,
+        performance_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::configure_vault_fees::handler(ctx, management_fee_bps, performance_fee_bps)
+    }
+    
+    pub fn accrue_vault_fees(
+        ctx: Context<AccrueVaultFees>,
+    ) -> Result<()> {
+        instructions::accrue_vault_fees::handler(ctx)
+    }
+    
+    pub fn set_penalty_split(
+        ctx: Context<SetPenaltySplit>,
+        penalty_split: PenaltySplit,
+        insurance_fund: Pubkey,
+    ) -> Result<()> {
+        instructions::set_penalty_split::handler(ctx, penalty_split, insurance_fund)
+    }
+    
+    pub fn set_penalty_exemption(
+        ctx: Context<SetPenaltyExemption>,
+        exempt: bool,
+    ) -> Result<()> {
+        instructions::set_penalty_exemption::handler(ctx, exempt)
+    }
+    
+    pub fn propose_protocol_admin(
+        ctx: Context<ProposeProtocolAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_protocol_admin::handler(ctx, new_admin)
+    }
+    
+    pub fn accept_protocol_admin(
+        ctx: Context<AcceptProtocolAdmin>,
+    ) -> Result<()> {
+        instructions::accept_protocol_admin::handler(ctx)
+    }
+    
+    pub fn propose_registry_admin(
+        ctx: Context<ProposeRegistryAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_registry_admin::handler(ctx, new_admin)
+    }
+    
+    pub fn accept_registry_admin(
+        ctx: Context<AcceptRegistryAdmin>,
+    ) -> Result<()> {
+        instructions::accept_registry_admin::handler(ctx)
+    }
+    
+    pub fn propose_vault_treasury(
+        ctx: Context<ProposeVaultTreasury>,
+        new_treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_treasury::handler(ctx, new_treasury)
+    }
+    
+    pub fn accept_vault_treasury(
+        ctx: Context<AcceptVaultTreasury>,
+    ) -> Result<()> {
+        instructions::accept_vault_treasury::handler(ctx)
+    }
+    
+    pub fn propose_staking_authority(
+        ctx: Context<ProposeStakingAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_staking_authority::handler(ctx, new_authority)
+    }
+    
+    pub fn accept_staking_authority(
+        ctx: Context<AcceptStakingAuthority>,
+    ) -> Result<()> {
+        instructions::accept_staking_authority::handler(ctx)
+    }
+    
+    pub fn initialize_emergency_state(
+        ctx: Context<InitializeEmergencyState>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_emergency_state::handler(ctx, guardian)
+    }
+    
+    pub fn set_emergency_halt(
+        ctx: Context<SetEmergencyHalt>,
+        halted: bool,
+    ) -> Result<()> {
+        instructions::set_emergency_halt::handler(ctx, halted)
+    }
+    
+    pub fn set_guardian(
+        ctx: Context<SetGuardian>,
+        guardian: Pubkey,
+    ) -> Result<()> {
+        instructions::set_guardian::handler(ctx, guardian)
+    }
+    
+    pub fn initialize_admin_log(
+        ctx: Context<InitializeAdminLog>,
+    ) -> Result<()> {
+        instructions::initialize_admin_log::handler(ctx)
+    }
+    
+    pub fn attest_upgrade_authority(
+        ctx: Context<AttestUpgradeAuthority>,
+    ) -> Result<()> {
+        instructions::attest_upgrade_authority::handler(ctx)
+    }
+    
+    pub fn set_minimum_amounts(
+        ctx: Context<SetMinimumAmounts>,
+        min_deposit_amount: u64,
+        min_withdraw_amount: u64,
+        min_swap_amount: u64,
+    ) -> Result<()> {
+        instructions::set_minimum_amounts::handler(ctx, min_deposit_amount, min_withdraw_amount, min_swap_amount)
+    }
+    
+    pub fn sweep_dust(
+        ctx: Context<SweepDust>,
+    ) -> Result<()> {
+        instructions::sweep_dust::handler(ctx)
+    }
+    
+    pub fn withdraw_all(
+        ctx: Context<WithdrawLiquidity>,
+        close_position: bool,
+    ) -> Result<()> {
+        instructions::withdraw_all::handler(ctx, close_position)
+    }
+    
+    pub fn claim_all<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAll<'info>>,
+    ) -> Result<()> {
+        instructions::claim_all::handler(ctx)
+    }
+    
+    pub fn push_incentives<'info>(
+        ctx: Context<'_, '_, '_, 'info, PushIncentives<'info>>,
+        complete_pass: bool,
+        max_positions: u8,
+    ) -> Result<()> {
+        instructions::push_incentives::handler(ctx, complete_pass, max_positions)
+    }
+    
+    pub fn propose_vault_admin(
+        ctx: Context<ProposeVaultAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_vault_admin::handler(ctx, new_admin)
+    }
+    
+    pub fn accept_vault_admin(
+        ctx: Context<AcceptVaultAdmin>,
+    ) -> Result<()> {
+        instructions::accept_vault_admin::handler(ctx)
+    }
+    
+    pub fn assign_role(
+        ctx: Context<AssignRole>,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        instructions::assign_role::handler(ctx, role, holder)
+    }
+    
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        role: Role,
+    ) -> Result<()> {
+        instructions::revoke_role::handler(ctx, role)
+    }
+    
+    pub fn set_vault_pauses(
+        ctx: Context<SetVaultPauses>,
+        deposits_paused: bool,
+        withdrawals_paused: bool,
+        swaps_paused: bool,
+    ) -> Result<()> {
+        instructions::set_vault_pauses::handler(ctx, deposits_paused, withdrawals_paused, swaps_paused)
+    }
+    
+    pub fn set_wind_down(
+        ctx: Context<SetWindDown>,
+    ) -> Result<()> {
+        instructions::set_wind_down::handler(ctx)
+    }
+    
+    pub fn propose_token_account_rotation(
+        ctx: Context<ProposeTokenAccountRotation>,
+    ) -> Result<()> {
+        instructions::propose_token_account_rotation::handler(ctx)
+    }
+    
+    pub fn cancel_token_account_rotation(
+        ctx: Context<CancelTokenAccountRotation>,
+    ) -> Result<()> {
+        instructions::cancel_token_account_rotation::handler(ctx)
+    }
+    
+    pub fn execute_token_account_rotation(
+        ctx: Context<ExecuteTokenAccountRotation>,
+    ) -> Result<()> {
+        instructions::execute_token_account_rotation::handler(ctx)
+    }
+    
+    pub fn recover_token(
+        ctx: Context<RecoverToken>,
+    ) -> Result<()> {
+        instructions::recover_token::handler(ctx)
+    }
+    
+    pub fn migrate_vault_layout(
+        ctx: Context<MigrateVaultLayout>,
+    ) -> Result<()> {
+        instructions::migrate_vault_layout::handler(ctx)
+    }
+    
+    pub fn create_vault_pair(
+        ctx: Context<CreateVaultPair>,
+        base_is_a: bool,
+    ) -> Result<()> {
+        instructions::create_vault_pair::handler(ctx, base_is_a)
+    }
+    
+    pub fn snapshot_vault_metrics(
+        ctx: Context<SnapshotVaultMetrics>,
+    ) -> Result<()> {
+        instructions::snapshot_vault_metrics::handler(ctx)
+    }
+    
+    pub fn initialize_keeper_registry(
+        ctx: Context<InitializeKeeperRegistry>,
+        min_bond: u64,
+        unbond_cooldown: i64,
+    ) -> Result<()> {
+        instructions::initialize_keeper_registry::handler(ctx, min_bond, unbond_cooldown)
+    }
+    
+    pub fn bond_keeper(
+        ctx: Context<BondKeeper>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::bond_keeper::handler(ctx, amount)
+    }
+    
+    pub fn unbond_keeper(
+        ctx: Context<UnbondKeeper>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unbond_keeper::handler(ctx, amount)
+    }
+    
+    pub fn withdraw_keeper_bond(
+        ctx: Context<WithdrawKeeperBond>,
+    ) -> Result<()> {
+        instructions::withdraw_keeper_bond::handler(ctx)
+    }
+    
+    pub fn slash_keeper(
+        ctx: Context<SlashKeeper>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::slash_keeper::handler(ctx, amount)
+    }
+    
+    pub fn set_rebalance_premium_cap(
+        ctx: Context<SetRebalancePremiumCap>,
+        max_rebalance_premium_bps: u16,
+    ) -> Result<()> {
+        instructions::set_rebalance_premium_cap::handler(ctx, max_rebalance_premium_bps)
+    }
+    
+    pub fn rebalance_from_fees(
+        ctx: Context<RebalanceFromFees>,
+    ) -> Result<()> {
+        instructions::rebalance_from_fees::handler(ctx)
+    }
+    
+    pub fn set_treasury_mint(
+        ctx: Context<SetTreasuryMint>,
+    ) -> Result<()> {
+        instructions::set_treasury_mint::handler(ctx)
+    }
+    
+    pub fn consolidate_fees(
+        ctx: Context<ConsolidateFees>,
+    ) -> Result<()> {
+        instructions::consolidate_fees::handler(ctx)
+    }
+    
+    pub fn set_utilization_fee_curve(
+        ctx: Context<SetUtilizationFeeCurve>,
+        utilization_fee_curve: ParameterCurve,
+    ) -> Result<()> {
+        instructions::set_utilization_fee_curve::handler(ctx, utilization_fee_curve)
+    }
+    
+    pub fn rename_vault(
+        ctx: Context<RenameVault>,
+        vault_name: String,
+    ) -> Result<()> {
+        instructions::rename_vault::handler(ctx, vault_name)
+    }
+    
+    pub fn quote_deposit(
+        ctx: Context<QuoteDeposit>,
+        amount: u64,
+    ) -> Result<DepositQuote> {
+        instructions::quote_deposit::handler(ctx, amount)
+    }
+    
+    pub fn quote_withdraw(
+        ctx: Context<QuoteWithdraw>,
+        shares: u64,
+    ) -> Result<WithdrawQuote> {
+        instructions::quote_withdraw::handler(ctx, shares)
+    }
+    
+    pub fn set_transaction_guard(
+        ctx: Context<SetTransactionGuard>,
+        tx_guard_enabled: bool,
+    ) -> Result<()> {
+        instructions::set_transaction_guard::handler(ctx, tx_guard_enabled)
+    }
+    
+    pub fn swap_and_split<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+        split_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::swap_and_split::handler(ctx, amount_in, minimum_amount_out, split_bps)
+    }
+    
+    pub fn get_version(
+        ctx: Context<GetVersion>,
+    ) -> Result<ProgramVersion> {
+        instructions::get_version::handler(ctx)
+    }
+    
+    pub fn set_insurance_fee_share(
+        ctx: Context<SetInsuranceFeeShare>,
+        insurance_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::set_insurance_fee_share::handler(ctx, insurance_fee_bps)
+    }
+    
+    pub fn set_keeper_liveness(
+        ctx: Context<SetKeeperLiveness>,
+        keeper_liveness_window: i64,
+        degraded_spread_bps: u16,
+        degraded_max_trade_bps: u16,
+    ) -> Result<()> {
+        instructions::set_keeper_liveness::handler(ctx, keeper_liveness_window, degraded_spread_bps, degraded_max_trade_bps)
+    }
+    
+    pub fn record_keeper_heartbeat(
+        ctx: Context<RecordKeeperHeartbeat>,
+    ) -> Result<()> {
+        instructions::record_keeper_heartbeat::handler(ctx)
+    }
+    
+    pub fn withdraw_protocol_liquidity(
+        ctx: Context<WithdrawProtocolLiquidity>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::withdraw_protocol_liquidity::handler(ctx, shares)
+    }
 } 
\ No newline at end of file
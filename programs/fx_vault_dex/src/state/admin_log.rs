@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::ADMIN_LOG_LEN;
+
+/// Privileged actions recorded in the admin log
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum AdminAction {
+    #[default]
+    None,
+    // Protocol admin
+    SetSpreadSlope,
+    SetDriftCurve,
+    SetFeeAllocationCurve,
+    SetWithdrawalFeeCurve,
+    SetPairConfig,
+    SetPenaltySplit,
+    SetPenaltyExemption,
+    SetGuardian,
+    ProposeProtocolAdmin,
+    AcceptProtocolAdmin,
+    // Feed registry admin
+    RegisterFeed,
+    RemoveFeed,
+    ProposeRegistryAdmin,
+    AcceptRegistryAdmin,
+    // Guardian
+    SetEmergencyHalt,
+    // Vault admin
+    ConfigureCircuitBreaker,
+    ConfigureHedge,
+    SetBalanceIncentive,
+    ConfigureRewardVesting,
+    ConfigureLaunchMode,
+    SetDailyVolumeCap,
+    ConfigureRetainedEarnings,
+    ConfigureVaultFees,
+    // Vault treasury
+    SeedProtocolLiquidity,
+    ProposeVaultTreasury,
+    AcceptVaultTreasury,
+    // Staking pool authority
+    ConfigureSlashing,
+    ProposeSlash,
+    CancelSlash,
+    ExecuteSlash,
+    ProposeStakingAuthority,
+    AcceptStakingAuthority,
+    // Permissionless
+    AttestUpgradeAuthority,
+    // Vault admin (appended to keep earlier discriminants stable)
+    SetMinimumAmounts,
+    // Vault admin
+    ProposeVaultAdmin,
+    AcceptVaultAdmin,
+    AssignRole,
+    RevokeRole,
+    // Pauser
+    SetVaultPauses,
+    // Vault admin
+    SetWindDown,
+    ProposeTokenAccountRotation,
+    CancelTokenAccountRotation,
+    ExecuteTokenAccountRotation,
+    RecoverToken,
+    MigrateVaultLayout,
+    CreateVaultPair,
+    SlashKeeper,
+    SetRebalancePremiumCap,
+    SetTreasuryMint,
+    ConsolidateFees,
+    SetUtilizationFeeCurve,
+    RenameVault,
+    SetTransactionGuard,
+    SetInsuranceFeeShare,
+    SetKeeperLiveness,
+    // Vault treasury
+    WithdrawProtocolLiquidity,
+}
+
+/// One recorded admin action
+/// Scalar settings are logged as-is; keys and multi-field settings are logged as a
+/// fingerprint (first 8 bytes of the SHA-256 of their Borsh encoding)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct AdminLogEntry {
+    pub actor: Pubkey,               // Signer that performed the action
+    pub action: AdminAction,         // What was done
+    pub target: Pubkey,              // Account that was changed
+    pub old_value: u64,              // Setting before the action
+    pub new_value: u64,              // Setting after the action
+    pub timestamp: i64,              // When the action happened
+}
+
+impl AdminLogEntry {
+    pub const LEN: usize = 32 + 1 + 32 + 8 + 8 + 8;
+}
+
+#[account]
+pub struct AdminLog {
+    // Log metadata
+    pub bump: u8,                    // Bump seed for the admin log PDA
+    pub sequence: u64,               // Total actions ever recorded (older ones live on in events)
+    pub upgrade_authority: Option<Pubkey>, // Last attested upgrade authority (None = never attested, default key = immutable)
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next entry is written to
+    pub count: u16,                  // Number of valid entries (up to ADMIN_LOG_LEN)
+    pub entries: [AdminLogEntry; ADMIN_LOG_LEN],
+}
+
+impl AdminLog {
+    pub const LEN: usize = 8 +        // discriminator
+                        1 +           // bump
+                        8 +           // sequence
+                        1 + 32 +      // upgrade_authority
+                        2 +           // head
+                        2 +           // count
+                        AdminLogEntry::LEN * ADMIN_LOG_LEN; // entries
+    
+    /// Appends an entry, overwriting the oldest once the buffer is full
+    pub fn push(&mut self, entry: AdminLogEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = ((self.head as usize + 1) % ADMIN_LOG_LEN) as u16;
+        if (self.count as usize) < ADMIN_LOG_LEN {
+            self.count += 1;
+        }
+        self.sequence = self.sequence.saturating_add(1);
+    }
+} 
\ No newline at end of file
@@ -1,26 +1,144 @@
+use anchor_lang::prelude::Pubkey;
+
 // Seeds for PDAs
 pub const VAULT_ACCOUNT_SEED: &[u8] = b"vault-account";
 pub const LP_POSITION_SEED: &[u8] = b"lp-position";
+pub const POL_POSITION_SEED: &[u8] = b"pol-position";
 pub const VAULT_AUTHORITY_SEED: &[u8] = b"vault-authority";
 pub const REWARD_TRACKER_SEED: &[u8] = b"reward-tracker";
+pub const VESTING_SCHEDULE_SEED: &[u8] = b"vesting-schedule";
+pub const STAKING_POOL_SEED: &[u8] = b"staking-pool";
+pub const STAKE_POSITION_SEED: &[u8] = b"stake-position";
+pub const PRICE_HISTORY_SEED: &[u8] = b"price-history";
+pub const FEED_REGISTRY_SEED: &[u8] = b"feed-registry";
+pub const HEDGE_CONFIG_SEED: &[u8] = b"hedge-config";
+pub const HEDGE_AUTHORITY_SEED: &[u8] = b"hedge-authority";
+pub const PROTOCOL_CONFIG_SEED: &[u8] = b"protocol-config";
+pub const PAIR_CONFIG_SEED: &[u8] = b"pair-config";
+pub const PAIR_STATE_SEED: &[u8] = b"pair-state";
+pub const EMERGENCY_STATE_SEED: &[u8] = b"emergency-state";
+pub const ADMIN_LOG_SEED: &[u8] = b"admin-log";
+pub const VAULT_PAIR_SEED: &[u8] = b"vault-pair";
+pub const USER_STATS_SEED: &[u8] = b"user-stats";
+pub const METRICS_HISTORY_SEED: &[u8] = b"metrics-history";
+pub const KEEPER_REGISTRY_SEED: &[u8] = b"keeper-registry";
+pub const KEEPER_BOND_SEED: &[u8] = b"keeper-bond";
+pub const KEEPER_HEARTBEAT_SEED: &[u8] = b"keeper-heartbeat";
 
 // Math constants
 pub const PRICE_SCALE: u64 = 1_000_000_000; // 10^9 - Oracle price scaling factor
 pub const PRECISION: u64 = 1_000_000_000;   // 10^9 - General precision for calculations
+pub const PRICE_SCALE_DECIMALS: i32 = 9;    // PRICE_SCALE expressed as a power of ten
+
+// Oracle constants
+// Pyth oracle program that must own every price account, selected by cluster feature
+// Localnet builds skip the owner check so tests can use mock price accounts
+#[cfg(all(feature = "devnet", feature = "mainnet"))]
+compile_error!("the devnet and mainnet features are mutually exclusive");
+#[cfg(feature = "mainnet")]
+pub const PYTH_PROGRAM_ID: Option<Pubkey> = Some(anchor_lang::solana_program::pubkey!("FsJ3A3u2vn5cTVP8GpK5yRqH5kdCEjiKRGbbApUMs8uZ"));
+#[cfg(feature = "devnet")]
+pub const PYTH_PROGRAM_ID: Option<Pubkey> = Some(anchor_lang::solana_program::pubkey!("gSbePebfvPy7tRqimPoVecS2UsBvYv46ynrzWocc92s"));
+#[cfg(not(any(feature = "devnet", feature = "mainnet")))]
+pub const PYTH_PROGRAM_ID: Option<Pubkey> = None;
+pub const MAX_ORACLE_STALENESS: i64 = 60;   // Seconds before an oracle price is considered stale
+pub const PRICE_HISTORY_LEN: usize = 64;    // Snapshots kept in each price history ring buffer
+pub const MAX_REGISTERED_FEEDS: usize = 32; // Approved feeds the feed registry can hold
+pub const MAX_DEPRECATED_VAULTS: usize = 32; // Wound-down vaults the feed registry can list
+
+// Account layout constants
+pub const VAULT_NAME_LEN: usize = 32;       // Bytes reserved for a vault's name, keeping later fields at fixed offsets
+
+// Admin log constants
+pub const ADMIN_LOG_LEN: usize = 32;        // Admin actions kept in the admin log ring buffer
 
 // Fee constants
 pub const MIN_SPREAD_BPS: u16 = 3;         // 0.03% minimum spread
 pub const MAX_SPREAD_BPS: u16 = 50;        // 0.5% maximum spread
 
 // Spread formula constants
-pub const SPREAD_SLOPE: f64 = 0.002833;    // 0.2833% slope factor for spread calculation
+pub const DEFAULT_SPREAD_SLOPE_PPM: u32 = 2_833; // 0.2833% slope factor for spread calculation
+pub const MAX_SPREAD_SLOPE_PPM: u32 = 50_000;    // 5% slope factor
+
+// Drift curve defaults (two-slope curve with a kink)
+pub const DEFAULT_DRIFT_START_HEALTH_BPS: u16 = 9000;  // Drift starts below 0.9 health
+pub const DEFAULT_DRIFT_KINK_HEALTH_BPS: u16 = 5000;   // Steep slope below 0.5 health
+pub const DEFAULT_DRIFT_GENTLE_SLOPE_PPM: u32 = 5_000; // 0.5% drift per unit of health above the kink
+pub const DEFAULT_DRIFT_STEEP_SLOPE_PPM: u32 = 20_000; // 2.0% drift per unit of health below the kink
+pub const MAX_DRIFT_SLOPE_PPM: u32 = 100_000;          // 10% drift per unit of health
+pub const MAX_DRIFT_IMPROVEMENT_BPS: u16 = 10;          // 0.1% cap on the price improvement for health-improving flow
+
+// Depth impact constants (quadratic in the share of the target vault a trade takes)
+pub const DEPTH_IMPACT_THRESHOLD_BPS: u64 = 100;    // Trades up to 1% of the target vault pay no impact
+pub const DEPTH_IMPACT_COEFFICIENT_BPS: u64 = 1000; // impact = 10% × (share - threshold)²
+pub const MAX_DEPTH_IMPACT_BPS: u16 = 500;          // 5% maximum depth impact
+
+// Stableswap fallback constants
+pub const MAX_STABLESWAP_AMP: u16 = 1000;                      // Maximum amplification of the fallback curve
+pub const STABLESWAP_FALLBACK_MAX_AGE: i64 = 60 * 60;          // Fallback needs an oracle-priced swap within the last hour
+pub const STABLESWAP_FALLBACK_SPREAD_BPS: u16 = MAX_SPREAD_BPS; // Flat spread charged on fallback swaps
+
+// Vault metrics history constants
+pub const METRICS_HISTORY_LEN: usize = 96;         // Hourly samples kept in each vault metrics ring buffer
+pub const METRICS_SAMPLE_INTERVAL: i64 = 60 * 60;  // Minimum seconds between two metrics samples
+pub const METRICS_DAILY_LEN: usize = 8;            // Daily checkpoints kept for the trailing fee APR
+pub const FEE_APR_WINDOW: i64 = 7 * SECONDS_PER_DAY; // Window the trailing fee APR is measured over
+
+// Utilization fee constants
+pub const UTILIZATION_AVERAGE_WINDOW: i64 = SECONDS_PER_DAY; // Time constant of the trailing average TVL
+pub const DEFAULT_UTILIZATION_FEE_KINK_BPS: u64 = 2000;     // No utilization fee until TVL is 20% below its average
+pub const DEFAULT_UTILIZATION_FEE_MAX_BPS: u16 = 50;        // 0.5% utilization fee at a 50% drawdown and beyond
+pub const MAX_UTILIZATION_FEE_BPS: u16 = 200;               // 2.00% maximum utilization fee
+
+// Insurance carve-out constants
+pub const MAX_INSURANCE_FEE_BPS: u16 = 5000; // At most half of protocol fees go to the insurance fund
+
+// Crank paging constants
+pub const MAX_PUSH_INCENTIVES_PAGE: usize = 16; // Positions one push_incentives call credits within the default compute budget
+
+// Keeper liveness constants
+pub const MAX_DEGRADED_SPREAD_BPS: u16 = 100; // 1.00% maximum extra spread while keepers are silent
 
-// Drift formula constants
-pub const DRIFT_SLOPE: f64 = 0.008333;     // 0.8333% slope factor for drift calculation
+// Payout splitting constants
+pub const MAX_SPLIT_RECIPIENTS: usize = 10; // Recipients one swap_and_split can pay out to
+
+// Rebalancing constants
+pub const MAX_REBALANCE_PRICE_DEVIATION_BPS: u64 = 50; // Rebalancer-quoted price must be within 0.5% of the oracle
+pub const DEFAULT_MAX_REBALANCE_PREMIUM_BPS: u16 = 10; // Rebalancers may get at most 0.1% better than the oracle rate
+pub const MAX_REBALANCE_PREMIUM_BPS: u16 = 50;         // Upper bound for the configurable premium (0.5%)
+pub const REBALANCE_FROM_FEES_HEALTH_BPS: u16 = 5000;  // Health below which accrued PDA fees top up the deficit vault
 
 // Fee allocation constants
 pub const LP_FEE_PERCENT: u8 = 70;         // 70% of fees go to LPs
-// The remaining 30% is split between PDA and Protocol according to vault health tiers 
+// The remaining 30% is split between PDA and Protocol according to the protocol fee curve
+pub const NON_LP_FEE_BPS: u16 = (100 - LP_FEE_PERCENT as u16) * 100; // 30% of fees, in basis points
+pub const HEALTH_AVERAGE_WINDOW: i64 = 5 * 60; // Time constant of the averaged health the split and drift read
+
+// Parameter curve constants
+pub const MAX_CURVE_POINTS: usize = 10;      // Breakpoints a piecewise-linear parameter curve can hold
+pub const MAX_WITHDRAWAL_FEE_BPS: u16 = 1000; // 10.00% maximum time-based withdrawal penalty
+
+// Share issuance constants
+pub const MINIMUM_LIQUIDITY: u64 = 1_000;   // Shares a vault's first deposit locks forever, owned by no position
+
+// Dust constants
+pub const DUST_SWEEP_FLOOR: u64 = 1_000;    // Untracked balance always treated as dust, in token base units
+
+// Token account rotation constants
+pub const TOKEN_ACCOUNT_ROTATION_TIMELOCK: i64 = 2 * SECONDS_PER_DAY; // 48 hours between proposing and executing a rotation
+
+// Balance incentive constants
+pub const MAX_BALANCE_BONUS_BPS: u16 = 100;  // 1.00% maximum bonus on the amount out
+
+// Launch mode constants
+pub const MAX_LAUNCH_SPREAD_BPS: u16 = 500;          // 5.00% maximum spread at launch
+pub const MAX_LAUNCH_WITHDRAWAL_FEE_BPS: u16 = 1000; // 10.00% maximum withdrawal penalty at launch
+
+// Reward vesting constants
+pub const MAX_EARLY_CLAIM_PENALTY_BPS: u16 = 5000;   // 50.00% maximum penalty on unvested rewards
+
+// Staking slashing constants
+pub const MAX_SLASH_BPS: u16 = 5000;                 // 50.00% maximum share of stake slashed at once
 
 // Withdrawal penalty fee schedule (in basis points)
 pub const WITHDRAWAL_FEE_TIER_1: u16 = 200;  // 2.00% if withdrawn within 60 hours
@@ -29,6 +147,14 @@ pub const WITHDRAWAL_FEE_TIER_3: u16 = 100;  // 1.00% if withdrawn within 120-18
 pub const WITHDRAWAL_FEE_TIER_4: u16 = 50;   // 0.50% if withdrawn within 180-240 hours
 pub const WITHDRAWAL_FEE_TIER_5: u16 = 0;    // 0.00% if withdrawn after 240 hours
 
+// Asset-management fee constants
+pub const MAX_MANAGEMENT_FEE_BPS: u16 = 500;         // 5.00% maximum annual management fee
+pub const MAX_PERFORMANCE_FEE_BPS: u16 = 3000;       // 30.00% maximum share of LP yield above the high-water mark
+
+// Time constants
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const SECONDS_PER_YEAR: i64 = 365 * SECONDS_PER_DAY;
+
 // Time thresholds for withdrawal penalties (in seconds)
 pub const HOURS_60_IN_SECONDS: i64 = 60 * 60 * 60;    // 60 hours in seconds
 pub const HOURS_120_IN_SECONDS: i64 = 120 * 60 * 60;  // 120 hours in seconds
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+/// Global kill switch checked by every swap and deposit path
+/// Lives at a fixed PDA so other programs can read it by address
+#[account]
+#[derive(Default)]
+pub struct EmergencyState {
+    pub guardian: Pubkey,            // Key allowed to flip the switch (may be a multisig PDA)
+    pub bump: u8,                    // Bump seed for the emergency state PDA
+    pub halted: bool,                // Swaps and deposits are halted; withdrawals stay open
+    pub last_changed: i64,           // Timestamp the switch was last flipped
+}
+
+impl EmergencyState {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // guardian
+                        1 +           // bump
+                        1 +           // halted
+                        8;            // last_changed
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::{MAX_REGISTERED_FEEDS, MAX_DEPRECATED_VAULTS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeedEntry {
+    pub base_mint: Pubkey,           // Mint of the currency being priced
+    pub quote_mint: Pubkey,          // Mint of the currency the price is quoted in
+    pub oracle: Pubkey,              // Approved oracle price account for the pair
+}
+
+impl FeedEntry {
+    pub const LEN: usize = 32 + 32 + 32;
+}
+
+#[account]
+#[derive(Default)]
+pub struct FeedRegistry {
+    // Registry metadata
+    pub admin: Pubkey,               // Admin allowed to approve and remove feeds (may be a multisig PDA)
+    pub pending_admin: Pubkey,       // Proposed new admin awaiting acceptance (default = none)
+    pub bump: u8,                    // Bump seed for the feed registry PDA
+    
+    // Approved feeds
+    pub feeds: Vec<FeedEntry>,       // Approved oracle per mint pair
+    
+    // Retired corridors
+    pub deprecated_vaults: Vec<Pubkey>, // Vaults in permanent wind-down
+}
+
+impl FeedRegistry {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // admin
+                        32 +          // pending_admin
+                        1 +           // bump
+                        4 + FeedEntry::LEN * MAX_REGISTERED_FEEDS + // feeds
+                        4 + 32 * MAX_DEPRECATED_VAULTS; // deprecated_vaults
+    
+    /// Returns true if the oracle is approved for the given base mint
+    pub fn is_approved(&self, base_mint: &Pubkey, oracle: &Pubkey) -> bool {
+        self.feeds
+            .iter()
+            .any(|feed| feed.base_mint == *base_mint && feed.oracle == *oracle)
+    }
+    
+    /// Returns true if the vault has been wound down
+    pub fn is_deprecated(&self, vault: &Pubkey) -> bool {
+        self.deprecated_vaults.contains(vault)
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct HedgeConfig {
+    // Hedge metadata
+    pub vault: Pubkey,               // Vault whose net exposure is hedged
+    pub bump: u8,                    // Bump seed for the hedge config PDA
+    pub authority_bump: u8,          // Bump seed for the hedge authority PDA that holds collateral
+    
+    // Admin-configured parameters
+    pub perp_program: Pubkey,        // Whitelisted perp program hedges are placed on
+    pub keeper: Pubkey,              // Keeper allowed to open and adjust hedges
+    pub exposure_threshold: u64,     // Net exposure that must be reached before the hedge grows
+    pub max_hedge_notional: u64,     // Maximum absolute hedge size
+    
+    // Hedge state
+    pub hedge_notional: i64,         // Current hedge (negative = short the vault currency)
+    pub last_hedge_time: i64,        // Timestamp of the last adjustment
+    pub hedge_adjustments: u64,      // Number of adjustments made
+}
+
+impl HedgeConfig {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // vault
+                        1 +           // bump
+                        1 +           // authority_bump
+                        32 +          // perp_program
+                        32 +          // keeper
+                        8 +           // exposure_threshold
+                        8 +           // max_hedge_notional
+                        8 +           // hedge_notional
+                        8 +           // last_hedge_time
+                        8;            // hedge_adjustments
+} 
\ No newline at end of file
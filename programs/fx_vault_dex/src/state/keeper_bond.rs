@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct KeeperBond {
+    pub keeper: Pubkey,                  // Operator the bond belongs to
+    pub bump: u8,                        // Bump seed for the keeper bond PDA
+    pub bonded_amount: u64,              // Tokens bonded and counting toward the minimum
+    pub unbonding_amount: u64,           // Tokens in the unbond cooldown
+    pub unbond_request_time: i64,        // Timestamp of the latest unbond request
+    pub total_slashed: u64,              // Tokens slashed from this keeper
+}
+
+impl KeeperBond {
+    pub const LEN: usize = 8 +           // discriminator
+                          32 +            // keeper
+                          1 +             // bump
+                          8 +             // bonded_amount
+                          8 +             // unbonding_amount
+                          8 +             // unbond_request_time
+                          8;              // total_slashed
+    
+    /// Whether the keeper has bonded enough to call keeper-gated instructions
+    pub fn is_active(&self, min_bond: u64) -> bool {
+        min_bond > 0 && self.bonded_amount >= min_bond
+    }
+} 
\ No newline at end of file
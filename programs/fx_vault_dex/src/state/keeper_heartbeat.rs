@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct KeeperHeartbeat {
+    pub bump: u8,                        // Bump seed for the heartbeat PDA
+    pub last_keeper: Pubkey,             // Bonded keeper that sent the latest heartbeat
+    pub last_heartbeat_time: i64,        // Timestamp of the latest heartbeat
+    pub heartbeat_count: u64,            // Heartbeats received from all keepers
+}
+
+impl KeeperHeartbeat {
+    pub const LEN: usize = 8 +           // discriminator
+                          1 +             // bump
+                          32 +            // last_keeper
+                          8 +             // last_heartbeat_time
+                          8;              // heartbeat_count
+    
+    /// Whether some keeper has checked in within the liveness window
+    /// A window of 0 disables monitoring, so keepers always count as live
+    pub fn is_live(&self, current_time: i64, liveness_window: i64) -> bool {
+        liveness_window == 0 || current_time.saturating_sub(self.last_heartbeat_time) <= liveness_window
+    }
+} 
\ No newline at end of file
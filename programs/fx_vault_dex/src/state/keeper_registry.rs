@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct KeeperRegistry {
+    // Registry metadata
+    pub authority: Pubkey,               // Governance key that can slash keepers
+    pub bond_mint: Pubkey,               // Mint of the protocol token keepers bond
+    pub bond_vault: Pubkey,              // Token account holding bonded tokens
+    pub bump: u8,                        // Bump seed for the registry PDA (signs for the bond vault)
+    
+    // Bonding parameters
+    pub min_bond: u64,                   // Bond a keeper needs to call keeper-gated instructions
+    pub unbond_cooldown: i64,            // Seconds between unbond request and withdrawal
+    
+    // Registry financials
+    pub total_bonded: u64,               // Tokens bonded by active keepers
+    pub total_unbonding: u64,            // Tokens in the unbond cooldown (still slashable)
+    pub total_slashed: u64,              // Total tokens slashed from keepers
+}
+
+impl KeeperRegistry {
+    pub const LEN: usize = 8 +           // discriminator
+                          32 +            // authority
+                          32 +            // bond_mint
+                          32 +            // bond_vault
+                          1 +             // bump
+                          8 +             // min_bond
+                          8 +             // unbond_cooldown
+                          8 +             // total_bonded
+                          8 +             // total_unbonding
+                          8;              // total_slashed
+} 
\ No newline at end of file
@@ -7,9 +7,12 @@ pub struct LPPosition {
     pub owner: Pubkey,               // Owner of this LP position
     pub vault: Pubkey,               // Vault this position belongs to
     pub bump: u8,                    // Bump seed for the LP position PDA
+    pub is_protocol: bool,           // Protocol-owned liquidity seeded from the treasury
+    pub penalty_exempt: bool,        // Withdrawal penalties waived by the admin (e.g. market makers)
+    pub manager: Pubkey,             // Delegate that may deposit and claim (default = none)
     
     // LP position details
-    pub amount: u64,                 // Amount of tokens deposited
+    pub shares: u64,                 // Vault shares held, redeemable at the vault's exchange rate
     pub last_deposit_time: i64,      // Timestamp of the last deposit
     
     // Rewards tracking
@@ -22,8 +25,40 @@ impl LPPosition {
                         32 +          // owner
                         32 +          // vault
                         1 +           // bump
-                        8 +           // amount
+                        1 +           // is_protocol
+                        1 +           // penalty_exempt
+                        32 +          // manager
+                        8 +           // shares
                         8 +           // last_deposit_time
                         8 +           // rewards_claimed
                         8;            // last_rewards_claim_time
+    
+    /// Returns true if the key is the owner or the position's manager
+    pub fn is_owner_or_manager(&self, key: &Pubkey) -> bool {
+        self.owner == *key || (self.manager != Pubkey::default() && self.manager == *key)
+    }
+    
+    /// Returns true if withdrawals from this position are never penalized
+    pub fn is_penalty_exempt(&self) -> bool {
+        self.is_protocol || self.penalty_exempt
+    }
+    
+    /// Adds a tranche of shares moved in from another position
+    /// The deposit time becomes the share-weighted average so early withdrawal
+    /// penalties keep tracking the age of each tranche
+    pub fn absorb_tranche(&mut self, shares: u64, deposit_time: i64) -> Option<()> {
+        let new_shares = self.shares.checked_add(shares)?;
+        if new_shares == 0 {
+            return Some(());
+        }
+        
+        let weighted_time = (self.shares as i128)
+            .checked_mul(self.last_deposit_time as i128)?
+            .checked_add((shares as i128).checked_mul(deposit_time as i128)?)?
+            / new_shares as i128;
+        
+        self.shares = new_shares;
+        self.last_deposit_time = i64::try_from(weighted_time).ok()?;
+        Some(())
+    }
 } 
\ No newline at end of file
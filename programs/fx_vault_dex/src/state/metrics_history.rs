@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::{METRICS_HISTORY_LEN, METRICS_DAILY_LEN, FEE_APR_WINDOW, SECONDS_PER_DAY, SECONDS_PER_YEAR};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct MetricsSample {
+    pub timestamp: i64,              // Time the sample was taken
+    pub tvl: u64,                    // Vault TVL
+    pub health_bps: u16,             // Health against the counter vault (scaled by 10000)
+    pub cumulative_fees: u64,        // Vault's cumulative swap fees at the time
+}
+
+impl MetricsSample {
+    pub const LEN: usize = 8 + 8 + 2 + 8;
+}
+
+#[account]
+pub struct MetricsHistory {
+    // History metadata
+    pub vault: Pubkey,               // Vault whose metrics are being recorded
+    pub counter_vault: Pubkey,       // Paired vault health is measured against
+    pub bump: u8,                    // Bump seed for the metrics history PDA
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next sample is written to
+    pub count: u16,                  // Number of valid samples (up to METRICS_HISTORY_LEN)
+    pub samples: [MetricsSample; METRICS_HISTORY_LEN],
+    
+    // Daily checkpoints for the trailing fee APR
+    pub daily_head: u16,             // Index the next checkpoint is written to
+    pub daily_count: u16,            // Number of valid checkpoints (up to METRICS_DAILY_LEN)
+    pub daily_samples: [MetricsSample; METRICS_DAILY_LEN],
+    pub trailing_7d_fee_apr_bps: u32, // Annualized swap fees over TVL for the last seven days
+}
+
+impl MetricsHistory {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // vault
+                        32 +          // counter_vault
+                        1 +           // bump
+                        2 +           // head
+                        2 +           // count
+                        MetricsSample::LEN * METRICS_HISTORY_LEN + // samples
+                        2 +           // daily_head
+                        2 +           // daily_count
+                        MetricsSample::LEN * METRICS_DAILY_LEN + // daily_samples
+                        4;            // trailing_7d_fee_apr_bps
+    
+    /// Returns the most recent sample, if any
+    pub fn latest(&self) -> Option<MetricsSample> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + METRICS_HISTORY_LEN - 1) % METRICS_HISTORY_LEN;
+        Some(self.samples[index])
+    }
+    
+    /// Appends a sample, overwriting the oldest once the buffer is full
+    /// Also checkpoints the sample once a day and refreshes the trailing fee APR
+    pub fn push(&mut self, sample: MetricsSample) {
+        self.samples[self.head as usize] = sample;
+        self.head = ((self.head as usize + 1) % METRICS_HISTORY_LEN) as u16;
+        if (self.count as usize) < METRICS_HISTORY_LEN {
+            self.count += 1;
+        }
+        
+        let checkpoint_due = match self.daily_sample(0) {
+            Some(latest) => sample.timestamp >= latest.timestamp.saturating_add(SECONDS_PER_DAY),
+            None => true,
+        };
+        if checkpoint_due {
+            self.daily_samples[self.daily_head as usize] = sample;
+            self.daily_head = ((self.daily_head as usize + 1) % METRICS_DAILY_LEN) as u16;
+            if (self.daily_count as usize) < METRICS_DAILY_LEN {
+                self.daily_count += 1;
+            }
+        }
+        
+        self.trailing_7d_fee_apr_bps = self.trailing_fee_apr_bps(&sample);
+    }
+    
+    /// Returns the daily checkpoint `age` checkpoints before the latest one
+    pub fn daily_sample(&self, age: usize) -> Option<MetricsSample> {
+        if age >= self.daily_count as usize {
+            return None;
+        }
+        let index = (self.daily_head as usize + METRICS_DAILY_LEN - 1 - age) % METRICS_DAILY_LEN;
+        Some(self.daily_samples[index])
+    }
+    
+    /// Annualized fees over average TVL since the newest checkpoint at least FEE_APR_WINDOW old,
+    /// or since the oldest checkpoint while the history is younger than that
+    /// apr = fees × SECONDS_PER_YEAR × 10000 / (elapsed × average TVL)
+    fn trailing_fee_apr_bps(&self, sample: &MetricsSample) -> u32 {
+        let start = (0..self.daily_count as usize)
+            .filter_map(|age| self.daily_sample(age))
+            .find(|checkpoint| sample.timestamp.saturating_sub(checkpoint.timestamp) >= FEE_APR_WINDOW)
+            .or_else(|| self.daily_sample(self.daily_count.saturating_sub(1) as usize));
+        let start = match start {
+            Some(start) => start,
+            None => return 0,
+        };
+        
+        let elapsed = sample.timestamp.saturating_sub(start.timestamp);
+        let average_tvl = (start.tvl as u128 + sample.tvl as u128) / 2;
+        if elapsed <= 0 || average_tvl == 0 {
+            return 0;
+        }
+        
+        let fees = sample.cumulative_fees.saturating_sub(start.cumulative_fees) as u128;
+        let apr = fees * SECONDS_PER_YEAR as u128 * 10000 / (elapsed as u128 * average_tvl);
+        apr.min(u32::MAX as u128) as u32
+    }
+} 
\ No newline at end of file
@@ -2,8 +2,44 @@ pub mod constants;
 pub mod vault_account;
 pub mod lp_position;
 pub mod reward_tracker;
+pub mod vesting_schedule;
+pub mod staking_pool;
+pub mod stake_position;
+pub mod price_history;
+pub mod feed_registry;
+pub mod hedge_config;
+pub mod protocol_config;
+pub mod pair_config;
+pub mod pair_state;
+pub mod emergency_state;
+pub mod admin_log;
+pub mod roles;
+pub mod vault_pair;
+pub mod user_stats;
+pub mod metrics_history;
+pub mod keeper_registry;
+pub mod keeper_bond;
+pub mod keeper_heartbeat;
 
 pub use constants::*;
 pub use vault_account::*;
 pub use lp_position::*;
-pub use reward_tracker::*; 
\ No newline at end of file
+pub use reward_tracker::*;
+pub use vesting_schedule::*;
+pub use staking_pool::*;
+pub use stake_position::*;
+pub use price_history::*;
+pub use feed_registry::*;
+pub use hedge_config::*;
+pub use protocol_config::*;
+pub use pair_config::*;
+pub use pair_state::*;
+pub use emergency_state::*;
+pub use admin_log::*;
+pub use roles::*;
+pub use vault_pair::*;
+pub use user_stats::*;
+pub use metrics_history::*;
+pub use keeper_registry::*;
+pub use keeper_bond::*;
+pub use keeper_heartbeat::*; 
\ No newline at end of file
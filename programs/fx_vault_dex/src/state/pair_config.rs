@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::{DriftCurve, ProtocolConfig};
+
+#[account]
+#[derive(Default)]
+pub struct PairConfig {
+    // Pair identification (mints are stored in ascending order)
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub bump: u8,                    // Bump seed for the pair config PDA
+    
+    // Pricing overrides, falling back to the protocol config when unset
+    pub spread_slope_ppm: u32,       // Spread slope override (0 = use the global slope)
+    pub drift_curve: Option<DriftCurve>, // Drift curve override (None = use the global curve)
+    
+    // Oracle outage behavior
+    pub stableswap_amp: u16,         // Fallback curve amplification when oracles are stale (0 = halt)
+    
+    // Best-execution guardrail
+    pub max_execution_deviation_bps: u16, // Max shortfall of the effective rate below the oracle mid (0 = no cap)
+}
+
+impl PairConfig {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // mint_a
+                        32 +          // mint_b
+                        1 +           // bump
+                        4 +           // spread_slope_ppm
+                        1 + DriftCurve::LEN + // drift_curve
+                        2 +           // stableswap_amp
+                        2;            // max_execution_deviation_bps
+    
+    /// Loads the pair config from its PDA, or None if no overrides were ever set
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*account.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        
+        let data = account.try_borrow_data()?;
+        Ok(Some(Self::try_deserialize(&mut &data[..])?))
+    }
+    
+    /// Returns the spread slope (ppm) and drift curve that apply to the pair
+    pub fn resolve(pair_config: Option<&Self>, protocol_config: &ProtocolConfig) -> (u32, DriftCurve) {
+        match pair_config {
+            Some(pair) => (
+                if pair.spread_slope_ppm > 0 { pair.spread_slope_ppm } else { protocol_config.spread_slope_ppm },
+                pair.drift_curve.unwrap_or(protocol_config.drift_curve),
+            ),
+            None => (protocol_config.spread_slope_ppm, protocol_config.drift_curve),
+        }
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use crate::state::{PRICE_SCALE, HEALTH_AVERAGE_WINDOW};
+
+#[account]
+#[derive(Default)]
+pub struct PairState {
+    // Pair identification (mints are stored in ascending order)
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub bump: u8,                    // Bump seed for the pair state PDA
+    
+    // Latest oracle price, whichever direction the last swap went
+    pub last_price: u64,             // Price of mint_a in units of mint_b, scaled by 10^9
+    pub publish_slot_a: u64,         // Publish slot of mint_a's oracle price
+    pub publish_slot_b: u64,         // Publish slot of mint_b's oracle price
+    pub last_update_slot: u64,       // Slot of the last price update
+    pub last_update_timestamp: i64,  // Timestamp of the last price update
+    
+    // TWAP accumulator
+    pub price_cumulative: u128,      // Sum of last_price × seconds it was current
+    pub first_update_timestamp: i64, // Timestamp the accumulator started
+    
+    // Averaged vault health
+    pub health_average_bps: u64,     // Trailing average of the pair's vault health, in basis points
+    pub health_average_time: i64,    // Timestamp the average was last moved
+}
+
+impl PairState {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // mint_a
+                        32 +          // mint_b
+                        1 +           // bump
+                        8 +           // last_price
+                        8 +           // publish_slot_a
+                        8 +           // publish_slot_b
+                        8 +           // last_update_slot
+                        8 +           // last_update_timestamp
+                        16 +          // price_cumulative
+                        8 +           // first_update_timestamp
+                        8 +           // health_average_bps
+                        8;            // health_average_time
+    
+    /// Sets the pair's mints on first use, in ascending order
+    pub fn init_if_needed(&mut self, mint_x: Pubkey, mint_y: Pubkey, bump: u8) {
+        if self.mint_a == Pubkey::default() {
+            self.mint_a = mint_x.min(mint_y);
+            self.mint_b = mint_x.max(mint_y);
+            self.bump = bump;
+        }
+    }
+    
+    /// Records the oracle prices used by a swap between the pair's vaults
+    /// Each side is its vault's (oracle price, publish slot), with prices in a common
+    /// quote currency scaled by PRICE_SCALE
+    pub fn record_prices(
+        &mut self,
+        source_mint: Pubkey,
+        source: (u64, u64),
+        target: (u64, u64),
+        slot: u64,
+        timestamp: i64,
+    ) -> Option<()> {
+        let ((price_a, slot_a), (price_b, slot_b)) = if source_mint == self.mint_a {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        
+        // Accumulate the outgoing price over the time it was current
+        self.price_cumulative = self.cumulative_price_at(timestamp)?;
+        if self.first_update_timestamp == 0 {
+            self.first_update_timestamp = timestamp;
+        }
+        
+        let price = (price_a as u128).checked_mul(PRICE_SCALE as u128)? / (price_b as u128);
+        self.last_price = u64::try_from(price).ok()?;
+        self.publish_slot_a = slot_a;
+        self.publish_slot_b = slot_b;
+        self.last_update_slot = slot;
+        self.last_update_timestamp = timestamp;
+        Some(())
+    }
+    
+    /// Moves the trailing average health towards the current health by the time elapsed since
+    /// the last update, weighted against HEALTH_AVERAGE_WINDOW
+    /// Returns the updated average, which a single trade can only nudge
+    pub fn update_health_average(&mut self, health_bps: u64, timestamp: i64) -> u64 {
+        if self.health_average_time == 0 {
+            self.health_average_bps = health_bps;
+            self.health_average_time = timestamp;
+            return health_bps;
+        }
+        
+        let elapsed = timestamp.saturating_sub(self.health_average_time).clamp(0, HEALTH_AVERAGE_WINDOW);
+        let delta = health_bps as i128 - self.health_average_bps as i128;
+        let average = self.health_average_bps as i128 + delta * elapsed as i128 / HEALTH_AVERAGE_WINDOW as i128;
+        
+        self.health_average_bps = average as u64;
+        self.health_average_time = timestamp;
+        self.health_average_bps
+    }
+    
+    /// Returns the last price of the given mint in units of the other mint, scaled by PRICE_SCALE
+    /// None until the pair has recorded a price
+    pub fn price_of(&self, base_mint: Pubkey) -> Option<u64> {
+        if self.last_price == 0 {
+            return None;
+        }
+        if base_mint == self.mint_a {
+            return Some(self.last_price);
+        }
+        
+        let inverse = (PRICE_SCALE as u128 * PRICE_SCALE as u128) / self.last_price as u128;
+        u64::try_from(inverse).ok()
+    }
+    
+    /// Returns the accumulator projected to the given time at the current price
+    /// A TWAP over any window is the difference of two readings divided by the elapsed seconds
+    pub fn cumulative_price_at(&self, timestamp: i64) -> Option<u128> {
+        if self.last_update_timestamp == 0 {
+            return Some(self.price_cumulative);
+        }
+        
+        let elapsed = timestamp.saturating_sub(self.last_update_timestamp).max(0) as u128;
+        self.price_cumulative.checked_add((self.last_price as u128).checked_mul(elapsed)?)
+    }
+    
+    /// Returns the time-weighted average price since the accumulator started
+    pub fn lifetime_twap(&self, timestamp: i64) -> Option<u64> {
+        let elapsed = timestamp.checked_sub(self.first_update_timestamp)?;
+        if self.first_update_timestamp == 0 || elapsed <= 0 {
+            return Some(self.last_price);
+        }
+        
+        u64::try_from(self.cumulative_price_at(timestamp)? / elapsed as u128).ok()
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::PRICE_HISTORY_LEN;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PriceSnapshot {
+    pub price: u64,                  // Oracle price scaled by PRICE_SCALE
+    pub conf: u64,                   // Oracle confidence scaled by PRICE_SCALE
+    pub timestamp: i64,              // Oracle publish time
+}
+
+impl PriceSnapshot {
+    pub const LEN: usize = 8 + 8 + 8;
+}
+
+#[account]
+pub struct PriceHistory {
+    // History metadata
+    pub vault: Pubkey,               // Vault whose oracle is being recorded
+    pub bump: u8,                    // Bump seed for the price history PDA
+    
+    // Ring buffer
+    pub head: u16,                   // Index the next snapshot is written to
+    pub count: u16,                  // Number of valid snapshots (up to PRICE_HISTORY_LEN)
+    pub snapshots: [PriceSnapshot; PRICE_HISTORY_LEN],
+}
+
+impl PriceHistory {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // vault
+                        1 +           // bump
+                        2 +           // head
+                        2 +           // count
+                        PriceSnapshot::LEN * PRICE_HISTORY_LEN; // snapshots
+    
+    /// Returns the most recent snapshot, if any
+    pub fn latest(&self) -> Option<PriceSnapshot> {
+        if self.count == 0 {
+            return None;
+        }
+        let index = (self.head as usize + PRICE_HISTORY_LEN - 1) % PRICE_HISTORY_LEN;
+        Some(self.snapshots[index])
+    }
+    
+    /// Appends a snapshot, overwriting the oldest once the buffer is full
+    pub fn push(&mut self, snapshot: PriceSnapshot) {
+        self.snapshots[self.head as usize] = snapshot;
+        self.head = ((self.head as usize + 1) % PRICE_HISTORY_LEN) as u16;
+        if (self.count as usize) < PRICE_HISTORY_LEN {
+            self.count += 1;
+        }
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::*;
+
+/// Two-slope drift curve: gentle between the start health and the kink, steep below the kink
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct DriftCurve {
+    pub start_health_bps: u16,       // Health below which drift starts (scaled by 10000)
+    pub kink_health_bps: u16,        // Health below which the steep slope applies
+    pub gentle_slope_ppm: u32,       // Drift per unit of health lost above the kink (parts per million)
+    pub steep_slope_ppm: u32,        // Drift per unit of health lost below the kink (parts per million)
+}
+
+impl DriftCurve {
+    pub const LEN: usize = 2 + 2 + 4 + 4;
+    
+    /// Returns true if the kink lies below the start health and the slopes are ordered and bounded
+    pub fn is_valid(&self) -> bool {
+        self.start_health_bps <= 10000
+            && self.kink_health_bps <= self.start_health_bps
+            && self.gentle_slope_ppm <= self.steep_slope_ppm
+            && self.steep_slope_ppm <= MAX_DRIFT_SLOPE_PPM
+    }
+}
+
+impl Default for DriftCurve {
+    fn default() -> Self {
+        Self {
+            start_health_bps: DEFAULT_DRIFT_START_HEALTH_BPS,
+            kink_health_bps: DEFAULT_DRIFT_KINK_HEALTH_BPS,
+            gentle_slope_ppm: DEFAULT_DRIFT_GENTLE_SLOPE_PPM,
+            steep_slope_ppm: DEFAULT_DRIFT_STEEP_SLOPE_PPM,
+        }
+    }
+}
+
+/// A single breakpoint of a piecewise-linear parameter curve
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CurvePoint {
+    pub x: u64,                      // Input value (vault health in bps, seconds, ...)
+    pub y: u16,                      // Output value in basis points
+}
+
+impl CurvePoint {
+    pub const LEN: usize = 8 + 2;
+}
+
+/// Piecewise-linear curve interpolated between breakpoints sorted by x
+/// Inputs outside the first and last breakpoints are clamped to the end values
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ParameterCurve {
+    pub points: Vec<CurvePoint>,     // Breakpoints in strictly increasing x order
+}
+
+impl ParameterCurve {
+    pub const LEN: usize = 4 + CurvePoint::LEN * MAX_CURVE_POINTS;
+    
+    /// Returns true if the curve has between 1 and MAX_CURVE_POINTS breakpoints,
+    /// strictly increasing in x, with every output at most max_y
+    pub fn is_valid(&self, max_y: u16) -> bool {
+        !self.points.is_empty()
+            && self.points.len() <= MAX_CURVE_POINTS
+            && self.points.windows(2).all(|pair| pair[0].x < pair[1].x)
+            && self.points.iter().all(|point| point.y <= max_y)
+    }
+    
+    /// Protocol share of swap fees (bps of the fee) by vault health (bps)
+    /// Adjacent breakpoints reproduce the original tiers:
+    /// 15% above 0.70, 10% above 0.50, 5% above 0.30, 0% otherwise
+    pub fn default_protocol_fee_curve() -> Self {
+        Self {
+            points: vec![
+                CurvePoint { x: 3000, y: 0 },
+                CurvePoint { x: 3001, y: 500 },
+                CurvePoint { x: 5000, y: 500 },
+                CurvePoint { x: 5001, y: 1000 },
+                CurvePoint { x: 7000, y: 1000 },
+                CurvePoint { x: 7001, y: 1500 },
+            ],
+        }
+    }
+    
+    /// Extra swap fee (bps) by how far a vault's TVL is below its trailing average (bps)
+    /// No fee up to a 20% drawdown, rising linearly to 0.5% at a 50% drawdown
+    pub fn default_utilization_fee_curve() -> Self {
+        Self {
+            points: vec![
+                CurvePoint { x: DEFAULT_UTILIZATION_FEE_KINK_BPS, y: 0 },
+                CurvePoint { x: 5000, y: DEFAULT_UTILIZATION_FEE_MAX_BPS },
+            ],
+        }
+    }
+    
+    /// Withdrawal penalty (bps) by seconds since the last deposit
+    /// Adjacent breakpoints reproduce the original 60/120/180/240 hour tiers
+    pub fn default_withdrawal_fee_curve() -> Self {
+        let tiers = [
+            (HOURS_60_IN_SECONDS, WITHDRAWAL_FEE_TIER_1, WITHDRAWAL_FEE_TIER_2),
+            (HOURS_120_IN_SECONDS, WITHDRAWAL_FEE_TIER_2, WITHDRAWAL_FEE_TIER_3),
+            (HOURS_180_IN_SECONDS, WITHDRAWAL_FEE_TIER_3, WITHDRAWAL_FEE_TIER_4),
+            (HOURS_240_IN_SECONDS, WITHDRAWAL_FEE_TIER_4, WITHDRAWAL_FEE_TIER_5),
+        ];
+        
+        let mut points = Vec::with_capacity(tiers.len() * 2);
+        for (threshold, fee_before, fee_after) in tiers {
+            points.push(CurvePoint { x: threshold as u64 - 1, y: fee_before });
+            points.push(CurvePoint { x: threshold as u64, y: fee_after });
+        }
+        
+        Self { points }
+    }
+}
+
+/// Destinations for withdrawal penalties, in basis points of the penalty
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PenaltySplit {
+    pub lp_bps: u16,                 // Left in the vault for the remaining LPs
+    pub insurance_bps: u16,          // Sent to the insurance fund
+    pub treasury_bps: u16,           // Sent to the vault's PDA treasury
+}
+
+impl PenaltySplit {
+    pub const LEN: usize = 2 + 2 + 2;
+    
+    /// Returns true if the shares add up to the whole penalty
+    pub fn is_valid(&self) -> bool {
+        self.lp_bps as u32 + self.insurance_bps as u32 + self.treasury_bps as u32 == 10000
+    }
+    
+    /// Splits a penalty into (lp, insurance, treasury) amounts
+    /// Rounding dust goes to the treasury
+    pub fn split(&self, amount: u64) -> (u64, u64, u64) {
+        let lp_amount = (amount as u128 * self.lp_bps as u128 / 10000) as u64;
+        let insurance_amount = (amount as u128 * self.insurance_bps as u128 / 10000) as u64;
+        (lp_amount, insurance_amount, amount - lp_amount - insurance_amount)
+    }
+}
+
+impl Default for PenaltySplit {
+    fn default() -> Self {
+        Self {
+            lp_bps: 0,
+            insurance_bps: 0,
+            treasury_bps: 10000,
+        }
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct ProtocolConfig {
+    // Config metadata
+    pub admin: Pubkey,               // Admin allowed to update protocol parameters (may be a multisig PDA)
+    pub pending_admin: Pubkey,       // Proposed new admin awaiting acceptance (default = none)
+    pub bump: u8,                    // Bump seed for the protocol config PDA
+    
+    // Pricing parameters
+    pub spread_slope_ppm: u32,       // Spread increase per unit of health below 0.9 (parts per million)
+    pub drift_curve: DriftCurve,     // Drift applied to swaps as vault health deteriorates
+    pub protocol_fee_curve: ParameterCurve,   // Protocol share of swap fees by vault health
+    pub withdrawal_fee_curve: ParameterCurve, // Withdrawal penalty by time since deposit
+    
+    // Withdrawal penalty routing
+    pub penalty_split: PenaltySplit, // How withdrawal penalties are shared out
+    pub insurance_fund: Pubkey,      // Owner of the insurance fund token accounts
+    
+    // Rebalancing
+    pub max_rebalance_premium_bps: u16, // Best rate a rebalancer may take over the oracle rate
+    
+    // Fee consolidation
+    pub treasury_mint: Pubkey,       // Currency protocol fees are consolidated into (default = none)
+    
+    // Utilization fee
+    pub utilization_fee_curve: ParameterCurve, // Extra swap fee by target vault drawdown below its trailing average
+    
+    // Insurance carve-out
+    pub insurance_fee_bps: u16,      // Share of protocol fees sent to the insurance fund on distribution
+    
+    // Keeper liveness
+    pub keeper_liveness_window: i64, // Seconds without a keeper heartbeat before swaps degrade (0 = off)
+    pub degraded_spread_bps: u16,    // Extra swap spread while keepers are silent
+    pub degraded_max_trade_bps: u16, // Largest swap output, in bps of target TVL, while keepers are silent (0 = no limit)
+    
+    // Staking
+    pub staking_pool: Pubkey,        // Only staking pool protocol fees may be routed to (default = none)
+    pub staker_fee_share_bps: u16,   // Share of protocol fees after the insurance carve-out routed to stakers
+}
+
+impl ProtocolConfig {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // admin
+                        32 +          // pending_admin
+                        1 +           // bump
+                        4 +           // spread_slope_ppm
+                        DriftCurve::LEN + // drift_curve
+                        ParameterCurve::LEN + // protocol_fee_curve
+                        ParameterCurve::LEN + // withdrawal_fee_curve
+                        PenaltySplit::LEN + // penalty_split
+                        32 +          // insurance_fund
+                        2 +           // max_rebalance_premium_bps
+                        32 +          // treasury_mint
+                        ParameterCurve::LEN + // utilization_fee_curve
+                        2 +           // insurance_fee_bps
+                        8 +           // keeper_liveness_window
+                        2 +           // degraded_spread_bps
+                        2 +           // degraded_max_trade_bps
+                        32 +          // staking_pool
+                        2;            // staker_fee_share_bps
+} 
\ No newline at end of file
@@ -12,6 +12,11 @@ pub struct RewardTracker {
     pub total_deposits: u64,         // Total deposits at last update
     pub reward_index: u64,           // Current reward index (scaled by PRECISION)
     pub last_update_time: i64,       // Last time rewards were updated
+    
+    // Push crank pagination
+    pub push_cursor: Pubkey,         // Last LP position credited in the current pass (default = pass not started)
+    pub push_pass: u64,              // Number of completed passes over all positions
+    pub pass_started_at: i64,        // Timestamp of the first page of the current pass
 }
 
 impl RewardTracker {
@@ -21,5 +26,8 @@ impl RewardTracker {
                          8 +         // total_rewards
                          8 +         // total_deposits
                          8 +         // reward_index
-                         8;          // last_update_time
+                         8 +         // last_update_time
+                         32 +        // push_cursor
+                         8 +         // push_pass
+                         8;          // pass_started_at
 } 
\ No newline at end of file
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+/// Capabilities the vault admin can delegate to separate keys
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    FeeManager,                      // Distributes accrued protocol and PDA fees
+    Pauser,                          // Pauses and resumes vault operations
+    Rebalancer,                      // Injects liquidity to rebalance the vault
+}
+
+/// Per-vault role assignments
+/// An unassigned role (Pubkey::default()) falls back to the vault's default holder
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct Roles {
+    pub fee_manager: Pubkey,         // Fee distribution (default = vault admin)
+    pub pauser: Pubkey,              // Operation pauses (default = vault admin)
+    pub rebalancer: Pubkey,          // Rebalancing (default = PDA treasury)
+}
+
+impl Roles {
+    pub const LEN: usize = 32 +       // fee_manager
+                        32 +          // pauser
+                        32;           // rebalancer
+    
+    /// Returns the key assigned to the role, or Pubkey::default() if unassigned
+    pub fn get(&self, role: Role) -> Pubkey {
+        match role {
+            Role::FeeManager => self.fee_manager,
+            Role::Pauser => self.pauser,
+            Role::Rebalancer => self.rebalancer,
+        }
+    }
+    
+    /// Assigns the role to a key; Pubkey::default() revokes it
+    pub fn set(&mut self, role: Role, key: Pubkey) {
+        match role {
+            Role::FeeManager => self.fee_manager = key,
+            Role::Pauser => self.pauser = key,
+            Role::Rebalancer => self.rebalancer = key,
+        }
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::constants::PRECISION;
+
+#[account]
+#[derive(Default)]
+pub struct StakePosition {
+    // Stake metadata
+    pub owner: Pubkey,               // Owner of this stake position
+    pub staking_pool: Pubkey,        // Staking pool this position belongs to
+    pub bump: u8,                    // Bump seed for the stake position PDA
+    
+    // Stake details
+    pub amount: u64,                 // Tokens staked and earning rewards
+    pub unstaking_amount: u64,       // Tokens in the unstake cooldown
+    pub unstake_request_time: i64,   // Timestamp of the last unstake request
+    
+    // Rewards tracking
+    pub reward_index_checkpoint: u128, // Pool reward index at the last settlement
+    pub pending_rewards: u64,        // Settled rewards not yet claimed
+    pub rewards_claimed: u64,        // Total rewards claimed by this staker
+    
+    // Slashing
+    pub slash_factor_checkpoint: u128, // Pool slash factor at the last settlement
+}
+
+impl StakePosition {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // owner
+                        32 +          // staking_pool
+                        1 +           // bump
+                        8 +           // amount
+                        8 +           // unstaking_amount
+                        8 +           // unstake_request_time
+                        16 +          // reward_index_checkpoint
+                        8 +           // pending_rewards
+                        8 +           // rewards_claimed
+                        16;           // slash_factor_checkpoint
+    
+    /// Applies slashes since the last checkpoint, then accrues rewards earned into pending_rewards
+    pub fn settle(&mut self, reward_index: u128, slash_factor: u128) -> Result<()> {
+        if slash_factor != self.slash_factor_checkpoint && self.slash_factor_checkpoint > 0 {
+            self.amount = Self::scale(self.amount, slash_factor, self.slash_factor_checkpoint)?;
+            self.unstaking_amount = Self::scale(self.unstaking_amount, slash_factor, self.slash_factor_checkpoint)?;
+        }
+        self.slash_factor_checkpoint = slash_factor;
+        
+        let index_delta = reward_index.saturating_sub(self.reward_index_checkpoint);
+        let earned = (self.amount as u128)
+            .checked_mul(index_delta)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(PRECISION as u128)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        self.pending_rewards = self.pending_rewards
+            .checked_add(u64::try_from(earned).map_err(|_| ErrorCode::MathOverflow)?)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.reward_index_checkpoint = reward_index;
+        
+        Ok(())
+    }
+    
+    fn scale(amount: u64, numerator: u128, denominator: u128) -> Result<u64> {
+        let scaled = (amount as u128)
+            .checked_mul(numerator)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_div(denominator)
+            .ok_or(ErrorCode::MathOverflow)?;
+        
+        u64::try_from(scaled).map_err(|_| ErrorCode::MathOverflow.into())
+    }
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+} 
\ No newline at end of file
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct StakingPool {
+    // Pool metadata
+    pub authority: Pubkey,               // Protocol authority that manages the pool (may be a multisig PDA)
+    pub pending_authority: Pubkey,       // Proposed new authority awaiting acceptance (default = none)
+    pub stake_mint: Pubkey,              // Mint of the protocol token being staked
+    pub stake_vault: Pubkey,             // Token account holding staked tokens
+    pub reward_mint: Pubkey,             // Mint protocol fees are paid to stakers in
+    pub reward_vault: Pubkey,            // Token account holding undistributed staker rewards
+    pub bump: u8,                        // Bump seed for the staking pool PDA (signs for both vaults)
+    
+    // Staking parameters
+    pub unstake_cooldown: i64,           // Seconds between unstake request and withdrawal
+    
+    // Staking financials
+    pub total_staked: u64,               // Tokens currently staked and earning
+    pub reward_index: u128,              // Cumulative rewards per staked token (scaled by PRECISION)
+    pub total_rewards_distributed: u64,  // Total protocol fees routed to stakers
+    
+    // Slashing backstop
+    pub total_unstaking: u64,            // Tokens in the unstake cooldown (still slashable)
+    pub slash_factor: u128,              // Cumulative post-slash share of stake (scaled by PRECISION)
+    pub max_slash_bps: u16,              // Maximum share of stake a single slash may take
+    pub slash_timelock: i64,             // Seconds between proposing and executing a slash
+    pub pending_slash_amount: u64,       // Amount of the proposed slash (0 = none pending)
+    pub pending_slash_vault: Pubkey,     // Vault whose shortfall the proposed slash covers
+    pub pending_slash_recipient: Pubkey, // Token account receiving the slashed tokens
+    pub pending_slash_eta: i64,          // Earliest time the proposed slash can execute
+    pub total_slashed: u64,              // Total tokens slashed from stakers
+}
+
+impl StakingPool {
+    pub const LEN: usize = 8 +           // discriminator
+                          32 +            // authority
+                          32 +            // pending_authority
+                          32 +            // stake_mint
+                          32 +            // stake_vault
+                          32 +            // reward_mint
+                          32 +            // reward_vault
+                          1 +             // bump
+                          8 +             // unstake_cooldown
+                          8 +             // total_staked
+                          16 +            // reward_index
+                          8 +             // total_rewards_distributed
+                          8 +             // total_unstaking
+                          16 +            // slash_factor
+                          2 +             // max_slash_bps
+                          8 +             // slash_timelock
+                          8 +             // pending_slash_amount
+                          32 +            // pending_slash_vault
+                          32 +            // pending_slash_recipient
+                          8 +             // pending_slash_eta
+                          8;              // total_slashed
+} 
\ No newline at end of file
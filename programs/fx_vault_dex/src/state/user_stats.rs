@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::PRICE_SCALE;
+
+#[account]
+#[derive(Default)]
+pub struct UserStats {
+    pub owner: Pubkey,               // Trader the stats belong to
+    pub bump: u8,                    // Bump seed for the user stats PDA
+    
+    // Rolling totals, valued in the oracles' common quote currency
+    pub swap_count: u64,             // Swaps executed by the trader
+    pub cumulative_volume: u128,     // Value of all amounts swapped in
+    pub cumulative_fees: u128,       // Value of all swap fees paid
+    
+    // Trade timing
+    pub first_trade_timestamp: i64,  // Timestamp of the first recorded swap
+    pub last_trade_slot: u64,        // Slot of the last swap
+    pub last_trade_timestamp: i64,   // Timestamp of the last swap
+}
+
+impl UserStats {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // owner
+                        1 +           // bump
+                        8 +           // swap_count
+                        16 +          // cumulative_volume
+                        16 +          // cumulative_fees
+                        8 +           // first_trade_timestamp
+                        8 +           // last_trade_slot
+                        8;            // last_trade_timestamp
+    
+    /// Records a swap, valuing the amount in and the fee at their oracle prices
+    /// Prices are in the common quote currency scaled by PRICE_SCALE
+    pub fn record_swap(
+        &mut self,
+        (amount_in, source_price): (u64, u64),
+        (fee_amount, target_price): (u64, u64),
+        slot: u64,
+        timestamp: i64,
+    ) {
+        let volume = amount_in as u128 * source_price as u128 / PRICE_SCALE as u128;
+        let fees = fee_amount as u128 * target_price as u128 / PRICE_SCALE as u128;
+        
+        self.swap_count = self.swap_count.saturating_add(1);
+        self.cumulative_volume = self.cumulative_volume.saturating_add(volume);
+        self.cumulative_fees = self.cumulative_fees.saturating_add(fees);
+        if self.first_trade_timestamp == 0 {
+            self.first_trade_timestamp = timestamp;
+        }
+        self.last_trade_slot = slot;
+        self.last_trade_timestamp = timestamp;
+    }
+} 
\ No newline at end of file
@@ -1,17 +1,22 @@
 use anchor_lang::prelude::*;
+use crate::state::constants::{SECONDS_PER_DAY, PRECISION, DUST_SWEEP_FLOOR, VAULT_NAME_LEN, UTILIZATION_AVERAGE_WINDOW, MINIMUM_LIQUIDITY};
+use crate::state::roles::{Role, Roles};
+use crate::utils::{ErrorCode, calculate_shares_for_amount, calculate_amount_for_shares, calculate_shares_to_burn};
 
 #[account]
 #[derive(Default)]
 pub struct VaultAccount {
     // Vault metadata
-    pub vault_name: String,              // User-friendly name of the vault
+    pub vault_name: [u8; VAULT_NAME_LEN], // User-friendly name of the vault, UTF-8 padded with zeros
     pub authority: Pubkey,               // Authority PDA that signs vault operations
     pub token_mint: Pubkey,              // Mint address of the stablecoin this vault accepts
     pub token_account: Pubkey,           // Token account PDA that holds the vault's tokens
-    pub nonce: u8,                       // Bump seed for the vault PDA
+    pub nonce: u8,                       // Canonical bump seed for the vault authority PDA
     
     // Vault financials
     pub tvl: u64,                        // Total value locked in the vault
+    pub total_shares: u64,               // LP shares outstanding against TVL plus retained LP fees
+    pub protocol_owned_liquidity: u64,   // Portion of TVL seeded by the protocol treasury
     pub accrued_lp_fees: u64,            // Accumulated fees for LPs since last distribution (70%)
     pub accrued_pda_fees: u64,           // Accumulated fees for PDA (variable based on vault health)
     pub accrued_protocol_fees: u64,      // Accumulated fees for protocol (variable based on vault health)
@@ -26,16 +31,103 @@ pub struct VaultAccount {
     // Treasury accounts
     pub treasury: Pubkey,                // Treasury account to receive protocol fees
     pub pda_treasury: Pubkey,            // PDA treasury account to receive PDA fees
+    pub pending_treasury: Pubkey,        // Proposed new treasury awaiting acceptance (default = none)
+    
+    // Vault admin
+    pub admin: Pubkey,                   // Key that distributes the vault's accrued fees
+    pub pending_admin: Pubkey,           // Proposed new admin awaiting acceptance (default = none)
+    pub roles: Roles,                    // Keys delegated individual capabilities by the admin
+    
+    // Balance incentive (rebalancing bounty paid out of accrued PDA fees)
+    pub balance_target_health_bps: u16,  // Health (scaled by 10000) a swap must reach to earn the bonus
+    pub balance_bonus_bps: u16,          // Bonus paid on the amount out, in basis points
+    pub balance_bonus_cap: u64,          // Maximum bonus paid per incentive
+    pub balance_bonus_cooldown: i64,     // Minimum seconds between two bonuses
+    pub last_balance_bonus_time: i64,    // Timestamp of the last bonus paid
+    pub total_balance_bonus_paid: u64,   // Total bonuses paid out of this vault
+    
+    // Launch mode (decaying fee parameters for newly launched vaults)
+    pub launch_start_time: i64,          // Timestamp the launch phase started
+    pub launch_duration: i64,            // Length of the launch phase in seconds (0 = disabled)
+    pub launch_spread_bps: u16,          // Spread at the start of the launch phase
+    pub launch_withdrawal_fee_bps: u16,  // Withdrawal penalty at the start of the launch phase
+    
+    // Reward vesting
+    pub reward_vesting_duration: i64,    // Seconds LP rewards vest over (0 = instant transfer)
+    pub early_claim_penalty_bps: u16,    // Penalty on unvested rewards claimed early
+    pub vesting_rewards_outstanding: u64, // LP rewards held in vesting schedules
+    
+    // Price deviation circuit breaker
+    pub max_price_deviation_bps: u16,    // Max move between consecutive prices before halting (0 = disabled)
+    pub breaker_cooldown_slots: u64,     // Slots swaps stay halted after the breaker trips
+    pub breaker_tripped: bool,           // Whether swaps are currently halted
+    pub halt_until_slot: u64,            // Slot swaps may resume at if the price is back within band
+    pub breaker_reference_price: u64,    // Last price the breaker accepted; the pre-trip price while tripped
+    
+    // Daily volume cap
+    pub daily_volume_cap: u64,           // Max swap volume through the vault per day (0 = unlimited)
+    pub volume_day: i64,                 // Day index (unix time / 86400) of the current counter
+    pub daily_volume: u64,               // Swap volume through the vault during volume_day
+    
+    // Net exposure since inception
+    pub cumulative_swap_inflow: u64,     // Tokens swapped into the vault
+    pub cumulative_swap_outflow: u64,    // Tokens swapped out of the vault
+    pub net_swap_flow: i128,             // Inflow minus outflow (positive = protocol long this currency)
+    
+    // Retained-earnings mode
+    pub retain_earnings: bool,           // Fold LP fees into TVL instead of paying claims
+    pub fee_fold_interval: i64,          // Minimum seconds between folds
+    pub last_fee_fold_time: i64,         // Timestamp of the last fold
+    
+    // Asset-management fees (paid to the protocol treasury out of LP assets)
+    pub management_fee_bps: u16,         // Annual fee on LP assets (0 = disabled)
+    pub performance_fee_bps: u16,        // Share of LP yield above the high-water mark (0 = disabled)
+    pub high_water_mark: u64,            // Highest virtual price performance fees were charged up to
+    pub last_management_fee_time: i64,   // Timestamp management fees were accrued up to
+    
+    // Dust limits (0 = no minimum)
+    pub min_deposit_amount: u64,         // Smallest deposit (or split-off tranche) accepted
+    pub min_withdraw_amount: u64,        // Smallest partial withdrawal accepted
+    pub min_swap_amount: u64,            // Smallest swap input accepted
+    
+    // Operation pauses (set by the pauser role)
+    pub deposits_paused: bool,           // Deposits into the vault are rejected
+    pub withdrawals_paused: bool,        // Withdrawals from the vault are rejected
+    pub swaps_paused: bool,              // Swaps into or out of the vault are rejected
+    
+    // Wind-down (permanent withdraw-only mode for retired corridors)
+    pub wound_down: bool,                // Deposits and swaps into the vault are disabled and penalties waived
+    pub wind_down_time: i64,             // Timestamp the wind-down started
+    
+    // Token account rotation (timelocked move of the vault's tokens to a new account)
+    pub pending_token_account: Pubkey,   // Proposed replacement token account (default = none)
+    pub token_account_rotation_eta: i64, // Earliest time the rotation can be executed
+    
+    // Oracle sequencing
+    pub last_swap_slot: u64,             // Slot of the most recent swap through the vault
+    pub last_swap_publish_slot: u64,     // Oracle publish slot used by that swap
+    
+    // Fee history
+    pub cumulative_swap_fees: u64,       // All swap fees charged on amounts paid out of the vault
+    
+    // Utilization (drawdown against a trailing average)
+    pub tvl_average: u64,                // Exponential moving average of TVL sampled by swaps
+    pub tvl_average_time: i64,           // Timestamp the average was last updated
+    
+    // Transaction guard
+    pub tx_guard_enabled: bool,          // Deposits, swaps and withdrawals must be alone in their transaction
 }
 
 impl VaultAccount {
     pub const LEN: usize = 8 +           // discriminator
-                          32 +            // vault_name (max)
+                          VAULT_NAME_LEN + // vault_name
                           32 +            // authority
                           32 +            // token_mint
                           32 +            // token_account
                           1 +             // nonce
                           8 +             // tvl
+                          8 +             // total_shares
+                          8 +             // protocol_owned_liquidity
                           8 +             // accrued_lp_fees
                           8 +             // accrued_pda_fees
                           8 +             // accrued_protocol_fees
@@ -45,5 +137,269 @@ impl VaultAccount {
                           8 +             // last_oracle_price
                           8 +             // last_update_timestamp
                           32 +            // treasury
-                          32;             // pda_treasury
+                          32 +            // pda_treasury
+                          32 +            // pending_treasury
+                          32 +            // admin
+                          32 +            // pending_admin
+                          Roles::LEN +    // roles
+                          2 +             // balance_target_health_bps
+                          2 +             // balance_bonus_bps
+                          8 +             // balance_bonus_cap
+                          8 +             // balance_bonus_cooldown
+                          8 +             // last_balance_bonus_time
+                          8 +             // total_balance_bonus_paid
+                          8 +             // launch_start_time
+                          8 +             // launch_duration
+                          2 +             // launch_spread_bps
+                          2 +             // launch_withdrawal_fee_bps
+                          8 +             // reward_vesting_duration
+                          2 +             // early_claim_penalty_bps
+                          8 +             // vesting_rewards_outstanding
+                          2 +             // max_price_deviation_bps
+                          8 +             // breaker_cooldown_slots
+                          1 +             // breaker_tripped
+                          8 +             // halt_until_slot
+                          8 +             // breaker_reference_price
+                          8 +             // daily_volume_cap
+                          8 +             // volume_day
+                          8 +             // daily_volume
+                          8 +             // cumulative_swap_inflow
+                          8 +             // cumulative_swap_outflow
+                          16 +            // net_swap_flow
+                          1 +             // retain_earnings
+                          8 +             // fee_fold_interval
+                          8 +             // last_fee_fold_time
+                          2 +             // management_fee_bps
+                          2 +             // performance_fee_bps
+                          8 +             // high_water_mark
+                          8 +             // last_management_fee_time
+                          8 +             // min_deposit_amount
+                          8 +             // min_withdraw_amount
+                          8 +             // min_swap_amount
+                          1 +             // deposits_paused
+                          1 +             // withdrawals_paused
+                          1 +             // swaps_paused
+                          1 +             // wound_down
+                          8 +             // wind_down_time
+                          32 +            // pending_token_account
+                          8 +             // token_account_rotation_eta
+                          8 +             // last_swap_slot
+                          8 +             // last_swap_publish_slot
+                          8 +             // cumulative_swap_fees
+                          8 +             // tvl_average
+                          8 +             // tvl_average_time
+                          1;              // tx_guard_enabled
+    
+    /// Tokens owned by LPs: TVL plus retained LP fees
+    pub fn lp_assets(&self) -> u64 {
+        self.tvl.saturating_add(self.accrued_lp_fees)
+    }
+    
+    /// Encodes a vault name into its fixed-size field, or None if it is too long
+    pub fn encode_name(name: &str) -> Option<[u8; VAULT_NAME_LEN]> {
+        let bytes = name.as_bytes();
+        if bytes.len() > VAULT_NAME_LEN {
+            return None;
+        }
+        
+        let mut encoded = [0u8; VAULT_NAME_LEN];
+        encoded[..bytes.len()].copy_from_slice(bytes);
+        Some(encoded)
+    }
+    
+    /// Decodes the vault name, dropping the zero padding
+    /// Returns None if the stored bytes are not valid UTF-8
+    pub fn name(&self) -> Option<&str> {
+        let len = self.vault_name.iter().position(|byte| *byte == 0).unwrap_or(VAULT_NAME_LEN);
+        std::str::from_utf8(&self.vault_name[..len]).ok()
+    }
+    
+    /// Returns the key holding a role, falling back to the role's default holder when unassigned
+    pub fn role_holder(&self, role: Role) -> Pubkey {
+        let assigned = self.roles.get(role);
+        if assigned != Pubkey::default() {
+            return assigned;
+        }
+        
+        match role {
+            Role::FeeManager | Role::Pauser => self.admin,
+            Role::Rebalancer => self.pda_treasury,
+        }
+    }
+    
+    /// Returns true if the key currently holds the role
+    pub fn has_role(&self, role: Role, key: &Pubkey) -> bool {
+        self.role_holder(role) == *key
+    }
+    
+    /// Returns the pause flags as a bitmask: deposits = 1, withdrawals = 2, swaps = 4
+    pub fn pause_flags(&self) -> u64 {
+        (self.deposits_paused as u64) | (self.withdrawals_paused as u64) << 1 | (self.swaps_paused as u64) << 2
+    }
+    
+    /// Tokens the vault's accounting says its token account holds
+    pub fn tracked_balance(&self) -> u64 {
+        self.tvl
+            .saturating_add(self.accrued_lp_fees)
+            .saturating_add(self.accrued_pda_fees)
+            .saturating_add(self.accrued_protocol_fees)
+            .saturating_add(self.vesting_rewards_outstanding)
+    }
+    
+    /// Largest untracked balance that counts as dust rather than a real surplus
+    pub fn dust_limit(&self) -> u64 {
+        self.min_deposit_amount
+            .max(self.min_withdraw_amount)
+            .max(self.min_swap_amount)
+            .max(DUST_SWEEP_FLOOR)
+    }
+    
+    /// Value of one share in tokens, scaled by PRECISION
+    pub fn virtual_price(&self) -> u64 {
+        if self.total_shares == 0 {
+            return PRECISION;
+        }
+        
+        let price = self.lp_assets() as u128 * PRECISION as u128 / self.total_shares as u128;
+        price.min(u64::MAX as u128) as u64
+    }
+    
+    /// Shares the first deposit into an empty vault locks instead of minting to the depositor
+    fn locked_shares(&self) -> u64 {
+        if self.total_shares == 0 {
+            MINIMUM_LIQUIDITY
+        } else {
+            0
+        }
+    }
+    
+    /// Shares a deposit would mint to the depositor at the current exchange rate
+    /// The first deposit locks MINIMUM_LIQUIDITY shares so a near-empty vault's exchange rate
+    /// cannot be pushed up until later deposits round down to nothing
+    /// Fails if the depositor would receive no shares
+    pub fn shares_for_deposit(&self, amount: u64) -> Result<u64> {
+        let shares = calculate_shares_for_amount(amount, self.lp_assets(), self.total_shares)?
+            .saturating_sub(self.locked_shares());
+        require!(shares > 0, ErrorCode::DepositTooSmall);
+        Ok(shares)
+    }
+    
+    /// Adds a deposit to TVL and mints shares at the current exchange rate
+    /// Returns the shares minted to the depositor
+    pub fn issue_shares(&mut self, amount: u64) -> Result<u64> {
+        let locked = self.locked_shares();
+        let shares = self.shares_for_deposit(amount)?;
+        
+        self.tvl = self.tvl.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        self.total_shares = self.total_shares
+            .checked_add(shares)
+            .and_then(|total| total.checked_add(locked))
+            .ok_or(ErrorCode::MathOverflow)?;
+        Ok(shares)
+    }
+    
+    /// Burns shares at the current exchange rate, drawing the tokens from TVL and
+    /// retained LP fees in proportion
+    /// Returns the tokens the shares were worth
+    pub fn redeem_shares(&mut self, shares: u64) -> Result<u64> {
+        let lp_assets = self.lp_assets();
+        let amount = calculate_amount_for_shares(shares, lp_assets, self.total_shares)?;
+        let fee_amount = calculate_amount_for_shares(amount, self.accrued_lp_fees, lp_assets)?;
+        
+        self.accrued_lp_fees = self.accrued_lp_fees.checked_sub(fee_amount).ok_or(ErrorCode::MathOverflow)?;
+        self.tvl = self.tvl.checked_sub(amount - fee_amount).ok_or(ErrorCode::MathOverflow)?;
+        self.total_shares = self.total_shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
+        Ok(amount)
+    }
+    
+    /// Pays out the retained LP fees backing a holding by burning the shares they are worth,
+    /// leaving the exchange rate unchanged
+    /// Returns (fee_amount, shares_burned)
+    pub fn harvest_fee_share(&mut self, shares: u64) -> Result<(u64, u64)> {
+        let fee_amount = calculate_amount_for_shares(shares, self.accrued_lp_fees, self.total_shares)?;
+        let shares_burned = calculate_shares_to_burn(fee_amount, self.lp_assets(), self.total_shares)?.min(shares);
+        
+        self.accrued_lp_fees = self.accrued_lp_fees.checked_sub(fee_amount).ok_or(ErrorCode::MathOverflow)?;
+        self.total_shares = self.total_shares.checked_sub(shares_burned).ok_or(ErrorCode::MathOverflow)?;
+        Ok((fee_amount, shares_burned))
+    }
+    
+    /// Moves an asset-management fee out of LP assets into the protocol fee bucket,
+    /// drawing on retained LP fees before TVL
+    /// Returns the fee actually taken
+    pub fn charge_lp_fee(&mut self, amount: u64) -> Result<u64> {
+        let amount = amount.min(self.lp_assets());
+        let from_lp_fees = amount.min(self.accrued_lp_fees);
+        
+        self.accrued_lp_fees -= from_lp_fees;
+        self.tvl = self.tvl.checked_sub(amount - from_lp_fees).ok_or(ErrorCode::MathOverflow)?;
+        self.accrued_protocol_fees = self.accrued_protocol_fees.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        Ok(amount)
+    }
+    
+    /// Adds swap volume to today's counter, resetting it on a new day
+    /// Returns false if the volume would exceed the daily cap
+    pub fn try_record_daily_volume(&mut self, amount: u64, current_time: i64) -> bool {
+        let day = current_time.div_euclid(SECONDS_PER_DAY);
+        if day != self.volume_day {
+            self.volume_day = day;
+            self.daily_volume = 0;
+        }
+        
+        let new_volume = self.daily_volume.saturating_add(amount);
+        if self.daily_volume_cap > 0 && new_volume > self.daily_volume_cap {
+            return false;
+        }
+        
+        self.daily_volume = new_volume;
+        true
+    }
+    
+    /// Records swap flow through the vault for net exposure tracking
+    pub fn record_swap_flow(&mut self, inflow: u64, outflow: u64) {
+        self.cumulative_swap_inflow = self.cumulative_swap_inflow.saturating_add(inflow);
+        self.cumulative_swap_outflow = self.cumulative_swap_outflow.saturating_add(outflow);
+        self.net_swap_flow = self.net_swap_flow
+            .saturating_add(inflow as i128)
+            .saturating_sub(outflow as i128);
+    }
+    
+    /// Moves the trailing average TVL towards the current TVL by the time elapsed since the
+    /// last update, weighted against UTILIZATION_AVERAGE_WINDOW
+    pub fn update_tvl_average(&mut self, current_time: i64) {
+        if self.tvl_average_time == 0 {
+            self.tvl_average = self.tvl;
+            self.tvl_average_time = current_time;
+            return;
+        }
+        
+        let elapsed = current_time.saturating_sub(self.tvl_average_time).clamp(0, UTILIZATION_AVERAGE_WINDOW);
+        let delta = self.tvl as i128 - self.tvl_average as i128;
+        let average = self.tvl_average as i128 + delta * elapsed as i128 / UTILIZATION_AVERAGE_WINDOW as i128;
+        
+        self.tvl_average = average as u64;
+        self.tvl_average_time = current_time;
+    }
+    
+    /// How far TVL is below its trailing average, in basis points of the average
+    /// Returns 0 when TVL is at or above the average
+    pub fn drawdown_bps(&self) -> u64 {
+        if self.tvl_average == 0 || self.tvl >= self.tvl_average {
+            return 0;
+        }
+        
+        ((self.tvl_average - self.tvl) as u128 * 10000 / self.tvl_average as u128) as u64
+    }
+    
+    /// Records the oracle publish slot used by a swap
+    /// Returns false if an earlier swap in the same slot already used a newer price
+    pub fn try_record_oracle_slot(&mut self, current_slot: u64, publish_slot: u64) -> bool {
+        if current_slot == self.last_swap_slot && publish_slot < self.last_swap_publish_slot {
+            return false;
+        }
+        
+        self.last_swap_slot = current_slot;
+        self.last_swap_publish_slot = publish_slot;
+        true
+    }
 } 
\ No newline at end of file
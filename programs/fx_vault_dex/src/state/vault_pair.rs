@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::utils::calculate_cross_rate;
+
+#[account]
+#[derive(Default)]
+pub struct VaultPair {
+    // Pair identification (vaults are stored in ascending order of their mints)
+    pub vault_a: Pubkey,             // Vault of the lower mint
+    pub vault_b: Pubkey,             // Vault of the higher mint
+    pub mint_a: Pubkey,              // Lower of the two vault mints
+    pub mint_b: Pubkey,              // Higher of the two vault mints
+    pub base_is_a: bool,             // Quote orientation (true = mint_a is the base currency)
+    pub bump: u8,                    // Bump seed for the vault pair PDA
+    pub created_at: i64,             // Timestamp the pair was registered
+    
+    // Pair statistics
+    pub swap_count: u64,             // Swaps executed between the two vaults
+    pub volume_in_a: u128,           // mint_a tokens swapped into vault A
+    pub volume_in_b: u128,           // mint_b tokens swapped into vault B
+    pub fees_a: u128,                // Fees charged on amounts paid out of vault A
+    pub fees_b: u128,                // Fees charged on amounts paid out of vault B
+    pub last_swap_timestamp: i64,    // Timestamp of the last swap
+}
+
+impl VaultPair {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // vault_a
+                        32 +          // vault_b
+                        32 +          // mint_a
+                        32 +          // mint_b
+                        1 +           // base_is_a
+                        1 +           // bump
+                        8 +           // created_at
+                        8 +           // swap_count
+                        16 +          // volume_in_a
+                        16 +          // volume_in_b
+                        16 +          // fees_a
+                        16 +          // fees_b
+                        8;            // last_swap_timestamp
+    
+    /// Returns the (base, quote) mints in the pair's quote orientation
+    pub fn orientation(&self) -> (Pubkey, Pubkey) {
+        if self.base_is_a {
+            (self.mint_a, self.mint_b)
+        } else {
+            (self.mint_b, self.mint_a)
+        }
+    }
+    
+    /// Prices a swap out of the vault of source_mint in the pair's quote orientation
+    /// Returns the base price in quote units, scaled by 10^9, and whether the swap sells the base currency
+    pub fn quote(&self, source_mint: Pubkey, source_price: u64, target_price: u64) -> Result<(u64, bool)> {
+        if self.orientation().0 == source_mint {
+            Ok((calculate_cross_rate(source_price, target_price)?, true))
+        } else {
+            Ok((calculate_cross_rate(target_price, source_price)?, false))
+        }
+    }
+    
+    /// Records a swap from the vault of source_mint into the other vault
+    /// The fee is charged in the target currency
+    pub fn record_swap(&mut self, source_mint: Pubkey, amount_in: u64, fee_amount: u64, timestamp: i64) {
+        if source_mint == self.mint_a {
+            self.volume_in_a = self.volume_in_a.saturating_add(amount_in as u128);
+            self.fees_b = self.fees_b.saturating_add(fee_amount as u128);
+        } else {
+            self.volume_in_b = self.volume_in_b.saturating_add(amount_in as u128);
+            self.fees_a = self.fees_a.saturating_add(fee_amount as u128);
+        }
+        self.swap_count = self.swap_count.saturating_add(1);
+        self.last_swap_timestamp = timestamp;
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::utils::calculate_vested_amount;
+
+#[account]
+#[derive(Default)]
+pub struct VestingSchedule {
+    // Vesting metadata
+    pub owner: Pubkey,               // LP the rewards vest to
+    pub vault: Pubkey,               // Vault the rewards were earned in
+    pub bump: u8,                    // Bump seed for the vesting schedule PDA
+    
+    // Current linear schedule
+    pub locked_amount: u64,          // Rewards on the current linear schedule
+    pub released_amount: u64,        // Portion of locked_amount already vested
+    pub start_time: i64,             // Start of the current schedule
+    pub end_time: i64,               // End of the current schedule
+    
+    // Vested balances
+    pub unlocked_amount: u64,        // Vested rewards not yet claimed
+    pub total_claimed: u64,          // Total vested rewards claimed
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 8 +        // discriminator
+                        32 +          // owner
+                        32 +          // vault
+                        1 +           // bump
+                        8 +           // locked_amount
+                        8 +           // released_amount
+                        8 +           // start_time
+                        8 +           // end_time
+                        8 +           // unlocked_amount
+                        8;            // total_claimed
+    
+    /// Moves rewards that have vested since the last update into the unlocked balance
+    pub fn release(&mut self, current_time: i64) -> Result<()> {
+        let vested = calculate_vested_amount(
+            self.locked_amount,
+            self.start_time,
+            self.end_time,
+            current_time,
+        )?;
+        let newly_vested = vested.saturating_sub(self.released_amount);
+        
+        self.unlocked_amount = self.unlocked_amount.saturating_add(newly_vested);
+        self.released_amount = vested;
+        
+        Ok(())
+    }
+    
+    /// Restarts the linear schedule with the still-locked rewards plus new rewards
+    pub fn credit(&mut self, amount: u64, current_time: i64, duration: i64) -> Result<()> {
+        self.release(current_time)?;
+        
+        let still_locked = self.locked_amount.saturating_sub(self.released_amount);
+        self.locked_amount = still_locked.saturating_add(amount);
+        self.released_amount = 0;
+        self.start_time = current_time;
+        self.end_time = current_time.saturating_add(duration);
+        
+        Ok(())
+    }
+} 
\ No newline at end of file
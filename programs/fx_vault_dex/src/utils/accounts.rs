@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+/// Deserializes an account passed through remaining_accounts after checking its owner
+pub fn load_program_account<T: AccountDeserialize>(account_info: &AccountInfo, owner: &Pubkey) -> Result<T> {
+    require_keys_eq!(*account_info.owner, *owner, AccountError::InvalidAccountOwner);
+    let data = account_info.try_borrow_data()?;
+    T::try_deserialize(&mut &data[..])
+}
+
+/// Writes a modified account back to its remaining_accounts entry
+/// Accounts loaded from remaining_accounts are not persisted automatically
+pub fn store_program_account<T: AccountSerialize>(account: &T, account_info: &AccountInfo) -> Result<()> {
+    require!(account_info.is_writable, AccountError::AccountNotWritable);
+    let mut data = account_info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// Error codes for accounts loaded from remaining_accounts
+#[error_code]
+pub enum AccountError {
+    #[msg("Account is not owned by the expected program")]
+    InvalidAccountOwner,
+    
+    #[msg("Account must be writable")]
+    AccountNotWritable,
+} 
\ No newline at end of file
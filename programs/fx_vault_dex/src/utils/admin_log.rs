@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use crate::events::AdminActionLogged;
+use crate::state::{AdminLog, AdminLogEntry, AdminAction};
+
+/// Records an admin action in the on-chain log and emits it as an event
+pub fn record_admin_action(
+    admin_log: &mut AdminLog,
+    actor: Pubkey,
+    action: AdminAction,
+    target: Pubkey,
+    old_value: u64,
+    new_value: u64,
+) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    
+    admin_log.push(AdminLogEntry {
+        actor,
+        action,
+        target,
+        old_value,
+        new_value,
+        timestamp,
+    });
+    
+    emit!(AdminActionLogged {
+        sequence: admin_log.sequence,
+        actor,
+        action,
+        target,
+        old_value,
+        new_value,
+        timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Compact fingerprint of a key or multi-field setting for the admin log
+/// fingerprint = first 8 bytes of SHA-256(borsh(value)), little endian
+pub fn fingerprint<T: AnchorSerialize>(value: &T) -> u64 {
+    let bytes = value.try_to_vec().unwrap_or_default();
+    let digest = hash(&bytes).to_bytes();
+    u64::from_le_bytes(digest[..8].try_into().unwrap())
+} 
\ No newline at end of file
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use crate::events::{CircuitBreakerTripped, CircuitBreakerResumed};
+use crate::state::VaultAccount;
+use crate::utils::calculate_price_deviation_bps;
+
+/// Trips the breaker if the new price moved too far from the previous one
+/// Returns true if the breaker tripped
+pub fn trip_circuit_breaker_if_deviated(
+    vault: &mut VaultAccount,
+    vault_key: Pubkey,
+    price: u64,
+    previous_price: u64,
+    current_slot: u64,
+) -> bool {
+    if vault.max_price_deviation_bps == 0 || vault.breaker_tripped || previous_price == 0 {
+        return false;
+    }
+    
+    let deviation_bps = calculate_price_deviation_bps(price, previous_price);
+    if deviation_bps <= vault.max_price_deviation_bps as u64 {
+        return false;
+    }
+    
+    vault.breaker_tripped = true;
+    vault.halt_until_slot = current_slot.saturating_add(vault.breaker_cooldown_slots);
+    vault.breaker_reference_price = previous_price;
+    
+    emit!(CircuitBreakerTripped {
+        vault: vault_key,
+        price,
+        reference_price: previous_price,
+        deviation_bps,
+        halt_until_slot: vault.halt_until_slot,
+    });
+    
+    true
+}
+
+/// Resumes a tripped breaker once the cooldown has passed and the price is back within band
+/// Returns true if the vault is (now) open for swaps
+pub fn try_resume_circuit_breaker(
+    vault: &mut VaultAccount,
+    vault_key: Pubkey,
+    price: u64,
+    current_slot: u64,
+) -> bool {
+    if !vault.breaker_tripped {
+        return true;
+    }
+    if current_slot < vault.halt_until_slot {
+        return false;
+    }
+    
+    let deviation_bps = calculate_price_deviation_bps(price, vault.breaker_reference_price);
+    if deviation_bps > vault.max_price_deviation_bps as u64 {
+        return false;
+    }
+    
+    vault.breaker_tripped = false;
+    
+    emit!(CircuitBreakerResumed {
+        vault: vault_key,
+        price,
+        reference_price: vault.breaker_reference_price,
+        slot: current_slot,
+    });
+    
+    true
+}
+
+/// Checks a price a swap or snapshot is about to use against the breaker's reference price
+/// Resumes a tripped breaker when it can, trips an open one on a large move, and otherwise
+/// moves the reference to the new price. Returns true if the vault is open for swaps
+pub fn check_circuit_breaker(
+    vault: &mut VaultAccount,
+    vault_key: Pubkey,
+    price: u64,
+    current_slot: u64,
+) -> bool {
+    if vault.breaker_tripped {
+        return try_resume_circuit_breaker(vault, vault_key, price, current_slot);
+    }
+    
+    let reference_price = vault.breaker_reference_price;
+    if trip_circuit_breaker_if_deviated(vault, vault_key, price, reference_price, current_slot) {
+        return false;
+    }
+    
+    vault.breaker_reference_price = price;
+    true
+}
+
+/// Checks both vaults of a pair, so a move on either feed is recorded even when the other
+/// vault is already halted. Returns true if both vaults are open for swaps
+pub fn check_pair_circuit_breakers(
+    source_vault: &mut VaultAccount,
+    source_vault_key: Pubkey,
+    source_price: u64,
+    target_vault: &mut VaultAccount,
+    target_vault_key: Pubkey,
+    target_price: u64,
+    current_slot: u64,
+) -> bool {
+    let source_open = check_circuit_breaker(source_vault, source_vault_key, source_price, current_slot);
+    let target_open = check_circuit_breaker(target_vault, target_vault_key, target_price, current_slot);
+    source_open && target_open
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn breaker_vault() -> VaultAccount {
+        VaultAccount {
+            max_price_deviation_bps: 500,
+            breaker_cooldown_slots: 100,
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn trip_persists_halt_until_slot_and_reference() {
+        let key = Pubkey::new_unique();
+        let mut vault = breaker_vault();
+        
+        assert!(check_circuit_breaker(&mut vault, key, 1_000_000_000, 10));
+        assert_eq!(vault.breaker_reference_price, 1_000_000_000);
+        
+        // A 10% move trips the breaker; the call itself succeeds so the caller can keep the state
+        assert!(!check_circuit_breaker(&mut vault, key, 1_100_000_000, 20));
+        assert!(vault.breaker_tripped);
+        assert_eq!(vault.halt_until_slot, 120);
+        assert_eq!(vault.breaker_reference_price, 1_000_000_000);
+    }
+    
+    #[test]
+    fn swaps_stay_halted_during_cooldown_and_resume_after_it() {
+        let key = Pubkey::new_unique();
+        let mut vault = breaker_vault();
+        check_circuit_breaker(&mut vault, key, 1_000_000_000, 10);
+        check_circuit_breaker(&mut vault, key, 1_100_000_000, 20);
+        
+        // Back within band, but still inside the cooldown
+        assert!(!check_circuit_breaker(&mut vault, key, 1_010_000_000, 60));
+        assert!(vault.breaker_tripped);
+        
+        // Past the cooldown but still out of band
+        assert!(!check_circuit_breaker(&mut vault, key, 1_100_000_000, 120));
+        assert!(vault.breaker_tripped);
+        
+        // Past the cooldown and within band: the next swap resumes the vault on its own
+        assert!(check_circuit_breaker(&mut vault, key, 1_010_000_000, 120));
+        assert!(!vault.breaker_tripped);
+        assert!(check_circuit_breaker(&mut vault, key, 1_010_000_000, 121));
+    }
+    
+    #[test]
+    fn pair_check_records_a_trip_on_either_side() {
+        let mut source = breaker_vault();
+        let mut target = breaker_vault();
+        let (source_key, target_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+        
+        assert!(check_pair_circuit_breakers(&mut source, source_key, 1_000_000_000, &mut target, target_key, 1_000_000_000, 10));
+        
+        // The source trips first; the target's move in the same call is still recorded
+        assert!(!check_pair_circuit_breakers(&mut source, source_key, 800_000_000, &mut target, target_key, 1_200_000_000, 20));
+        assert!(source.breaker_tripped);
+        assert!(target.breaker_tripped);
+        assert_eq!(target.halt_until_slot, 120);
+    }
+    
+    #[test]
+    fn disabled_breaker_never_trips() {
+        let key = Pubkey::new_unique();
+        let mut vault = VaultAccount::default();
+        
+        assert!(check_circuit_breaker(&mut vault, key, 1_000_000_000, 10));
+        assert!(check_circuit_breaker(&mut vault, key, 5_000_000_000, 11));
+        assert!(!vault.breaker_tripped);
+    }
+} 
\ No newline at end of file
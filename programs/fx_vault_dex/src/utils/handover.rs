@@ -0,0 +1,147 @@
+//! Two-step handover of privileged keys
+//!
+//! The protocol admin, the feed registry admin, each vault's admin and treasury, and the
+//! staking pool authority change hands in two steps. The current holder proposes a new key,
+//! and the handover completes only when that key signs the matching `accept_*` instruction,
+//! so a mistyped key cannot lock a role. Any holder may be a multisig vault PDA signing
+//! through CPI. Proposing `Pubkey::default()` cancels a pending handover.
+//!
+//! The vault treasury is a signer role like the others: it seeds protocol-owned liquidity
+//! besides owning the token accounts protocol fees are paid to. The vault's configuration
+//! instructions are signed by the vault admin.
+
+use anchor_lang::prelude::*;
+
+/// Records the proposed next holder of a role
+pub fn propose_handover(pending: &mut Pubkey, new_holder: Pubkey) {
+    *pending = new_holder;
+}
+
+/// Hands the role to the signer if it is the pending holder, clearing the proposal
+/// Returns the previous holder
+pub fn accept_handover(holder: &mut Pubkey, pending: &mut Pubkey, signer: Pubkey) -> Result<Pubkey> {
+    require!(*pending != Pubkey::default() && signer == *pending, HandoverError::NotPendingHolder);
+    
+    let previous = *holder;
+    *holder = signer;
+    *pending = Pubkey::default();
+    
+    Ok(previous)
+}
+
+#[error_code]
+pub enum HandoverError {
+    #[msg("Signer is not the pending holder of the role")]
+    NotPendingHolder,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Squads-style multisig: its vault PDA only signs once enough members approve
+    struct MockMultisig {
+        vault: Pubkey,
+        members: Vec<Pubkey>,
+        threshold: usize,
+    }
+    
+    impl MockMultisig {
+        fn new(member_count: usize, threshold: usize) -> Self {
+            let multisig_program = Pubkey::new_unique();
+            let (vault, _) = Pubkey::find_program_address(&[b"multisig", b"vault"], &multisig_program);
+            let members = (0..member_count).map(|_| Pubkey::new_unique()).collect();
+            Self { vault, members, threshold }
+        }
+        
+        /// Key the multisig signs a transaction with, if the approvals reach the threshold
+        fn signer(&self, approvals: &[Pubkey]) -> Option<Pubkey> {
+            let approved = self.members.iter().filter(|member| approvals.contains(member)).count();
+            (approved >= self.threshold).then_some(self.vault)
+        }
+    }
+    
+    #[test]
+    fn handover_to_multisig_completes_when_it_signs() {
+        let deployer = Pubkey::new_unique();
+        let multisig = MockMultisig::new(3, 2);
+        let mut holder = deployer;
+        let mut pending = Pubkey::default();
+        
+        propose_handover(&mut pending, multisig.vault);
+        assert_eq!(holder, deployer);
+        
+        let signer = multisig.signer(&multisig.members[..2]).unwrap();
+        let previous = accept_handover(&mut holder, &mut pending, signer).unwrap();
+        
+        assert_eq!(previous, deployer);
+        assert_eq!(holder, multisig.vault);
+        assert_eq!(pending, Pubkey::default());
+    }
+    
+    #[test]
+    fn multisig_member_cannot_accept_alone() {
+        let deployer = Pubkey::new_unique();
+        let multisig = MockMultisig::new(3, 2);
+        let mut holder = deployer;
+        let mut pending = Pubkey::default();
+        
+        propose_handover(&mut pending, multisig.vault);
+        
+        // One approval is below the threshold, and a member key is not the multisig
+        assert_eq!(multisig.signer(&multisig.members[..1]), None);
+        assert!(accept_handover(&mut holder, &mut pending, multisig.members[0]).is_err());
+        assert_eq!(holder, deployer);
+        assert_eq!(pending, multisig.vault);
+    }
+    
+    #[test]
+    fn mistyped_proposal_leaves_the_role_with_its_holder() {
+        let deployer = Pubkey::new_unique();
+        let multisig = MockMultisig::new(3, 2);
+        let mut holder = deployer;
+        let mut pending = Pubkey::default();
+        
+        // Nobody controls the mistyped key, so the multisig cannot take the role
+        propose_handover(&mut pending, Pubkey::new_unique());
+        let signer = multisig.signer(&multisig.members).unwrap();
+        assert!(accept_handover(&mut holder, &mut pending, signer).is_err());
+        assert_eq!(holder, deployer);
+        
+        // The holder can still correct the proposal
+        propose_handover(&mut pending, multisig.vault);
+        accept_handover(&mut holder, &mut pending, signer).unwrap();
+        assert_eq!(holder, multisig.vault);
+    }
+    
+    #[test]
+    fn cancelled_handover_cannot_be_accepted() {
+        let deployer = Pubkey::new_unique();
+        let multisig = MockMultisig::new(2, 2);
+        let mut holder = deployer;
+        let mut pending = Pubkey::default();
+        
+        propose_handover(&mut pending, multisig.vault);
+        propose_handover(&mut pending, Pubkey::default());
+        
+        let signer = multisig.signer(&multisig.members).unwrap();
+        assert!(accept_handover(&mut holder, &mut pending, signer).is_err());
+        assert!(accept_handover(&mut holder, &mut pending, Pubkey::default()).is_err());
+        assert_eq!(holder, deployer);
+    }
+    
+    #[test]
+    fn multisig_can_hand_the_role_on() {
+        let first = MockMultisig::new(3, 2);
+        let second = MockMultisig::new(5, 3);
+        let mut holder = first.vault;
+        let mut pending = Pubkey::default();
+        
+        propose_handover(&mut pending, second.vault);
+        let signer = second.signer(&second.members[1..4]).unwrap();
+        let previous = accept_handover(&mut holder, &mut pending, signer).unwrap();
+        
+        assert_eq!(previous, first.vault);
+        assert_eq!(holder, second.vault);
+    }
+} 
\ No newline at end of file
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 use crate::state::constants::*;
+use crate::state::{DriftCurve, ParameterCurve};
 
 /// Calculates the spread fee based on vault health
-/// spread = max(0.03%, 0.03% - 0.2833% × (vault_health - 0.9))
+/// spread = max(0.03%, 0.03% - slope × (vault_health - 0.9)), with a default slope of 0.2833%
 /// Returns spread in basis points
-pub fn calculate_spread(amount_a: u64, amount_b: u64) -> u16 {
+pub fn calculate_spread(amount_a: u64, amount_b: u64, spread_slope_ppm: u32) -> u16 {
     // Vault health is between 0 and 1
     let vault_health = calculate_vault_health(amount_a, amount_b);
     
@@ -15,7 +16,8 @@ pub fn calculate_spread(amount_a: u64, amount_b: u64) -> u16 {
     let spread_percentage = if vault_health > 0.9 {
         min_spread
     } else {
-        let adjustment = SPREAD_SLOPE * (vault_health - 0.9);
+        let spread_slope = spread_slope_ppm as f64 / 1_000_000.0;
+        let adjustment = spread_slope * (vault_health - 0.9);
         f64::max(min_spread, min_spread - adjustment)
     };
     
@@ -24,37 +26,90 @@ pub fn calculate_spread(amount_a: u64, amount_b: u64) -> u16 {
     std::cmp::min(spread_bps, MAX_SPREAD_BPS)
 }
 
-/// Calculates the drift based on vault health
-/// drift = max(0%, -0.8333% × (vault_health - 0.9))
+/// Calculates the drift based on vault health using a two-slope (kinked) curve
+/// drift = gentle × (start - health)                              for kink ≤ health < start
+/// drift = gentle × (start - kink) + steep × (kink - health)       for health < kink
 /// Returns drift as a positive percentage (0.0 to 1.0)
-pub fn calculate_drift(amount_a: u64, amount_b: u64) -> f64 {
-    let vault_health = calculate_vault_health(amount_a, amount_b);
+pub fn calculate_drift(vault_health_bps: u64, curve: &DriftCurve) -> f64 {
+    let vault_health = vault_health_bps as f64 / 10000.0;
+    
+    let start_health = curve.start_health_bps as f64 / 10000.0;
+    let kink_health = (curve.kink_health_bps.min(curve.start_health_bps)) as f64 / 10000.0;
+    let gentle_slope = curve.gentle_slope_ppm as f64 / 1_000_000.0;
+    let steep_slope = curve.steep_slope_ppm as f64 / 1_000_000.0;
     
-    if vault_health >= 0.9 {
+    if vault_health >= start_health {
         0.0 // No drift when vault is balanced
+    } else if vault_health >= kink_health {
+        gentle_slope * (start_health - vault_health)
     } else {
-        let adjustment = DRIFT_SLOPE * (vault_health - 0.9);
-        f64::max(0.0, -adjustment)
+        gentle_slope * (start_health - kink_health) + steep_slope * (kink_health - vault_health)
     }
 }
 
-/// Calculate fee allocation between PDA and protocol based on vault health
-/// Returns (pda_fee_percentage, protocol_fee_percentage)
-pub fn calculate_fee_allocation(amount_a: u64, amount_b: u64) -> (u8, u8) {
-    // The percentages are of the 30% non-LP portion of fees
-    let vault_health = calculate_vault_health(amount_a, amount_b);
+/// Calculates the signed drift for a swap from the source vault into the target vault
+/// The size comes from the given (averaged) health and the direction from the current amounts:
+/// flow that worsens health pays the drift; flow into the scarcer vault receives it as a
+/// price improvement, capped at MAX_DRIFT_IMPROVEMENT_BPS
+/// Returns a positive penalty or a negative improvement
+pub fn calculate_directional_drift(source_amount: u64, target_amount: u64, vault_health_bps: u64, curve: &DriftCurve) -> f64 {
+    let drift = calculate_drift(vault_health_bps, curve);
     
-    if vault_health > 0.70 {
-        (15, 15) // 15% to PDA, 15% to protocol
-    } else if vault_health > 0.50 {
-        (20, 10) // 20% to PDA, 10% to protocol
-    } else if vault_health > 0.30 {
-        (25, 5)  // 25% to PDA, 5% to protocol
+    if source_amount < target_amount {
+        let max_improvement = MAX_DRIFT_IMPROVEMENT_BPS as f64 / 10000.0;
+        -drift.min(max_improvement)
     } else {
-        (30, 0)  // 30% to PDA, 0% to protocol
+        drift
     }
 }
 
+/// Calculate fee allocation between PDA and protocol based on vault health
+/// The protocol share is read from the protocol fee curve and the PDA receives the rest
+/// Returns (pda_fee_bps, protocol_fee_bps) of the total fee
+pub fn calculate_fee_allocation(vault_health_bps: u64, curve: &ParameterCurve) -> (u16, u16) {
+    // The shares are of the 30% non-LP portion of fees
+    let protocol_bps = (interpolate_curve(curve, vault_health_bps) as u16).min(NON_LP_FEE_BPS);
+    (NON_LP_FEE_BPS - protocol_bps, protocol_bps)
+}
+
+/// Evaluates a piecewise-linear curve at x
+/// Linear between breakpoints, clamped to the end values outside them
+/// Returns 0 for an empty curve
+pub fn interpolate_curve(curve: &ParameterCurve, x: u64) -> u64 {
+    let points = &curve.points;
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0,
+    };
+    
+    if x <= first.x {
+        return first.y as u64;
+    }
+    if x >= last.x {
+        return last.y as u64;
+    }
+    
+    for pair in points.windows(2) {
+        let (left, right) = (pair[0], pair[1]);
+        if x <= right.x {
+            // y = left.y + (right.y - left.y) × (x - left.x) / (right.x - left.x)
+            let span = (right.x - left.x) as u128;
+            let offset = (x - left.x) as u128;
+            let left_y = left.y as u128;
+            let right_y = right.y as u128;
+            
+            let y = if right_y >= left_y {
+                left_y + (right_y - left_y) * offset / span
+            } else {
+                left_y - (left_y - right_y) * offset / span
+            };
+            return y as u64;
+        }
+    }
+    
+    last.y as u64
+}
+
 /// Calculates vault health as min(vault_a, vault_b) / max(vault_a, vault_b)
 /// Returns a value between 0 and 1, where 1 is perfectly balanced
 pub fn calculate_vault_health(amount_a: u64, amount_b: u64) -> f64 {
@@ -68,31 +123,109 @@ pub fn calculate_vault_health(amount_a: u64, amount_b: u64) -> f64 {
     min_amount / max_amount
 }
 
-/// Calculate the amount out based on exchange rate, spread, and drift
+/// Calculates vault health in basis points (0 to 10000)
+pub fn calculate_vault_health_bps(amount_a: u64, amount_b: u64) -> u64 {
+    (calculate_vault_health(amount_a, amount_b) * 10000.0) as u64
+}
+
+/// Calculates the fee on a trade's amount out for the spread plus the depth impact
+/// impact = coefficient × (share - threshold)², where share = amount_out / target_liquidity,
+/// capped at MAX_DEPTH_IMPACT_BPS, and the total at 100%
+/// The share is kept exact rather than rounded to whole basis points, so the fee never grows
+/// by more than the amount out and a larger trade never receives less than a smaller one
+pub fn calculate_swap_fee(amount_out: u64, spread_bps: u16, target_liquidity: u64) -> Result<u64> {
+    let amount_out = amount_out as u128;
+    let spread_bps = spread_bps as u128;
+    
+    if target_liquidity == 0 {
+        let rate_bps = (spread_bps + MAX_DEPTH_IMPACT_BPS as u128).min(10000);
+        return Ok((amount_out * rate_bps / 10000) as u64);
+    }
+    
+    // Rates are in basis points scaled by 10^8 × target_liquidity, so the share needs no rounding:
+    // impact_bps × 10^8 × liquidity = coefficient × excess² / liquidity,
+    // where excess = (share_bps - threshold_bps) × liquidity
+    let liquidity = target_liquidity as u128;
+    let scale = 100_000_000 * liquidity;
+    let excess = (amount_out * 10000)
+        .min(10000 * liquidity)
+        .saturating_sub(DEPTH_IMPACT_THRESHOLD_BPS as u128 * liquidity);
+    let impact = mul_div(excess, excess, liquidity).ok_or(ErrorCode::MathOverflow)?
+        * DEPTH_IMPACT_COEFFICIENT_BPS as u128;
+    let impact = impact.min(MAX_DEPTH_IMPACT_BPS as u128 * scale);
+    let rate = (spread_bps * scale + impact).min(10000 * scale);
+    
+    let fee = mul_div(amount_out, rate, 10000 * scale).ok_or(ErrorCode::MathOverflow)?;
+    Ok(fee as u64)
+}
+
+/// Calculates a × b / denominator, rounded down, with a 256-bit intermediate product
+/// Returns None if the denominator is zero or the result does not fit in a u128
+fn mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product / denominator);
+    }
+    
+    // Product as (high, low) halves from 64-bit limbs
+    let mask = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & mask);
+    let (b_high, b_low) = (b >> 64, b & mask);
+    let low_low = a_low * b_low;
+    let high_low = a_high * b_low;
+    let low_high = a_low * b_high;
+    let middle = (low_low >> 64) + (high_low & mask) + (low_high & mask);
+    let low = (middle << 64) | (low_low & mask);
+    let high = a_high * b_high + (high_low >> 64) + (low_high >> 64) + (middle >> 64);
+    if high >= denominator {
+        return None;
+    }
+    
+    // Shift-subtract long division; the remainder always stays below the denominator
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    
+    Some(quotient)
+}
+
+/// Calculate the amount out based on exchange rate, spread, depth impact, and signed drift
 pub fn calculate_amount_out(
     amount_in: u64,
     oracle_price: u64,
     spread_bps: u16,
     drift_percentage: f64,
-    source_to_target: bool, // true if converting from source to target, false otherwise
+    target_liquidity: u64,  // liquidity the amount out is paid from, for the depth impact
+    source_to_target: bool, // true if selling the base currency of oracle_price, false if buying it
 ) -> Result<(u64, u64)> {
     // Oracle price is scaled by PRICE_SCALE (10^9)
     // Example: If 1 EUR = 1.1 USD, oracle_price = 1_100_000_000
-
-    let spread = spread_bps as u64;
+    
     let amount_in_u128 = amount_in as u128;
     
     // Apply drift to oracle price if applicable
-    let adjusted_oracle_price = if source_to_target {
-        // When buying target currency, decrease the exchange rate (get less target)
-        let drift_adjustment = (oracle_price as f64 * drift_percentage) as u64;
+    // A positive drift penalizes the trade and a negative drift improves its price
+    let drift_adjustment = (oracle_price as f64 * drift_percentage.abs()) as u64;
+    let adjusted_oracle_price = if source_to_target == (drift_percentage >= 0.0) {
+        // Penalty when buying target currency, or improvement when selling it:
+        // decrease the exchange rate
         oracle_price.saturating_sub(drift_adjustment)
     } else {
-        // When selling target currency, increase the exchange rate (get less source)
-        let drift_adjustment = (oracle_price as f64 * drift_percentage) as u64;
+        // Penalty when selling target currency, or improvement when buying it:
+        // increase the exchange rate
         oracle_price.saturating_add(drift_adjustment)
     };
-
+    
     // Calculate the amount out based on the direction
     let amount_out_before_fee = if source_to_target {
         // Source to target (e.g., EUR to USD)
@@ -111,49 +244,325 @@ pub fn calculate_amount_out(
             .checked_div(adjusted_oracle_price as u128)
             .ok_or(ErrorCode::MathOverflow)?
     };
-
+    
     // Convert to u64, checking for overflow
     let amount_out_before_fee_u64: u64 = amount_out_before_fee
         .try_into()
         .map_err(|_| ErrorCode::MathOverflow)?;
+    
+    // Large trades relative to the target vault pay a depth impact on top of the spread
+    let fee_amount = calculate_swap_fee(amount_out_before_fee_u64, spread_bps, target_liquidity)?;
+    
+    // Calculate final amount out after fee
+    let amount_out = amount_out_before_fee_u64
+        .checked_sub(fee_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok((amount_out, fee_amount))
+}
+
+/// Calculates the amount out on an amplified constant-sum/constant-product (stableswap) curve
+/// Both reserves are valued in target units at the reference price, so the curve is centered
+/// on the last good oracle price and the spread is charged on the amount out
+/// Returns (amount_out, fee_amount)
+pub fn calculate_stableswap_amount_out(
+    amount_in: u64,
+    reference_price: u64,
+    source_reserve: u64,
+    target_reserve: u64,
+    amp: u16,
+    spread_bps: u16,
+) -> Result<(u64, u64)> {
+    let to_target_units = |amount: u64| -> Result<u128> {
+        Ok((amount as u128)
+            .checked_mul(reference_price as u128)
+            .ok_or(ErrorCode::MathOverflow)?
+            / PRICE_SCALE as u128)
+    };
+    
+    let x = to_target_units(source_reserve)?;
+    let y = target_reserve as u128;
+    require!(x > 0 && y > 0 && amp > 0, ErrorCode::MathOverflow);
+    
+    let ann = amp as u128 * 2;
+    let d = calculate_stableswap_invariant(x, y, ann)?;
+    let new_x = x.checked_add(to_target_units(amount_in)?).ok_or(ErrorCode::MathOverflow)?;
+    let new_y = calculate_stableswap_y(new_x, d, ann)?;
+    
+    // Round against the trader
+    let amount_out_before_fee = y.saturating_sub(new_y).saturating_sub(1);
+    let amount_out_before_fee_u64: u64 = amount_out_before_fee
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    
+    let fee_amount = amount_out_before_fee_u64
+        .checked_mul((spread_bps as u64).min(10000))
+        .ok_or(ErrorCode::MathOverflow)?
+        / 10000;
+    
+    Ok((amount_out_before_fee_u64 - fee_amount, fee_amount))
+}
+
+/// Solves the two-coin stableswap invariant D for reserves x and y by Newton's method
+/// ann × (x + y) + D = ann × D + D³ / (4xy)
+fn calculate_stableswap_invariant(x: u128, y: u128, ann: u128) -> Result<u128> {
+    let sum = x.checked_add(y).ok_or(ErrorCode::MathOverflow)?;
+    let mut d = sum;
+    
+    for _ in 0..255 {
+        // d_p = D³ / (4xy), computed stepwise to limit the intermediate size
+        let d_p = d
+            .checked_mul(d)
+            .ok_or(ErrorCode::MathOverflow)?
+            / (x * 2);
+        let d_p = d_p
+            .checked_mul(d)
+            .ok_or(ErrorCode::MathOverflow)?
+            / (y * 2);
+        
+        let previous = d;
+        let numerator = ann
+            .checked_mul(sum)
+            .and_then(|v| v.checked_add(d_p * 2))
+            .and_then(|v| v.checked_mul(d))
+            .ok_or(ErrorCode::MathOverflow)?;
+        let denominator = (ann - 1)
+            .checked_mul(d)
+            .and_then(|v| v.checked_add(d_p * 3))
+            .ok_or(ErrorCode::MathOverflow)?;
+        d = numerator / denominator;
+        
+        if d.abs_diff(previous) <= 1 {
+            return Ok(d);
+        }
+    }
+    
+    err!(ErrorCode::MathOverflow)
+}
+
+/// Solves the stableswap invariant for the target reserve given the new source reserve
+/// y² + (x + D / ann - D) × y = D³ / (4 × ann × x)
+fn calculate_stableswap_y(x: u128, d: u128, ann: u128) -> Result<u128> {
+    let c = d
+        .checked_mul(d)
+        .ok_or(ErrorCode::MathOverflow)?
+        / (x * 2);
+    let c = c
+        .checked_mul(d)
+        .ok_or(ErrorCode::MathOverflow)?
+        / (ann * 2);
+    let b = x + d / ann;
+    let mut y = d;
+    
+    for _ in 0..255 {
+        let previous = y;
+        let numerator = y
+            .checked_mul(y)
+            .and_then(|v| v.checked_add(c))
+            .ok_or(ErrorCode::MathOverflow)?;
+        let denominator = (y * 2 + b).checked_sub(d).ok_or(ErrorCode::MathOverflow)?;
+        y = numerator / denominator;
+        
+        if y.abs_diff(previous) <= 1 {
+            return Ok(y);
+        }
+    }
+    
+    err!(ErrorCode::MathOverflow)
+}
 
-    // Calculate fee (spread * amount_out / 10000)
-    let fee_amount: u64 = amount_out_before_fee_u64
-        .checked_mul(spread)
+/// Converts a deposit into vault shares at the current exchange rate
+/// shares = amount × total_shares / total_assets, or 1:1 while the vault has no shares
+pub fn calculate_shares_for_amount(
+    amount: u64,
+    total_assets: u64,
+    total_shares: u64,
+) -> Result<u64> {
+    if total_shares == 0 || total_assets == 0 {
+        return Ok(amount);
+    }
+    
+    let shares = (amount as u128)
+        .checked_mul(total_shares as u128)
         .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(10000)
+        .checked_div(total_assets as u128)
         .ok_or(ErrorCode::MathOverflow)?;
+    
+    u64::try_from(shares).map_err(|_| ErrorCode::MathOverflow.into())
+}
 
-    // Calculate final amount out after fee
-    let amount_out = amount_out_before_fee_u64
-        .checked_sub(fee_amount)
+/// Converts vault shares into tokens at the current exchange rate
+/// amount = shares × total_assets / total_shares, rounded down
+pub fn calculate_amount_for_shares(
+    shares: u64,
+    total_assets: u64,
+    total_shares: u64,
+) -> Result<u64> {
+    if total_shares == 0 {
+        return Ok(0);
+    }
+    
+    let amount = (shares as u128)
+        .checked_mul(total_assets as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(total_shares as u128)
         .ok_or(ErrorCode::MathOverflow)?;
+    
+    u64::try_from(amount).map_err(|_| ErrorCode::MathOverflow.into())
+}
 
-    Ok((amount_out, fee_amount))
+/// Shares that must be burned to pay out an amount at the current exchange rate
+/// shares = amount × total_shares / total_assets, rounded up in the vault's favor
+pub fn calculate_shares_to_burn(
+    amount: u64,
+    total_assets: u64,
+    total_shares: u64,
+) -> Result<u64> {
+    if total_assets == 0 {
+        return Ok(0);
+    }
+    
+    let numerator = (amount as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let shares = numerator
+        .checked_add(total_assets as u128 - 1)
+        .ok_or(ErrorCode::MathOverflow)?
+        / total_assets as u128;
+    
+    u64::try_from(shares).map_err(|_| ErrorCode::MathOverflow.into())
 }
 
-/// Calculates reward distribution for a specific LP
-pub fn calculate_lp_rewards(
-    lp_amount: u64,
-    total_rewards: u64,
-    total_deposits: u64,
+/// Calculates a launch-phase fee that decays linearly to zero
+/// fee = initial_bps × (1 - elapsed / duration)
+/// Returns the fee in basis points, or 0 once the launch phase is over
+pub fn calculate_launch_decay(
+    initial_bps: u16,
+    launch_start_time: i64,
+    launch_duration: i64,
+    current_time: i64,
+) -> u16 {
+    if launch_duration <= 0 || initial_bps == 0 {
+        return 0;
+    }
+    
+    let elapsed = current_time.saturating_sub(launch_start_time).max(0);
+    if elapsed >= launch_duration {
+        return 0;
+    }
+    
+    let remaining = (launch_duration - elapsed) as u128;
+    (initial_bps as u128 * remaining / launch_duration as u128) as u16
+}
+
+/// Calculates how much of a linear vesting schedule has vested
+/// vested = total × (now - start) / (end - start), clamped to [0, total]
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    start_time: i64,
+    end_time: i64,
+    current_time: i64,
 ) -> Result<u64> {
-    if total_deposits == 0 {
+    if current_time >= end_time || end_time <= start_time {
+        return Ok(total_amount);
+    }
+    if current_time <= start_time {
         return Ok(0);
     }
+    
+    let elapsed = (current_time - start_time) as u128;
+    let duration = (end_time - start_time) as u128;
+    
+    let vested = (total_amount as u128)
+        .checked_mul(elapsed)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(duration)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok(vested as u64)
+}
+
+/// Calculates how far a price has moved from a reference price
+/// deviation = |price - reference| × 10000 / reference
+/// Returns the deviation in basis points
+pub fn calculate_price_deviation_bps(price: u64, reference_price: u64) -> u64 {
+    if reference_price == 0 {
+        return 0;
+    }
+    
+    let difference = price.abs_diff(reference_price) as u128;
+    let deviation = difference * 10000 / reference_price as u128;
+    
+    deviation.min(u64::MAX as u128) as u64
+}
 
-    // Calculate the LP's share of rewards based on their proportion of deposits
-    let lp_amount_u128 = lp_amount as u128;
-    let total_rewards_u128 = total_rewards as u128;
-    let total_deposits_u128 = total_deposits as u128;
+/// Calculates the rate a swap actually executed at
+/// rate = amount_out × PRICE_SCALE / amount_in
+pub fn calculate_effective_rate(amount_in: u64, amount_out: u64) -> u64 {
+    if amount_in == 0 {
+        return 0;
+    }
+    
+    let rate = amount_out as u128 * PRICE_SCALE as u128 / amount_in as u128;
+    rate.min(u64::MAX as u128) as u64
+}
 
-    let lp_rewards = lp_amount_u128
-        .checked_mul(total_rewards_u128)
+/// Calculates how far an execution rate is from the oracle mid
+/// deviation = (mid - rate) × 10000 / mid
+/// Returns the deviation in basis points, positive when the trader got less than the mid
+pub fn calculate_execution_deviation_bps(effective_rate: u64, oracle_price: u64) -> i64 {
+    if oracle_price == 0 {
+        return 0;
+    }
+    
+    let deviation = (oracle_price as i128 - effective_rate as i128) * 10000 / oracle_price as i128;
+    deviation.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Calculates the management fee accrued on LP assets over a period
+/// fee = assets × fee_bps × elapsed / (10000 × SECONDS_PER_YEAR)
+pub fn calculate_management_fee(
+    assets: u64,
+    fee_bps: u16,
+    elapsed: i64,
+) -> Result<u64> {
+    if elapsed <= 0 || fee_bps == 0 {
+        return Ok(0);
+    }
+    
+    let fee = (assets as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_mul(elapsed as u128)
         .ok_or(ErrorCode::MathOverflow)?
-        .checked_div(total_deposits_u128)
+        .checked_div(10000 * SECONDS_PER_YEAR as u128)
         .ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok(fee.min(u64::MAX as u128) as u64)
+}
 
-    Ok(lp_rewards as u64)
+/// Calculates the performance fee on share value gained above the high-water mark
+/// fee = (virtual_price - high_water_mark) × total_shares / PRECISION × fee_bps / 10000
+/// Returns 0 while the virtual price is at or below the high-water mark
+pub fn calculate_performance_fee(
+    virtual_price: u64,
+    high_water_mark: u64,
+    total_shares: u64,
+    fee_bps: u16,
+) -> Result<u64> {
+    if virtual_price <= high_water_mark || fee_bps == 0 {
+        return Ok(0);
+    }
+    
+    let fee = ((virtual_price - high_water_mark) as u128)
+        .checked_mul(total_shares as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_mul(fee_bps as u128)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(PRECISION as u128 * 10000)
+        .ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok(fee.min(u64::MAX as u128) as u64)
 }
 
 /// Error codes for math operations
@@ -161,4 +570,178 @@ pub fn calculate_lp_rewards(
 pub enum ErrorCode {
     #[msg("Math operation resulted in overflow")]
     MathOverflow,
+    
+    #[msg("Deposit is too small to mint any shares")]
+    DepositTooSmall,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    
+    /// Oracle prices from 0.001 to 1000 target units per source unit
+    fn price() -> impl Strategy<Value = u64> {
+        PRICE_SCALE / 1000..=PRICE_SCALE * 1000
+    }
+    
+    /// Total spreads up to 10%, above the launch, utilization and degraded maximums combined
+    fn spread_bps() -> impl Strategy<Value = u16> {
+        0u16..=1000
+    }
+    
+    /// Signed drifts up to 10%
+    fn drift() -> impl Strategy<Value = f64> {
+        -0.1f64..=0.1
+    }
+    
+    proptest! {
+        #[test]
+        fn amount_out_is_monotonic_in_amount_in(
+            (smaller, target_liquidity) in any::<u64>().prop_flat_map(|liquidity| (0..=liquidity, Just(liquidity))),
+            growth_ppb in 0u64..=100_000,
+            oracle_price in PRICE_SCALE / 2..=PRICE_SCALE * 2,
+            spread_bps in spread_bps(),
+            drift_percentage in drift(),
+            source_to_target in any::<bool>(),
+        ) {
+            // Trades within 0.01% of each other, sized against the target vault so the depth impact applies
+            let larger = (smaller as u128 + smaller as u128 * growth_ppb as u128 / 1_000_000_000) as u64;
+            let quote = |amount_in| {
+                calculate_amount_out(amount_in, oracle_price, spread_bps, drift_percentage, target_liquidity, source_to_target)
+            };
+            
+            // A larger trade that prices at all never receives less than a smaller one
+            if let Ok((larger_out, _)) = quote(larger) {
+                let (smaller_out, _) = quote(smaller).unwrap();
+                prop_assert!(smaller_out <= larger_out);
+            }
+        }
+        
+        #[test]
+        fn swap_fee_grows_by_at_most_one_per_unit(
+            (amount_out, target_liquidity) in any::<u64>().prop_flat_map(|liquidity| (0..liquidity, Just(liquidity))),
+            spread_bps in spread_bps(),
+        ) {
+            let fee = calculate_swap_fee(amount_out, spread_bps, target_liquidity).unwrap();
+            let next_fee = calculate_swap_fee(amount_out + 1, spread_bps, target_liquidity).unwrap();
+            prop_assert!(fee <= next_fee && next_fee <= fee + 1);
+        }
+        
+        #[test]
+        fn stableswap_amount_out_is_monotonic_in_amount_in(
+            (smaller, larger) in (0u64..=1_000_000_000_000_000, 0u64..=1_000_000_000_000_000).prop_map(|(a, b)| (a.min(b), a.max(b))),
+            reference_price in price(),
+            source_reserve in 1u64..=1_000_000_000_000_000,
+            target_reserve in 1u64..=1_000_000_000_000_000,
+            amp in 1u16..=MAX_STABLESWAP_AMP,
+            spread_bps in spread_bps(),
+        ) {
+            let quote = |amount_in| {
+                calculate_stableswap_amount_out(amount_in, reference_price, source_reserve, target_reserve, amp, spread_bps)
+            };
+            
+            if let (Ok((smaller_out, _)), Ok((larger_out, _))) = (quote(smaller), quote(larger)) {
+                prop_assert!(smaller_out <= larger_out);
+            }
+        }
+        
+        #[test]
+        fn fee_never_exceeds_the_converted_input(
+            amount_in in any::<u64>(),
+            oracle_price in price(),
+            spread_bps in any::<u16>(),
+            target_liquidity in any::<u64>(),
+            source_to_target in any::<bool>(),
+        ) {
+            // Without drift the fee and the amount out split the input converted at the oracle price
+            let converted = if source_to_target {
+                amount_in as u128 * oracle_price as u128 / PRICE_SCALE as u128
+            } else {
+                amount_in as u128 * PRICE_SCALE as u128 / oracle_price as u128
+            };
+            
+            if let Ok((amount_out, fee_amount)) =
+                calculate_amount_out(amount_in, oracle_price, spread_bps, 0.0, target_liquidity, source_to_target)
+            {
+                prop_assert!(fee_amount as u128 <= converted);
+                prop_assert_eq!(amount_out as u128 + fee_amount as u128, converted);
+            }
+        }
+        
+        #[test]
+        fn swap_fee_never_exceeds_the_amount_out(
+            amount_out in any::<u64>(),
+            spread_bps in any::<u16>(),
+            target_liquidity in any::<u64>(),
+        ) {
+            prop_assert!(calculate_swap_fee(amount_out, spread_bps, target_liquidity).unwrap() <= amount_out);
+        }
+        
+        #[test]
+        fn math_never_panics_across_the_u64_range(
+            amount in any::<u64>(),
+            oracle_price in any::<u64>(),
+            reserves in (any::<u64>(), any::<u64>()),
+            spread_bps in any::<u16>(),
+            amp in any::<u16>(),
+            drift_percentage in -1.0f64..=1.0,
+            source_to_target in any::<bool>(),
+        ) {
+            let (source_reserve, target_reserve) = reserves;
+            let _ = calculate_amount_out(amount, oracle_price, spread_bps, drift_percentage, target_reserve, source_to_target);
+            let _ = calculate_stableswap_amount_out(amount, oracle_price, source_reserve, target_reserve, amp, spread_bps);
+            let _ = calculate_swap_fee(amount, spread_bps, target_reserve);
+            let _ = calculate_shares_for_amount(amount, source_reserve, target_reserve);
+            let _ = calculate_amount_for_shares(amount, source_reserve, target_reserve);
+            let _ = calculate_shares_to_burn(amount, source_reserve, target_reserve);
+            let _ = calculate_effective_rate(amount, source_reserve);
+            let _ = calculate_execution_deviation_bps(amount, oracle_price);
+            let _ = calculate_price_deviation_bps(amount, oracle_price);
+            let _ = calculate_vault_health_bps(source_reserve, target_reserve);
+            let _ = calculate_spread(source_reserve, target_reserve, MAX_SPREAD_SLOPE_PPM);
+        }
+        
+        #[test]
+        fn stableswap_never_panics_on_funded_reserves(
+            amount_in in 0u64..=1_000_000_000_000_000,
+            reference_price in price(),
+            source_reserve in 1u64..=1_000_000_000_000_000,
+            target_reserve in 1u64..=1_000_000_000_000_000,
+            amp in 0u16..=MAX_STABLESWAP_AMP,
+            spread_bps in any::<u16>(),
+        ) {
+            // Random u64 amounts and reserves mostly overflow the invariant; these reach the curve and the fee
+            let _ = calculate_stableswap_amount_out(amount_in, reference_price, source_reserve, target_reserve, amp, spread_bps);
+        }
+        
+        #[test]
+        fn round_trip_is_never_profitable(
+            amount_in in any::<u64>(),
+            oracle_price in price(),
+            spread_bps in spread_bps(),
+            drift_penalty in 0.0f64..=0.1,
+            improvement_share in 0.0f64..=1.0,
+            improved_first in any::<bool>(),
+            target_liquidity in any::<u64>(),
+            source_liquidity in any::<u64>(),
+            source_to_target in any::<bool>(),
+        ) {
+            // Flow in one direction pays the drift and the way back earns at most as much improvement
+            let improvement = -drift_penalty * improvement_share;
+            let (first_drift, second_drift) = if improved_first {
+                (improvement, drift_penalty)
+            } else {
+                (drift_penalty, improvement)
+            };
+            
+            let first = calculate_amount_out(amount_in, oracle_price, spread_bps, first_drift, target_liquidity, source_to_target);
+            if let Ok((intermediate, _)) = first {
+                let second = calculate_amount_out(intermediate, oracle_price, spread_bps, second_drift, source_liquidity, !source_to_target);
+                if let Ok((amount_back, _)) = second {
+                    prop_assert!(amount_back <= amount_in);
+                }
+            }
+        }
+    }
 } 
\ No newline at end of file
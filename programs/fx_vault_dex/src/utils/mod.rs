@@ -1,3 +1,17 @@
 pub mod math;
+pub mod oracle;
+pub mod circuit_breaker;
+pub mod admin_log;
+pub mod accounts;
+pub mod tx_guard;
+pub mod pricing;
+pub mod handover;
 
-pub use math::*; 
\ No newline at end of file
+pub use math::*;
+pub use oracle::*;
+pub use circuit_breaker::*;
+pub use admin_log::*;
+pub use accounts::*;
+pub use tx_guard::*;
+pub use pricing::*;
+pub use handover::*; 
\ No newline at end of file
@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::{load_price_feed_from_account_info, Price};
+use pyth_sdk_solana::state::{load_price_account, PriceStatus};
+use crate::state::constants::*;
+
+/// Oracle price normalized to PRICE_SCALE (10^9)
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OraclePrice {
+    pub price: u64,          // Price scaled by PRICE_SCALE
+    pub conf: u64,           // Confidence interval scaled by PRICE_SCALE
+    pub publish_time: i64,   // Unix timestamp the price was published
+    pub publish_slot: u64,   // Slot the price was published in
+}
+
+/// Loads the Pyth price feed and checks that it holds a usable price
+pub fn validate_oracle_data(oracle: &AccountInfo) -> Result<Price> {
+    if let Some(pyth_program_id) = PYTH_PROGRAM_ID {
+        require_keys_eq!(*oracle.owner, pyth_program_id, OracleError::InvalidOracleAccount);
+    }
+    
+    let price_feed = load_price_feed_from_account_info(oracle)
+        .map_err(|_| OracleError::InvalidOracleAccount)?;
+    let price = price_feed.get_price_unchecked();
+    
+    require!(price.price > 0, OracleError::InvalidOraclePrice);
+    
+    Ok(price)
+}
+
+impl OraclePrice {
+    /// Whether the price was published within MAX_ORACLE_STALENESS of the given time
+    pub fn is_fresh(&self, current_time: i64) -> bool {
+        current_time.saturating_sub(self.publish_time) <= MAX_ORACLE_STALENESS
+    }
+}
+
+/// Reads the current oracle price, rejecting stale feeds
+/// Returns the price and confidence scaled by PRICE_SCALE
+pub fn get_oracle_price(oracle: &AccountInfo, current_time: i64) -> Result<OraclePrice> {
+    let price = read_oracle_price(oracle)?;
+    require!(price.is_fresh(current_time), OracleError::StaleOraclePrice);
+    
+    Ok(price)
+}
+
+/// Reads the latest oracle price without checking its age
+/// Callers that can price without the oracle decide for themselves how stale is too stale
+pub fn read_oracle_price(oracle: &AccountInfo) -> Result<OraclePrice> {
+    let price = validate_oracle_data(oracle)?;
+    
+    // A positive price too small for PRICE_SCALE would otherwise read as zero
+    let normalized_price = normalize_to_price_scale(price.price as u64, price.expo)?;
+    require!(normalized_price > 0, OracleError::InvalidOracleExponent);
+    
+    Ok(OraclePrice {
+        price: normalized_price,
+        conf: normalize_to_price_scale(price.conf, price.expo)?,
+        publish_time: price.publish_time,
+        publish_slot: get_publish_slot(oracle)?,
+    })
+}
+
+/// Reads the slot of the price returned by validate_oracle_data
+/// Matches the SDK's fallback to the previous trading price when the aggregate is not trading
+pub fn get_publish_slot(oracle: &AccountInfo) -> Result<u64> {
+    let data = oracle.try_borrow_data()?;
+    let price_account = load_price_account(&data)
+        .map_err(|_| OracleError::InvalidOracleAccount)?;
+    
+    Ok(match price_account.agg.status {
+        PriceStatus::Trading => price_account.agg.pub_slot,
+        _ => price_account.prev_slot,
+    })
+}
+
+/// Converts two prices quoted in the same currency into the price of base in units of quote
+/// cross = base_price × PRICE_SCALE / quote_price
+pub fn calculate_cross_rate(base_price: u64, quote_price: u64) -> Result<u64> {
+    require!(quote_price > 0, OracleError::InvalidOraclePrice);
+    
+    let cross = (base_price as u128)
+        .checked_mul(PRICE_SCALE as u128)
+        .ok_or(OracleError::InvalidOraclePrice)?
+        / quote_price as u128;
+    
+    u64::try_from(cross).map_err(|_| OracleError::InvalidOraclePrice.into())
+}
+
+/// Converts a value with a Pyth exponent into PRICE_SCALE units
+/// value × 10^(expo + 9), computed with u128 intermediates
+pub fn normalize_to_price_scale(value: u64, expo: i32) -> Result<u64> {
+    let shift = expo
+        .checked_add(PRICE_SCALE_DECIMALS)
+        .ok_or(OracleError::InvalidOracleExponent)?;
+    
+    let scaled = if shift >= 0 {
+        let factor = 10u128
+            .checked_pow(shift as u32)
+            .ok_or(OracleError::InvalidOracleExponent)?;
+        (value as u128)
+            .checked_mul(factor)
+            .ok_or(OracleError::InvalidOracleExponent)?
+    } else {
+        match 10u128.checked_pow(shift.unsigned_abs()) {
+            Some(factor) => (value as u128) / factor,
+            None => 0, // Divisor exceeds any u64 value
+        }
+    };
+    
+    u64::try_from(scaled).map_err(|_| OracleError::InvalidOracleExponent.into())
+}
+
+/// Error codes for oracle operations
+#[error_code]
+pub enum OracleError {
+    #[msg("Oracle account is not a valid Pyth price account")]
+    InvalidOracleAccount,
+    
+    #[msg("Oracle price must be positive")]
+    InvalidOraclePrice,
+    
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+    
+    #[msg("Oracle exponent cannot be represented at PRICE_SCALE")]
+    InvalidOracleExponent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyth_sdk_solana::state::{AccountType, CorpAction, PriceAccount, PriceInfo, MAGIC, VERSION_2};
+    
+    /// Pyth price account with an aggregate price
+    fn price_account(price: i64, conf: u64, expo: i32, status: PriceStatus) -> PriceAccount {
+        PriceAccount {
+            magic: MAGIC,
+            ver: VERSION_2,
+            atype: AccountType::Price as u32,
+            expo,
+            timestamp: 1_700_000_000,
+            prev_slot: 90,
+            prev_price: price / 2,
+            prev_conf: conf / 2,
+            prev_timestamp: 1_699_999_990,
+            agg: PriceInfo {
+                price,
+                conf,
+                status,
+                corp_act: CorpAction::NoCorpAct,
+                pub_slot: 100,
+            },
+            ..PriceAccount::default()
+        }
+    }
+    
+    /// Runs read_oracle_price against the account as the runtime would pass it
+    fn read(mut account: PriceAccount) -> Result<OraclePrice> {
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut lamports = 0;
+        let data = bytemuck::bytes_of_mut(&mut account);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+        
+        read_oracle_price(&info)
+    }
+    
+    #[test]
+    fn normalizes_common_pyth_exponents() {
+        // 1.23456, 1.08512345, 1.0851234567 and 1.085123456789 at 10^9
+        assert_eq!(normalize_to_price_scale(123_456, -5).unwrap(), 1_234_560_000);
+        assert_eq!(normalize_to_price_scale(108_512_345, -8).unwrap(), 1_085_123_450);
+        assert_eq!(normalize_to_price_scale(1_085_123_450, -9).unwrap(), 1_085_123_450);
+        assert_eq!(normalize_to_price_scale(10_851_234_567, -10).unwrap(), 1_085_123_456);
+        assert_eq!(normalize_to_price_scale(1_085_123_456_789, -12).unwrap(), 1_085_123_456);
+    }
+    
+    #[test]
+    fn normalizes_non_negative_exponents() {
+        assert_eq!(normalize_to_price_scale(7, 0).unwrap(), 7_000_000_000);
+        assert_eq!(normalize_to_price_scale(5, 2).unwrap(), 500_000_000_000);
+        assert_eq!(normalize_to_price_scale(1, 10).unwrap(), 10_000_000_000_000_000_000);
+    }
+    
+    #[test]
+    fn truncates_values_below_the_scale_to_zero() {
+        assert_eq!(normalize_to_price_scale(999, -12).unwrap(), 0);
+        assert_eq!(normalize_to_price_scale(u64::MAX, -40).unwrap(), 0);
+        
+        // The divisor itself overflows u128
+        assert_eq!(normalize_to_price_scale(u64::MAX, -50).unwrap(), 0);
+    }
+    
+    #[test]
+    fn rejects_values_beyond_u64_at_the_scale() {
+        // u64::MAX / 10^9 is the largest whole price that fits
+        assert_eq!(normalize_to_price_scale(18_446_744_073, 0).unwrap(), 18_446_744_073_000_000_000);
+        assert!(normalize_to_price_scale(18_446_744_074, 0).is_err());
+        assert!(normalize_to_price_scale(u64::MAX, 0).is_err());
+        
+        // 10^38 still fits u128 but not the result; 10^39 overflows the factor itself
+        assert!(normalize_to_price_scale(1, 29).is_err());
+        assert!(normalize_to_price_scale(0, 30).is_err());
+        assert!(normalize_to_price_scale(1, i32::MAX).is_err());
+    }
+    
+    #[test]
+    fn reads_and_normalizes_a_trading_price() {
+        let price = read(price_account(108_512_345, 12_345, -8, PriceStatus::Trading)).unwrap();
+        
+        assert_eq!(price.price, 1_085_123_450);
+        assert_eq!(price.conf, 123_450);
+        assert_eq!(price.publish_time, 1_700_000_000);
+        assert_eq!(price.publish_slot, 100);
+    }
+    
+    #[test]
+    fn falls_back_to_the_previous_price_when_not_trading() {
+        let price = read(price_account(108_512_346, 12_346, -8, PriceStatus::Unknown)).unwrap();
+        
+        assert_eq!(price.price, 542_561_730);
+        assert_eq!(price.conf, 61_730);
+        assert_eq!(price.publish_time, 1_699_999_990);
+        assert_eq!(price.publish_slot, 90);
+    }
+    
+    #[test]
+    fn rejects_a_price_that_rounds_to_zero() {
+        assert!(read(price_account(999, 0, -12, PriceStatus::Trading)).is_err());
+        assert!(read(price_account(1_000, 0, -12, PriceStatus::Trading)).is_ok());
+    }
+    
+    #[test]
+    fn rejects_non_positive_and_overflowing_prices() {
+        assert!(read(price_account(0, 0, -8, PriceStatus::Trading)).is_err());
+        assert!(read(price_account(-108_512_345, 0, -8, PriceStatus::Trading)).is_err());
+        assert!(read(price_account(i64::MAX, 0, 0, PriceStatus::Trading)).is_err());
+    }
+    
+    #[test]
+    fn rejects_accounts_that_are_not_price_accounts() {
+        let mut account = price_account(108_512_345, 0, -8, PriceStatus::Trading);
+        account.magic = 0;
+        assert!(read(account).is_err());
+        
+        let mut account = price_account(108_512_345, 0, -8, PriceStatus::Trading);
+        account.atype = AccountType::Product as u32;
+        assert!(read(account).is_err());
+    }
+} 
\ No newline at end of file
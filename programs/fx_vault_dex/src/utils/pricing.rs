@@ -0,0 +1,419 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+use crate::state::{
+    VaultAccount, ProtocolConfig, PairConfig, PairState, VaultPair, UserStats, KeeperHeartbeat, STABLESWAP_FALLBACK_MAX_AGE,
+    STABLESWAP_FALLBACK_SPREAD_BPS, VAULT_AUTHORITY_SEED, LP_FEE_PERCENT
+};
+use crate::events::SwapExecuted;
+use crate::utils::{
+    OraclePrice, calculate_fee_allocation, read_oracle_price, calculate_cross_rate, check_pair_circuit_breakers, calculate_amount_out,
+    calculate_stableswap_amount_out, calculate_spread, calculate_launch_decay, calculate_directional_drift,
+    calculate_vault_health_bps, interpolate_curve, calculate_effective_rate, calculate_execution_deviation_bps,
+};
+
+/// State a conversion between two paired vaults is priced from
+pub struct PairPricingAccounts<'a, 'info> {
+    pub source_vault: &'a mut VaultAccount,
+    pub target_vault: &'a mut VaultAccount,
+    pub source_oracle: &'a AccountInfo<'info>,
+    pub target_oracle: &'a AccountInfo<'info>,
+    pub protocol_config: &'a ProtocolConfig,
+    pub pair_config: Option<&'a PairConfig>,
+    pub pair_state: &'a mut PairState,
+    pub vault_pair: &'a VaultPair,
+    pub keeper_heartbeat: Option<&'a KeeperHeartbeat>, // None counts as no heartbeat while monitoring is on
+}
+
+/// Priced conversion, before slippage, volume caps and fee allocation
+pub struct PairSwapQuote {
+    pub amount_out: u64,
+    pub fee_amount: u64,
+    pub oracle_price: u64, // Cross rate of the source currency in the target currency
+    pub oracle_fresh: bool, // False when priced on the stableswap fallback curve
+    pub source_price: OraclePrice,
+    pub target_price: OraclePrice,
+    pub health_bps: u64, // Trailing average health the fee allocation reads
+    pub effective_rate: u64,
+    pub execution_deviation_bps: i64,
+    pub current_slot: u64,
+}
+
+/// State a priced conversion between two paired vaults settles against
+pub struct PairSettlementAccounts<'a, 'info> {
+    pub user: AccountInfo<'info>,
+    pub source_vault: &'a mut VaultAccount,
+    pub source_vault_key: Pubkey,
+    pub target_vault: &'a mut VaultAccount,
+    pub target_vault_key: Pubkey,
+    pub user_source_token: Option<AccountInfo<'info>>, // None when the source vault already holds the amount in
+    pub source_vault_token: AccountInfo<'info>,
+    pub target_vault_token: AccountInfo<'info>,
+    pub user_target_token: AccountInfo<'info>,
+    pub target_vault_authority: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub protocol_config: &'a ProtocolConfig,
+    pub pair_state: &'a mut PairState,
+    pub pair_state_bump: u8,
+    pub vault_pair: &'a mut VaultPair,
+    pub user_stats: Option<&'a mut UserStats>, // None when the trader has not opted in to statistics
+    pub user_stats_bump: u8, // Only read when user_stats is passed
+}
+
+/// Split of a settled conversion's fee
+pub struct PairSwapFees {
+    pub lp_fee_amount: u64,
+    pub pda_fee_amount: u64,
+    pub protocol_fee_amount: u64,
+}
+
+/// Checks both vaults' oracle prices against their circuit breakers before a conversion
+/// A trip, or a resume after the cooldown, is written to the vaults, so callers must end the
+/// instruction successfully without moving funds when this returns false; an error would roll
+/// the trip back. Returns true if the pair is open for the conversion
+pub fn trip_pair_circuit_breakers(
+    source_vault: &mut VaultAccount,
+    source_vault_key: Pubkey,
+    target_vault: &mut VaultAccount,
+    target_vault_key: Pubkey,
+    source_oracle: &AccountInfo,
+    target_oracle: &AccountInfo,
+) -> Result<bool> {
+    let current_slot = Clock::get()?.slot;
+    let source_price = read_oracle_price(source_oracle)?;
+    let target_price = read_oracle_price(target_oracle)?;
+    
+    Ok(check_pair_circuit_breakers(
+        source_vault,
+        source_vault_key,
+        source_price.price,
+        target_vault,
+        target_vault_key,
+        target_price.price,
+        current_slot,
+    ))
+}
+
+/// Prices amount_in of the source currency in the target currency
+/// Swaps, balance incentives and single-currency pair exits all price through here, so they
+/// share the oracle checks, circuit breakers, spreads, drift and trade caps
+pub fn price_pair_swap(accounts: PairPricingAccounts, amount_in: u64) -> Result<PairSwapQuote> {
+    let PairPricingAccounts {
+        source_vault,
+        target_vault,
+        source_oracle,
+        target_oracle,
+        protocol_config,
+        pair_config,
+        pair_state,
+        vault_pair,
+        keeper_heartbeat,
+    } = accounts;
+    
+    // Pair overrides take precedence over the global pricing parameters
+    let (spread_slope_ppm, drift_curve) = PairConfig::resolve(pair_config, protocol_config);
+    
+    // Price from the vaults' oracles rather than a caller-supplied rate
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    let current_slot = clock.slot;
+    let source_price = read_oracle_price(source_oracle)?;
+    let target_price = read_oracle_price(target_oracle)?;
+    let oracle_price = calculate_cross_rate(source_price.price, target_price.price)?;
+    
+    // A stale feed halts the pair unless it has a stableswap fallback curve
+    let oracle_fresh = source_price.is_fresh(current_time) && target_price.is_fresh(current_time);
+    let stableswap_amp = pair_config.map_or(0, |pair| pair.stableswap_amp);
+    require!(oracle_fresh || stableswap_amp > 0, PricingError::StaleOraclePrice);
+    
+    // Callers run trip_pair_circuit_breakers first; a conversion is never priced on a halted vault
+    require!(
+        !source_vault.breaker_tripped && !target_vault.breaker_tripped,
+        PricingError::CircuitBreakerActive
+    );
+    
+    // Reject conversions that use an older price than an earlier one in the same slot
+    require!(
+        source_vault.try_record_oracle_slot(current_slot, source_price.publish_slot),
+        PricingError::StaleOracleSequence
+    );
+    require!(
+        target_vault.try_record_oracle_slot(current_slot, target_price.publish_slot),
+        PricingError::StaleOracleSequence
+    );
+    
+    // A target vault drawn well below its trailing average TVL charges a utilization fee
+    // on top of the spread, compensating the LPs who remain for the concentration risk
+    source_vault.update_tvl_average(current_time);
+    target_vault.update_tvl_average(current_time);
+    let utilization_fee_bps = interpolate_curve(&protocol_config.utilization_fee_curve, target_vault.drawdown_bps()) as u16;
+    
+    // While no bonded keeper has checked in within the liveness window, conversions run in a
+    // degraded-but-safe mode with a wider spread and a smaller maximum trade
+    let liveness_window = protocol_config.keeper_liveness_window;
+    let keepers_live = keeper_heartbeat.map_or(liveness_window == 0, |heartbeat| heartbeat.is_live(current_time, liveness_window));
+    let degraded_spread_bps = if keepers_live { 0 } else { protocol_config.degraded_spread_bps };
+    
+    let source_amount = source_vault.tvl;
+    let target_amount = target_vault.tvl;
+    
+    // Fee allocation and drift read a short trailing average of health, so they move smoothly
+    // across tier boundaries and a tiny trade just before a swap barely shifts them
+    let health_bps = pair_state.update_health_average(calculate_vault_health_bps(source_amount, target_amount), current_time);
+    
+    let (amount_out, fee_amount) = if oracle_fresh {
+        // Calculate the spread based on vault health (imbalance)
+        let base_spread_bps = calculate_spread(source_amount, target_amount, spread_slope_ppm);
+        
+        // Newly launched vaults charge a decaying launch spread on top of the floor
+        let launch_spread_bps = calculate_launch_decay(
+            target_vault.launch_spread_bps,
+            target_vault.launch_start_time,
+            target_vault.launch_duration,
+            current_time,
+        );
+        let spread_bps = base_spread_bps.max(launch_spread_bps) + utilization_fee_bps + degraded_spread_bps;
+        
+        // Calculate the drift based on vault health (imbalance); flow into the scarcer vault
+        // receives a bounded price improvement instead
+        let drift_percentage = calculate_directional_drift(source_amount, target_amount, health_bps, &drift_curve);
+        
+        // Price in the pair's base/quote orientation so both directions use the same rate
+        let (pair_price, source_is_base) = vault_pair.quote(source_vault.token_mint, source_price.price, target_price.price)?;
+        
+        calculate_amount_out(
+            amount_in,
+            pair_price,
+            spread_bps,
+            drift_percentage,
+            target_amount,
+            source_is_base,
+        )?
+    } else {
+        // Price on the fallback curve around the pair's last oracle-priced swap
+        require!(
+            current_time.saturating_sub(pair_state.last_update_timestamp) <= STABLESWAP_FALLBACK_MAX_AGE,
+            PricingError::StaleOraclePrice
+        );
+        let reference_price = pair_state.price_of(source_vault.token_mint).ok_or(PricingError::StaleOraclePrice)?;
+        
+        calculate_stableswap_amount_out(
+            amount_in,
+            reference_price,
+            source_amount,
+            target_amount,
+            stableswap_amp,
+            STABLESWAP_FALLBACK_SPREAD_BPS + utilization_fee_bps + degraded_spread_bps,
+        )?
+    };
+    
+    // Pairs with a best-execution cap reject trades that fall too far below the oracle mid
+    let effective_rate = calculate_effective_rate(amount_in, amount_out);
+    let execution_deviation_bps = calculate_execution_deviation_bps(effective_rate, oracle_price);
+    let max_execution_deviation_bps = pair_config.map_or(0, |pair| pair.max_execution_deviation_bps);
+    require!(
+        max_execution_deviation_bps == 0 || execution_deviation_bps <= max_execution_deviation_bps as i64,
+        PricingError::ExecutionDeviationExceeded
+    );
+    
+    // Ensure the target vault has enough funds
+    require!(target_amount >= amount_out, PricingError::InsufficientLiquidity);
+    
+    // Degraded mode also caps each conversion at a share of the target vault
+    let degraded_max_trade_bps = protocol_config.degraded_max_trade_bps;
+    if !keepers_live && degraded_max_trade_bps > 0 {
+        let max_amount_out = (target_amount as u128 * degraded_max_trade_bps as u128 / 10000) as u64;
+        require!(amount_out <= max_amount_out, PricingError::DegradedTradeSizeExceeded);
+    }
+    
+    Ok(PairSwapQuote {
+        amount_out,
+        fee_amount,
+        oracle_price,
+        oracle_fresh,
+        source_price,
+        target_price,
+        health_bps,
+        effective_rate,
+        execution_deviation_bps,
+        current_slot,
+    })
+}
+
+/// Settles a conversion priced by price_pair_swap
+/// Records the daily volume, moves the tokens, allocates the fee, updates both vaults, the pair
+/// records and the trader's statistics, and emits SwapExecuted. payout is what leaves the target
+/// vault for the user; anything above amount_out is the caller's to account for
+pub fn settle_pair_swap(
+    accounts: PairSettlementAccounts,
+    quote: &PairSwapQuote,
+    amount_in: u64,
+    payout: u64,
+) -> Result<PairSwapFees> {
+    let PairSettlementAccounts {
+        user,
+        source_vault,
+        source_vault_key,
+        target_vault,
+        target_vault_key,
+        user_source_token,
+        source_vault_token,
+        target_vault_token,
+        user_target_token,
+        target_vault_authority,
+        token_program,
+        protocol_config,
+        pair_state,
+        pair_state_bump,
+        vault_pair,
+        user_stats,
+        user_stats_bump,
+    } = accounts;
+    let amount_out = quote.amount_out;
+    let fee_amount = quote.fee_amount;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    // Enforce the daily volume caps on both sides of the conversion
+    require!(source_vault.try_record_daily_volume(amount_in, current_time), PricingError::DailyVolumeCapExceeded);
+    require!(target_vault.try_record_daily_volume(amount_out, current_time), PricingError::DailyVolumeCapExceeded);
+    
+    // 1. Transfer tokens from user to source vault
+    if let Some(user_source_token) = user_source_token {
+        let transfer_in_accounts = Transfer {
+            from: user_source_token,
+            to: source_vault_token,
+            authority: user.clone(),
+        };
+        
+        let cpi_ctx_in = CpiContext::new(token_program.clone(), transfer_in_accounts);
+        
+        token::transfer(cpi_ctx_in, amount_in)?;
+    }
+    
+    // 2. Transfer the payout from target vault to user
+    let bump = target_vault.nonce;
+    let seeds = &[
+        VAULT_AUTHORITY_SEED,
+        target_vault_key.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    
+    let transfer_out_accounts = Transfer {
+        from: target_vault_token,
+        to: user_target_token,
+        authority: target_vault_authority,
+    };
+    
+    let cpi_ctx_out = CpiContext::new_with_signer(token_program, transfer_out_accounts, signer_seeds);
+    
+    token::transfer(cpi_ctx_out, payout)?;
+    
+    // 3. Calculate and distribute fees
+    // Get fee allocation shares from the protocol fee curve
+    let (pda_fee_bps, protocol_fee_bps) = calculate_fee_allocation(quote.health_bps, &protocol_config.protocol_fee_curve);
+    
+    // Calculate fee amounts (the sum is always 30% of total fee)
+    let lp_fee_amount = fee_amount.checked_mul(LP_FEE_PERCENT as u64).ok_or(PricingError::MathOverflow)?.checked_div(100).ok_or(PricingError::MathOverflow)?;
+    let pda_fee_amount = fee_amount.checked_mul(pda_fee_bps as u64).ok_or(PricingError::MathOverflow)?.checked_div(10000).ok_or(PricingError::MathOverflow)?;
+    let protocol_fee_amount = fee_amount.checked_mul(protocol_fee_bps as u64).ok_or(PricingError::MathOverflow)?.checked_div(10000).ok_or(PricingError::MathOverflow)?;
+    
+    // Update the source vault's TVL
+    source_vault.tvl = source_vault.tvl.checked_add(amount_in).ok_or(PricingError::MathOverflow)?;
+    
+    // Update the target vault's TVL and record accrued fees
+    target_vault.tvl = target_vault.tvl.checked_sub(amount_out).ok_or(PricingError::MathOverflow)?;
+    target_vault.accrued_lp_fees = target_vault.accrued_lp_fees.checked_add(lp_fee_amount).ok_or(PricingError::MathOverflow)?;
+    target_vault.accrued_pda_fees = target_vault.accrued_pda_fees.checked_add(pda_fee_amount).ok_or(PricingError::MathOverflow)?;
+    target_vault.accrued_protocol_fees = target_vault.accrued_protocol_fees.checked_add(protocol_fee_amount).ok_or(PricingError::MathOverflow)?;
+    target_vault.cumulative_swap_fees = target_vault.cumulative_swap_fees.saturating_add(fee_amount);
+    target_vault.last_fee_update = current_time;
+    
+    // The pair record holds the same price whichever vault was the source
+    pair_state.init_if_needed(source_vault.token_mint, target_vault.token_mint, pair_state_bump);
+    
+    // Fallback conversions leave the recorded prices alone so the curve keeps its reference
+    if quote.oracle_fresh {
+        source_vault.last_oracle_price = quote.oracle_price;
+        source_vault.last_update_timestamp = current_time;
+        
+        pair_state
+            .record_prices(
+                source_vault.token_mint,
+                (quote.source_price.price, quote.source_price.publish_slot),
+                (quote.target_price.price, quote.target_price.publish_slot),
+                quote.current_slot,
+                current_time,
+            )
+            .ok_or(PricingError::MathOverflow)?;
+    }
+    
+    // Per-pair statistics
+    vault_pair.record_swap(source_vault.token_mint, amount_in, fee_amount, current_time);
+    
+    if let Some(user_stats) = user_stats {
+        if user_stats.owner == Pubkey::default() {
+            user_stats.owner = user.key();
+            user_stats.bump = user_stats_bump;
+        }
+        user_stats.record_swap(
+            (amount_in, quote.source_price.price),
+            (fee_amount, quote.target_price.price),
+            quote.current_slot,
+            current_time,
+        );
+    }
+    
+    // Track net exposure per currency
+    source_vault.record_swap_flow(amount_in, 0);
+    target_vault.record_swap_flow(0, amount_out);
+    
+    emit!(SwapExecuted {
+        user: user.key(),
+        source_vault: source_vault_key,
+        target_vault: target_vault_key,
+        amount_in,
+        amount_out,
+        fee_amount,
+        oracle_price: quote.oracle_price,
+        effective_rate: quote.effective_rate,
+        execution_deviation_bps: quote.execution_deviation_bps,
+        source_publish_slot: quote.source_price.publish_slot,
+        target_publish_slot: quote.target_price.publish_slot,
+        source_net_flow: source_vault.net_swap_flow,
+        target_net_flow: target_vault.net_swap_flow,
+        stableswap_fallback: !quote.oracle_fresh,
+    });
+    
+    Ok(PairSwapFees {
+        lp_fee_amount,
+        pda_fee_amount,
+        protocol_fee_amount,
+    })
+}
+
+#[error_code]
+pub enum PricingError {
+    #[msg("Oracle price is stale and the pair has no usable fallback curve")]
+    StaleOraclePrice,
+    
+    #[msg("Swaps are halted by the price deviation circuit breaker")]
+    CircuitBreakerActive,
+    
+    #[msg("Oracle price is older than the one used by an earlier swap in this slot")]
+    StaleOracleSequence,
+    
+    #[msg("Effective rate is further below the oracle mid than the pair allows")]
+    ExecutionDeviationExceeded,
+    
+    #[msg("Insufficient liquidity in target vault")]
+    InsufficientLiquidity,
+    
+    #[msg("Swap exceeds the maximum trade size while keepers are not live")]
+    DegradedTradeSizeExceeded,
+    
+    #[msg("Swap exceeds the vault's daily volume cap")]
+    DailyVolumeCapExceeded,
+    
+    #[msg("Math operation resulted in overflow")]
+    MathOverflow,
+} 
\ No newline at end of file
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use crate::state::VaultAccount;
+
+/// Enforces the vault's transaction guard, if enabled
+/// Guarded vaults only allow one operation per transaction, so flash-loan style sequences
+/// cannot interleave deposits, swaps and withdrawals against the same share price
+pub fn check_transaction_guard(vault: &VaultAccount, vault_key: &Pubkey, instructions_sysvar: Option<&AccountInfo>) -> Result<()> {
+    if !vault.tx_guard_enabled {
+        return Ok(());
+    }
+    
+    let instructions_sysvar = instructions_sysvar.ok_or(TxGuardError::InstructionsSysvarRequired)?;
+    require_isolated_vault_instruction(instructions_sysvar, vault_key)
+}
+
+/// Rejects the instruction if it is reached through a CPI or if any other instruction in
+/// the transaction calls this program with the vault
+fn require_isolated_vault_instruction(instructions_sysvar: &AccountInfo, vault: &Pubkey) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+    
+    let current = load_instruction_at_checked(current_index, instructions_sysvar)?;
+    require_keys_eq!(current.program_id, crate::ID, TxGuardError::CpiNotAllowed);
+    
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if index != current_index
+            && instruction.program_id == crate::ID
+            && instruction.accounts.iter().any(|meta| meta.pubkey == *vault)
+        {
+            return err!(TxGuardError::InterleavedVaultInstruction);
+        }
+        index += 1;
+    }
+    
+    Ok(())
+}
+
+/// Error codes for the transaction guard
+#[error_code]
+pub enum TxGuardError {
+    #[msg("Vault operations cannot be invoked through a CPI while the transaction guard is on")]
+    CpiNotAllowed,
+    
+    #[msg("Transaction contains another instruction for this vault")]
+    InterleavedVaultInstruction,
+    
+    #[msg("Instructions sysvar is required while the transaction guard is on")]
+    InstructionsSysvarRequired,
+} 
\ No newline at end of file